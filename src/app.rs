@@ -1,24 +1,36 @@
+use crate::misc::{config::config, session::Session, type_ext::UnwrapOrEnqueueError};
 use crate::tui::Pane;
-use crate::tui::popups::sql_query_picker::SqlQueryPicker;
+use crate::tui::popups::{
+    session_picker::{LoadSessionPicker, SaveSessionPicker},
+    sql_file_runner::SqlFileRunner,
+    sql_query_picker::SqlQueryPicker,
+};
 use crate::tui::table::Table;
-use crate::tui::{error_popup::ErrorPopup, tabs::Tabs};
+use crate::tui::{error_popup::ErrorPopup, tabs::Tabs, toast::Toast};
 use crate::{
+    AppResult,
     handler::message::Message,
     tui::{
         component::{Component, FocusState},
         popups::{
-            command_palette::CommandPalette, help_modal::Help, import_wizard::ImportWizard,
+            command_palette::CommandPalette,
+            help_modal::Help,
+            import_wizard::{self, ImportWizard},
+            message_log::{LogKind, MessageLog, MessageLogView},
             theme_selector::ThemeSelector,
         },
         schema::schema::Schema,
     },
 };
 use crossterm::event::KeyCode;
+use polars::frame::DataFrame;
 
 pub struct App {
     tabs: Tabs,
     overlay: Option<Overlay>,
     schema: Option<Schema>,
+    toast: Option<Toast>,
+    messages: MessageLog,
     running: bool,
 }
 
@@ -28,6 +40,8 @@ impl App {
             tabs,
             overlay: None,
             schema: None,
+            toast: None,
+            messages: MessageLog::default(),
             running: true,
         }
     }
@@ -36,6 +50,12 @@ impl App {
         self.running
     }
 
+    /// Table of the selected tab as it is currently shown, collected whole if the tab shows the
+    /// preview of a lazily scanned table.
+    pub fn selected_data_frame(&self) -> AppResult<Option<DataFrame>> {
+        self.tabs.selected().map(Pane::full_data_frame).transpose()
+    }
+
     fn show_theme_selector(&mut self) {
         self.overlay = Some(Overlay::ThemeSelector(Default::default()));
     }
@@ -45,13 +65,26 @@ impl App {
     }
 
     fn show_error(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        self.messages
+            .push(LogKind::Error, message.clone(), config().message_log_size());
         self.overlay = Some(Overlay::Error(ErrorPopup::new(message)));
     }
 
+    fn show_message_log(&mut self) {
+        self.overlay = Some(Overlay::MessageLog(MessageLogView::new(&self.messages)));
+    }
+
     fn show_import_wizard(&mut self) {
         self.overlay = Some(Overlay::Import(ImportWizard::default()))
     }
 
+    fn show_open_wizard(&mut self) {
+        self.overlay = Some(Overlay::Import(ImportWizard::new(
+            import_wizard::State::auto(),
+        )))
+    }
+
     fn show_sql_query_picker(&mut self) {
         self.overlay = Some(Overlay::SqlQueryPicker(SqlQueryPicker::new(
             self.tabs
@@ -62,6 +95,63 @@ impl App {
         )));
     }
 
+    fn show_explain_query_picker(&mut self) {
+        self.overlay = Some(Overlay::SqlQueryPicker(
+            SqlQueryPicker::new(
+                self.tabs
+                    .selected()
+                    .map(Pane::table)
+                    .map(Table::data_frame)
+                    .cloned(),
+            )
+            .with_explain(),
+        ));
+    }
+
+    fn show_toast(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        self.messages
+            .push(LogKind::Info, message.clone(), config().message_log_size());
+        self.toast = Some(Toast::new(message));
+    }
+
+    fn show_sql_file_runner(&mut self) {
+        self.overlay = Some(Overlay::SqlFileRunner(SqlFileRunner::new(
+            self.tabs
+                .selected()
+                .map(Pane::table)
+                .map(Table::data_frame)
+                .cloned(),
+        )));
+    }
+
+    fn show_save_session(&mut self) {
+        self.overlay = Some(Overlay::SaveSession(SaveSessionPicker::default()));
+    }
+
+    fn show_load_session(&mut self) {
+        self.overlay = Some(Overlay::LoadSession(LoadSessionPicker::default()));
+    }
+
+    fn save_session(&mut self, name: &str) -> AppResult<()> {
+        Session::new(self.tabs.iter().map(Pane::base_description)).store(name)?;
+        self.show_toast(format!("Session {name} saved"));
+        Ok(())
+    }
+
+    fn load_session(&mut self, name: &str) -> AppResult<()> {
+        let failed = Session::load(name)?.restore();
+        if failed.is_empty() {
+            self.show_toast(format!("Session {name} loaded"));
+        } else {
+            self.show_error(format!(
+                "Some entries of session {name} could not be restored:\n{}",
+                failed.join("\n")
+            ));
+        }
+        Ok(())
+    }
+
     fn dismiss_overlay(&mut self) {
         self.overlay = None;
     }
@@ -102,6 +192,13 @@ impl Component for App {
                 self.tabs.render(area, buf, FocusState::Focused);
             }
         }
+        if let Some(toast) = self.toast.as_mut() {
+            toast.render(area, buf, FocusState::NotFocused);
+        }
+    }
+
+    fn handle_mouse(&mut self, event: crossterm::event::MouseEvent) -> bool {
+        self.overlay.is_none() && self.schema.is_none() && self.tabs.handle_mouse(event)
     }
 
     fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
@@ -133,8 +230,17 @@ impl Component for App {
             Message::AppShowThemeSelector => self.show_theme_selector(),
             Message::AppShowSchema => self.show_schema(),
             Message::AppShowImportWizard => self.show_import_wizard(),
+            Message::AppShowOpenWizard => self.show_open_wizard(),
             Message::AppDismissSchema => self.dismiss_schema(),
             Message::AppShowSqlQuery => self.show_sql_query_picker(),
+            Message::AppShowExplainQuery => self.show_explain_query_picker(),
+            Message::AppShowSqlFileRunner => self.show_sql_file_runner(),
+            Message::AppShowToast(message) => self.show_toast(message),
+            Message::AppShowMessageLog => self.show_message_log(),
+            Message::AppShowSaveSession => self.show_save_session(),
+            Message::AppShowLoadSession => self.show_load_session(),
+            Message::AppSaveSession(name) => self.save_session(name).unwrap_or_enqueue_error(),
+            Message::AppLoadSession(name) => self.load_session(name).unwrap_or_enqueue_error(),
             _ => (),
         };
         match (self.overlay.as_mut(), self.schema.as_mut()) {
@@ -161,6 +267,12 @@ impl Component for App {
         if let Some(overlay) = self.overlay.as_mut() {
             overlay.responder().tick();
         }
+        if let Some(toast) = self.toast.as_mut() {
+            toast.tick();
+            if toast.expired() {
+                self.toast = None;
+            }
+        }
         self.tabs.tick();
     }
 }
@@ -171,8 +283,12 @@ pub enum Overlay {
     CommandPicker(CommandPalette),
     ThemeSelector(ThemeSelector),
     SqlQueryPicker(SqlQueryPicker),
+    SqlFileRunner(SqlFileRunner),
     Import(ImportWizard),
     Help(Help),
+    SaveSession(SaveSessionPicker),
+    LoadSession(LoadSessionPicker),
+    MessageLog(MessageLogView),
 }
 
 impl Overlay {
@@ -184,6 +300,10 @@ impl Overlay {
             Overlay::Help(help) => help,
             Overlay::Import(wizard) => wizard,
             Overlay::SqlQueryPicker(sql_query_picker) => sql_query_picker,
+            Overlay::SqlFileRunner(sql_file_runner) => sql_file_runner,
+            Overlay::SaveSession(picker) => picker,
+            Overlay::LoadSession(picker) => picker,
+            Overlay::MessageLog(log) => log,
         }
     }
 }
@@ -1,10 +1,16 @@
 use clap::{Parser, ValueEnum};
-use std::{num::NonZero, path::PathBuf};
+use std::{
+    num::NonZero,
+    path::{Path, PathBuf},
+};
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 pub struct Args {
-    #[arg(help = "Path(s) to the file(s) to be opened.", required = false)]
+    #[arg(
+        help = "Path(s) to the file(s) to be opened. Use - or leave empty to read from stdin.",
+        required = false
+    )]
     pub files: Vec<PathBuf>,
 
     #[arg(long, help = "Paths to be opened and concatenated vertically.",
@@ -20,6 +26,13 @@ pub struct Args {
     )]
     pub format: Option<Format>,
 
+    #[arg(
+        long,
+        help = "Specifies the format of the data piped to stdin. By default, it is detected from the leading bytes and falls back to CSV",
+        value_enum
+    )]
+    pub stdin_format: Option<Format>,
+
     #[arg(long, help = "Sets the key for sqlite (if required)", value_enum)]
     pub sqlite_key: Option<String>,
 
@@ -69,6 +82,15 @@ pub struct Args {
     )]
     pub quote_char: char,
 
+    #[arg(
+        long,
+        help = "Character encoding of DSV and FWF files, the input is transcoded to UTF-8 before parsing.",
+        required = false,
+        value_enum,
+        default_value_t = Encoding::Utf8,
+    )]
+    pub encoding: Encoding,
+
     #[arg(
         long,
         help = "A comma-separated list of widths, which specifies the column widths for FWF files.",
@@ -77,6 +99,13 @@ pub struct Args {
     )]
     pub widths: String,
 
+    #[arg(
+        long,
+        help = "Path to a file defining the column names and widths for FWF files (one 'name:width' per line).",
+        required = false
+    )]
+    pub schema_file: Option<PathBuf>,
+
     #[arg(
         long,
         help = "Specifies the separator length for FWF files.",
@@ -103,7 +132,7 @@ pub struct Args {
 
     #[arg(
         long,
-        help = "Specifies the types to infer for text-based files.",
+        help = "Specifies the types to infer for text-based files. 'currency' parses values like $1,234.50 and 'percent' parses values like 45% as a fraction, neither is part of 'all'.",
         required = false,
         default_value_t = TypeVec(vec![Type::Int, Type::Float]),
     )]
@@ -116,6 +145,68 @@ pub struct Args {
         default_value_t = false
     )]
     pub no_type_inference: bool,
+
+    #[arg(
+        long,
+        help = "Scans CSV, Parquet, Arrow and JSONL files lazily, loading a preview and running queries on the streaming engine.",
+        required = false,
+        default_value_t = false
+    )]
+    pub lazy: bool,
+
+    #[arg(
+        long,
+        help = "Seed of the random rows picked by the sample command, makes samples reproducible.",
+        required = false
+    )]
+    pub seed: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Reads at most this many rows of CSV, Parquet, Arrow, JSON and JSONL files for a quick preview, CSV, Parquet, Arrow and JSONL files are not read further.",
+        required = false
+    )]
+    pub limit: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Skips this many rows of CSV, Parquet, Arrow, JSON and JSONL files before reading, combines with --limit to preview the middle of a file.",
+        required = false,
+        default_value_t = 0_usize
+    )]
+    pub offset: usize,
+
+    #[arg(
+        long,
+        help = "Skips malformed lines of JSONL files and reports how many were dropped.",
+        required = false,
+        default_value_t = false
+    )]
+    pub skip_errors: bool,
+
+    #[arg(
+        long,
+        help = "Runs the SQL query against the loaded tables and exits without starting the TUI, the first table is also available as _. A leading 'Q ' is ignored.",
+        required = false
+    )]
+    pub batch: Option<String>,
+
+    #[arg(
+        long,
+        help = "Prints the final table to stdout on exit, the result of --batch or the table of the selected tab.",
+        required = false,
+        default_value_t = false
+    )]
+    pub print: bool,
+
+    #[arg(
+        long,
+        help = "Specifies the format of the table printed by --print.",
+        required = false,
+        value_enum,
+        default_value_t = PrintFormat::Csv,
+    )]
+    pub print_format: PrintFormat,
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -127,12 +218,65 @@ pub enum Format {
     Jsonl,
     Json,
     Arrow,
+    Avro,
     Fwf,
     Sqlite,
     Excel,
+    Ods,
     Logfmt,
 }
 
+impl Format {
+    /// Format of the file by its extension, None if the extension is unknown.
+    pub fn from_extension(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "csv" => Some(Format::Csv),
+            "tsv" => Some(Format::Tsv),
+            "parquet" | "pqt" => Some(Format::Parquet),
+            "json" => Some(Format::Json),
+            "jsonl" | "ndjson" => Some(Format::Jsonl),
+            "arrow" | "ipc" => Some(Format::Arrow),
+            "avro" => Some(Format::Avro),
+            "fwf" => Some(Format::Fwf),
+            "db" | "sqlite" => Some(Format::Sqlite),
+            "xls" | "xlsx" | "xlsm" | "xlsb" => Some(Format::Excel),
+            "ods" => Some(Format::Ods),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PrintFormat {
+    Csv,
+    Tsv,
+    Json,
+    Md,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Encoding {
+    #[value(name = "utf8", alias = "utf-8")]
+    Utf8,
+    #[value(name = "latin1", alias = "iso-8859-1")]
+    Latin1,
+    #[value(name = "windows-1252", alias = "cp1252")]
+    Windows1252,
+    #[value(name = "utf16", alias = "utf-16")]
+    Utf16,
+}
+
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Encoding::Utf8 => write!(f, "utf8"),
+            Encoding::Latin1 => write!(f, "latin1"),
+            Encoding::Windows1252 => write!(f, "windows-1252"),
+            Encoding::Utf16 => write!(f, "utf16"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TypeVec(Vec<Type>);
 
@@ -168,6 +312,8 @@ pub enum Type {
     Boolean,
     Date,
     Datetime,
+    Currency,
+    Percent,
 }
 
 impl std::fmt::Display for Type {
@@ -179,6 +325,8 @@ impl std::fmt::Display for Type {
             Type::Boolean => write!(f, "boolean"),
             Type::Date => write!(f, "date"),
             Type::Datetime => write!(f, "datetime"),
+            Type::Currency => write!(f, "currency"),
+            Type::Percent => write!(f, "percent"),
         }
     }
 }
@@ -194,6 +342,8 @@ impl std::str::FromStr for Type {
             "boolean" => Ok(Type::Boolean),
             "date" => Ok(Type::Date),
             "datetime" => Ok(Type::Datetime),
+            "currency" => Ok(Type::Currency),
+            "percent" => Ok(Type::Percent),
             _ => Err(format!("Unknown type: {s}")),
         }
     }
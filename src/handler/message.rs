@@ -1,11 +1,27 @@
-use std::sync::{
-    LazyLock, Mutex,
-    mpsc::{Receiver, Sender, channel},
+use std::{
+    sync::{
+        LazyLock, Mutex,
+        mpsc::{Receiver, Sender, channel},
+    },
+    time::Duration,
 };
 
 use polars::frame::DataFrame;
 
-use crate::tui::pane::TableDescription;
+use crate::{
+    misc::{
+        cell_format::Notation,
+        polars_ext::{Aggregation, RenameSpec, SampleSpec},
+        sql::QueryProgress,
+    },
+    reader::ImportProgress,
+    tui::{
+        pane::{PaneId, TableDescription},
+        popups::column_caster_wizard::TargetType,
+        themes::theme::Theme,
+    },
+    writer::{TextFormat, WriteProgress},
+};
 
 #[derive(Debug)]
 pub enum Message {
@@ -15,27 +31,137 @@ pub enum Message {
     AppShowThemeSelector,
     AppShowSchema,
     AppShowImportWizard,
+    AppShowOpenWizard,
     AppDismissSchema,
     AppShowSqlQuery,
+    AppShowExplainQuery,
+    AppShowSqlFileRunner,
+    AppShowToast(String),
+    AppShowMessageLog,
+    AppShowSaveSession,
+    AppShowLoadSession,
+    AppSaveSession(String),
+    AppLoadSession(String),
     TabsSelect(usize),
     TabsDismissSwitcher,
     TabsAddNamePane(DataFrame, String),
     TabsAddQueryPane(DataFrame, String),
+    TabsShowWriteProgress(WriteProgress),
+    TabsDismissWriteProgress(WriteProgress),
+    TabsShowImportProgress(ImportProgress),
+    TabsDismissImportProgress(ImportProgress),
+    TabsShowQueryProgress(QueryProgress),
+    TabsDismissQueryProgress(QueryProgress),
+    TabsCancelQuery,
+    TabsShowQueryTime(Duration),
     PaneShowExportWizard,
     PaneShowInlineFilter,
     PaneShowInlineOrder,
+    PaneShowInlineGroupBy,
+    PaneShowInlineJoin,
+    PaneShowInlineDiff,
+    PaneShowInlineUnpivot,
+    PaneShowInlineRename,
+    PaneShowInlineCast,
+    PaneShowInlineView,
     PaneShowHistogram(String, usize),
     PaneShowHistogramWizard,
     PaneShowScatterPlot(String, String, Option<String>),
     PaneShowScatterPlotWizard,
+    PaneShowLinePlot(String, String, Option<String>),
+    PaneShowLinePlotWizard,
+    PaneShowBarPlot(String),
+    PaneShowBarPlotWizard,
+    PaneShowDensityPlot(String, Option<f64>),
+    PaneShowDensityPlotWizard,
+    PaneShowBoxPlot(String, Option<String>),
+    PaneShowBoxPlotWizard,
+    PaneShowCorrMatrix,
+    PaneShowNullMap,
+    PaneDescribe,
+    PaneTranspose,
     PaneDismissModal,
+    PaneSheetNextRow,
+    PaneSheetGoTo(usize),
+    PaneSheetPrevRow,
     PanePushDataFrame(DataFrame, TableDescription),
+    PanePushQueryResult(PaneId, DataFrame, TableDescription),
     PanePopDataFrame,
     PaneTableSelect(usize),
     PaneShowInlineSelect,
     PaneShowTableRegisterer,
     PaneShowTableInfo,
+    PaneShowColumnList,
     PaneShowColumnCasterWizard,
+    PaneShowPrecisionPicker,
+    PaneShowTimeFormatPicker,
+    PaneSetPrecision(Option<usize>),
+    PaneShowPinPicker,
+    PaneSetPinned(usize),
+    PaneReverse,
+    PaneShowDuplicates,
+    PaneDedup,
+    PaneShowSamplePicker,
+    PaneSample(SampleSpec),
+    PaneReset,
+    PaneResetNow,
+    PaneFilterBookmarks,
+    PaneSnapshot,
+    PaneShowHeadPicker,
+    PaneShowTailPicker,
+    PaneHead(usize),
+    PaneTail(usize),
+    PaneRenameColumns(RenameSpec),
+    PaneCastColumn(String, TargetType, bool),
+    PaneShowNotationWizard,
+    PaneSetNotation(String, Notation),
+    PaneShowColumnIndexWizard,
+    PaneShowColumnIndex(String),
+    PaneShowColumnStatsWizard,
+    PaneShowColumnStats(String),
+    PaneShowValueCountsWizard,
+    PaneShowValueCounts(String),
+    PaneShowPivotWizard,
+    PanePivot(String, String, String, Aggregation),
+    PaneCopyRow(bool),
+    PaneShowInlineExportClipboard,
+    PaneExportClipboard(TextFormat),
+    PaneShowCopyColumnWizard,
+    PaneCopyColumn(String),
+    PaneShowHideColumnWizard,
+    PaneHideColumn(String),
+    PaneShowShadeColumnWizard,
+    PaneShadeColumn(String),
+    PaneUnshade,
+    PaneToggleFilterRow,
+    PaneShowInlineTabTheme,
+    PaneShowInlineAddColumn,
+    PaneAddColumn(String),
+    PaneShowInlineCount,
+    PaneCount(String),
+    PaneSetTheme(Option<Theme>),
+    PaneShowShowColumnWizard,
+    PaneShowColumn(String),
+    PaneShowAllColumns,
+    PaneShowCellEditWizard,
+    PaneEditCell(String),
+    PaneShowFilterSelectedWizard,
+    PaneFilterSelected(String),
+    PaneCommitCellEdit(usize, String, String),
+    PaneShowGoToColumn,
+    PaneGoToColumn(String),
+    PaneToggleZebra,
+    PaneToggleFitColumns,
+    PaneToggleWrap,
+    PaneShowMaxWidthPicker,
+    PaneShowWherePicker,
+    PaneSetWhere(Option<String>),
+    PaneShowRegexSearch,
+    PaneShowRenamePicker,
+    PaneRename(Option<String>),
+    PaneWatch,
+    PaneUnwatch,
+    PaneReloaded(String, DataFrame),
     Quit,
 }
 
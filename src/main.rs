@@ -1,23 +1,26 @@
 use clap::{CommandFactory, Parser};
 use indexmap::IndexMap;
 use polars::frame::DataFrame;
-use polars::prelude::Schema;
+use polars::prelude::{Engine, Schema};
 use ratatui::backend::CrosstermBackend;
-use std::io::{self, IsTerminal};
+use std::io::{self, IsTerminal, Write};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tabiew::app::App;
-use tabiew::args::Args;
+use tabiew::args::{Args, PrintFormat};
 use tabiew::handler::event::{Event, EventHandler};
 use tabiew::handler::message::Message;
 use tabiew::misc::config::config;
-use tabiew::misc::globals::sql;
+use tabiew::misc::globals::{set_args, sql, stdin};
+use tabiew::misc::history::history;
 use tabiew::misc::osc52::flush_osc52_buffer;
 use tabiew::misc::type_ext::UnwrapOrGracefulShutdown;
 use tabiew::misc::type_inferer::TypeInferer;
-use tabiew::reader::{BuildReader, Source};
+use tabiew::reader::{BuildReader, LAZY_PREVIEW_ROWS, Preview, Source, scan_path};
 use tabiew::tui::component::{Component, FocusState};
 use tabiew::tui::pane::TableDescription;
+use tabiew::tui::themes::file_theme::file_themes;
+use tabiew::writer::{TextFormat, text_writer};
 
 use tabiew::tui::Pane;
 use tabiew::{AppResult, tui};
@@ -33,10 +36,14 @@ fn main() {
             Args::parse_from(args_os)
         }
     };
+    let args = set_args(args);
 
+    // Discover theme files before the TUI starts so parse warnings stay visible
+    file_themes();
     let _ = config().reload();
+    let _ = history().load();
 
-    let type_infer = TypeInferer::from_args(&args);
+    let type_infer = TypeInferer::from_args(args);
 
     // Dataframe loading
     let mut name_dfs = Vec::new();
@@ -44,7 +51,7 @@ fn main() {
     // Load multiparts to data frames
     let mut multiparts = IndexMap::<Arc<Schema>, (String, DataFrame)>::new();
     for path in args.multiparts.iter() {
-        for (name, new_df) in try_read_path(&args, path).unwrap_or_graceful_shutdown() {
+        for (name, new_df) in try_read_path(args, path).unwrap_or_graceful_shutdown() {
             let schema = new_df.schema().clone();
             if let Some((_, df)) = multiparts.get_mut(&schema) {
                 df.vstack_mut_owned(new_df).unwrap_or_graceful_shutdown();
@@ -60,18 +67,47 @@ fn main() {
         name_dfs.push((name, df));
     }
 
+    // Scanned tables are queried on the streaming engine, only a preview is collected for the tab
+    sql().set_streaming(args.lazy);
+
     // Load files to data frames
     for path in args.files.iter() {
-        for (name, mut df) in try_read_path(&args, path).unwrap_or_graceful_shutdown() {
+        if args.lazy
+            && let Some(lf) = scan_path(args, path)
+        {
+            let lf = lf.unwrap_or_graceful_shutdown();
+            let source = Source::from(path.to_string_lossy().into_owned());
+            let name = sql()
+                .register_lazy(&source.table_name(), lf.clone(), source)
+                .unwrap_or_graceful_shutdown();
+            let df = lf
+                .limit(LAZY_PREVIEW_ROWS)
+                .collect_with_engine(Engine::Streaming)
+                .unwrap_or_graceful_shutdown();
+            name_dfs.push((name, df));
+            continue;
+        }
+        for (name, mut df) in try_read_path(args, path).unwrap_or_graceful_shutdown() {
             type_infer.update(&mut df);
-            let name = sql().register(&name, df.clone(), Source::File(path.clone()));
+            let name = sql().register(
+                &name,
+                df.clone(),
+                Source::from(path.to_string_lossy().into_owned()),
+            );
+            if let Some(preview) = Preview::from_args(args, path, df.height()) {
+                sql().set_preview(&name, preview);
+            }
             name_dfs.push((name, df))
         }
     }
 
     if name_dfs.is_empty() {
+        if io::stdin().is_terminal() {
+            eprintln!("No input file is given and nothing is piped to stdin");
+            std::process::exit(1);
+        }
         for (name, mut df) in args
-            .build_reader("")
+            .build_stdin_reader()
             .unwrap_or_graceful_shutdown()
             .named_frames(Source::Stdin)
             .unwrap_or_graceful_shutdown()
@@ -82,10 +118,37 @@ fn main() {
         }
     }
 
-    let _ = start_tui(name_dfs);
+    if let Some(query) = &args.batch {
+        let query = query.strip_prefix("Q ").unwrap_or(query);
+        // `_` is the registered table, so a scanned table is queried whole, not its preview
+        if let Some((name, _)) = name_dfs.first() {
+            sql().set_default(name).unwrap_or_graceful_shutdown();
+        }
+        let mut df = sql().execute(query, None).unwrap_or_graceful_shutdown();
+        if args.print {
+            print_table(args.print_format, &mut df).unwrap_or_graceful_shutdown();
+        }
+        return;
+    }
+
+    // Drain a piped stdin before the terminal enters raw mode, so later imports
+    // from stdin never compete with the event reader.
+    if !io::stdin().is_terminal() {
+        stdin();
+    }
+
+    // The printed table is often redirected to a file, keep the interface off stdout then
+    if args.print {
+        tui::terminal::draw_to_tty();
+    }
+    let df = start_tui(name_dfs).unwrap_or_graceful_shutdown();
+    if let (true, Some(mut df)) = (args.print, df) {
+        print_table(args.print_format, &mut df).unwrap_or_graceful_shutdown();
+    }
 }
 
-fn start_tui(tabs: Vec<(String, DataFrame)>) -> AppResult<()> {
+/// Runs the TUI until the app quits, returns the table of the tab selected at that point.
+fn start_tui(tabs: Vec<(String, DataFrame)>) -> AppResult<Option<DataFrame>> {
     let tabs = tabs
         .into_iter()
         .map(|(name, df)| Pane::new(df, TableDescription::Table(name)))
@@ -93,7 +156,7 @@ fn start_tui(tabs: Vec<(String, DataFrame)>) -> AppResult<()> {
 
     // Initialize the terminal user interface.
     let mut tui = tui::Terminal::new(
-        ratatui::Terminal::new(CrosstermBackend::new(io::stdout()))?,
+        ratatui::Terminal::new(CrosstermBackend::new(tui::terminal::output()?))?,
         EventHandler::new(100),
     );
     tui.init()?;
@@ -121,7 +184,9 @@ fn start_tui(tabs: Vec<(String, DataFrame)>) -> AppResult<()> {
                     app.handle(key_event);
                 }
             }
-            Event::Mouse(_) => {}
+            Event::Mouse(mouse_event) => {
+                app.handle_mouse(mouse_event);
+            }
             Event::Resize(_, _) => {}
         }
 
@@ -133,11 +198,24 @@ fn start_tui(tabs: Vec<(String, DataFrame)>) -> AppResult<()> {
 
     // Exit the user interface.
     tui.exit()?;
-    Ok(())
+    let _ = history().store();
+    app.selected_data_frame()
+}
+
+fn print_table(format: PrintFormat, df: &mut DataFrame) -> AppResult<()> {
+    let format = match format {
+        PrintFormat::Csv => TextFormat::Csv,
+        PrintFormat::Tsv => TextFormat::Tsv,
+        PrintFormat::Json => TextFormat::Json,
+        PrintFormat::Md => TextFormat::Md,
+    };
+    let mut stdout = io::stdout().lock();
+    text_writer(format).write(&mut stdout, df)?;
+    Ok(stdout.flush()?)
 }
 
 fn try_read_path(args: &Args, path: &PathBuf) -> AppResult<Box<[(String, DataFrame)]>> {
-    let source = Source::File(path.clone());
+    let source = Source::from(path.to_string_lossy().into_owned());
     let reader = args.build_reader(path)?;
     reader.named_frames(source.clone())
 }
@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Timelike};
+use polars::prelude::{AnyValue, DataType, TimeUnit};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+use super::polars_ext::AnyValueExt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, EnumIter, Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum Notation {
+    Plain,
+    Sci,
+    Thousands,
+}
+
+/// On-screen format of date and datetime cells. Datetimes with a time zone keep their default
+/// rendering unless they are shown relative to now.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    EnumString,
+    EnumIter,
+    Display,
+    Serialize,
+    Deserialize,
+)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TimeFormat {
+    #[default]
+    Default,
+    Iso,
+    Short,
+    Relative,
+}
+
+/// Render-time formatting of table cells, the underlying data is never changed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CellFormat {
+    precision: Option<usize>,
+    notations: HashMap<String, Notation>,
+    separator: Option<String>,
+    time: TimeFormat,
+    now: Option<NaiveDateTime>,
+}
+
+impl CellFormat {
+    pub fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    pub fn set_precision(&mut self, precision: Option<usize>) {
+        self.precision = precision;
+    }
+
+    pub fn set_notation(&mut self, column: impl Into<String>, notation: Notation) {
+        self.notations.insert(column.into(), notation);
+    }
+
+    /// Fills in the configured digit separator and, unless this format has its own, the float
+    /// precision.
+    pub fn with_defaults(&self, separator: Option<String>, precision: Option<usize>) -> Self {
+        Self {
+            precision: self.precision.or(precision),
+            notations: self.notations.clone(),
+            separator,
+            ..self.clone()
+        }
+    }
+
+    /// Sets the format of temporal cells, relative times are measured from `now` truncated to the
+    /// minute so the format only changes once a minute.
+    pub fn with_time(self, time: TimeFormat, now: NaiveDateTime) -> Self {
+        Self {
+            time,
+            now: (time == TimeFormat::Relative).then(|| {
+                now.with_second(0)
+                    .and_then(|now| now.with_nanosecond(0))
+                    .unwrap_or(now)
+            }),
+            ..self
+        }
+    }
+
+    /// Returns true if values of the column would be rendered differently than the default.
+    pub fn affects(&self, column: &str, dtype: &DataType) -> bool {
+        self.notations.contains_key(column)
+            || (dtype.is_float() && self.precision.is_some())
+            || (dtype.is_primitive_numeric() && self.separator.is_some())
+            || match dtype {
+                DataType::Datetime(_, _) => self.time != TimeFormat::Default,
+                DataType::Date => self.time == TimeFormat::Relative,
+                _ => false,
+            }
+    }
+
+    pub fn format(&self, column: &str, value: AnyValue) -> String {
+        if let Some(time) = self.format_time(&value) {
+            return time;
+        }
+        let notation = self
+            .notations
+            .get(column)
+            .or(self.separator.as_ref().map(|_| &Notation::Thousands));
+        match notation {
+            Some(notation) if value.dtype().is_primitive_numeric() => {
+                let num = value.extract::<f64>().unwrap_or_default();
+                let plain = match value {
+                    AnyValue::Float32(_) | AnyValue::Float64(_) => {
+                        value.into_single_line_with_precision(self.precision)
+                    }
+                    _ => value.to_string(),
+                };
+                match notation {
+                    Notation::Plain => plain,
+                    Notation::Thousands => {
+                        group_digits(&plain, self.separator.as_deref().unwrap_or(","))
+                    }
+                    Notation::Sci => match self.precision {
+                        Some(p) => format!("{num:.p$e}"),
+                        None => format!("{num:e}"),
+                    },
+                }
+            }
+            _ => value.into_single_line_with_precision(self.precision),
+        }
+    }
+
+    fn format_time(&self, value: &AnyValue) -> Option<String> {
+        let (timestamp, unit, zoned) = match value {
+            AnyValue::Date(days) => {
+                let now = self.now?;
+                let date = NaiveDate::default()
+                    .checked_add_signed(chrono::Duration::days(*days as i64))?;
+                return Some(match (now.date() - date).num_days() {
+                    0 => "today".to_owned(),
+                    days => relative_time(days * 86_400),
+                });
+            }
+            AnyValue::Datetime(timestamp, unit, zone) => (*timestamp, *unit, zone.is_some()),
+            AnyValue::DatetimeOwned(timestamp, unit, zone) => (*timestamp, *unit, zone.is_some()),
+            _ => return None,
+        };
+        let datetime = match unit {
+            TimeUnit::Nanoseconds => DateTime::from_timestamp_nanos(timestamp),
+            TimeUnit::Microseconds => DateTime::from_timestamp_micros(timestamp)?,
+            TimeUnit::Milliseconds => DateTime::from_timestamp_millis(timestamp)?,
+        }
+        .naive_utc();
+        match (self.time, zoned) {
+            (TimeFormat::Relative, _) => Some(relative_time((self.now? - datetime).num_seconds())),
+            (TimeFormat::Iso, false) => Some(datetime.format("%Y-%m-%dT%H:%M:%S%.f").to_string()),
+            (TimeFormat::Short, false) => Some(datetime.format("%Y-%m-%d %H:%M").to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Describes a span like "3 days ago" or "in 2 hours" in its largest whole unit, positive
+/// seconds lie in the past.
+pub fn relative_time(seconds: i64) -> String {
+    const UNITS: [(i64, &str); 6] = [
+        (31_536_000, "year"),
+        (2_592_000, "month"),
+        (604_800, "week"),
+        (86_400, "day"),
+        (3_600, "hour"),
+        (60, "minute"),
+    ];
+    let Some((count, unit)) = UNITS
+        .iter()
+        .find(|(len, _)| seconds.saturating_abs() >= *len)
+        .map(|(len, unit)| (seconds.saturating_abs() / len, unit))
+    else {
+        return "just now".to_owned();
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    if seconds > 0 {
+        format!("{count} {unit}{plural} ago")
+    } else {
+        format!("in {count} {unit}{plural}")
+    }
+}
+
+/// Inserts a comma between every three digits of the integer part of a formatted number.
+pub fn group_thousands(number: &str) -> String {
+    group_digits(number, ",")
+}
+
+/// Inserts the separator between every three digits of the integer part of a formatted number.
+pub fn group_digits(number: &str, separator: &str) -> String {
+    let (sign, rest) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number),
+    };
+    let (int, frac) = match rest.find('.') {
+        Some(idx) => rest.split_at(idx),
+        None => (rest, ""),
+    };
+    if !int.chars().all(|c| c.is_ascii_digit()) {
+        return number.to_owned();
+    }
+    let mut grouped = String::with_capacity(number.len() + int.len() / 3 * separator.len());
+    grouped.push_str(sign);
+    for (idx, c) in int.chars().enumerate() {
+        if idx > 0 && (int.len() - idx) % 3 == 0 {
+            grouped.push_str(separator);
+        }
+        grouped.push(c);
+    }
+    grouped.push_str(frac);
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_thousands() {
+        assert_eq!(group_thousands("0"), "0");
+        assert_eq!(group_thousands("999"), "999");
+        assert_eq!(group_thousands("1000"), "1,000");
+        assert_eq!(group_thousands("1234567.891"), "1,234,567.891");
+        assert_eq!(group_thousands("-123456"), "-123,456");
+        assert_eq!(group_thousands("inf"), "inf");
+    }
+
+    #[test]
+    fn test_format_notation() {
+        let mut fmt = CellFormat::default();
+        fmt.set_notation("a", Notation::Sci);
+        fmt.set_notation("b", Notation::Thousands);
+        assert_eq!(fmt.format("a", AnyValue::Float64(1500.0)), "1.5e3");
+        assert_eq!(fmt.format("b", AnyValue::Int64(1500000)), "1,500,000");
+        assert_eq!(fmt.format("c", AnyValue::Float64(1500.25)), "1500.25");
+        fmt.set_precision(Some(1));
+        assert_eq!(fmt.format("b", AnyValue::Float64(1500.25)), "1,500.2");
+        assert_eq!(fmt.format("c", AnyValue::Float64(1500.25)), "1500.2");
+    }
+
+    #[test]
+    fn test_format_defaults() {
+        let mut fmt = CellFormat::default();
+        fmt.set_notation("a", Notation::Plain);
+        let with_defaults = fmt.with_defaults(Some(" ".to_owned()), Some(2));
+        assert_eq!(
+            with_defaults.format("a", AnyValue::Int64(1500000)),
+            "1500000"
+        );
+        assert_eq!(
+            with_defaults.format("b", AnyValue::Int64(1500000)),
+            "1 500 000"
+        );
+        assert_eq!(
+            with_defaults.format("c", AnyValue::Float64(1500.256)),
+            "1 500.26"
+        );
+        assert_eq!(with_defaults.format("d", AnyValue::String("1500")), "1500");
+        assert!(with_defaults.affects("b", &DataType::Int64));
+        assert!(!fmt.affects("b", &DataType::Int64));
+
+        fmt.set_precision(Some(0));
+        let with_defaults = fmt.with_defaults(None, Some(2));
+        assert_eq!(
+            with_defaults.format("c", AnyValue::Float64(1500.256)),
+            "1500"
+        );
+    }
+
+    #[test]
+    fn test_format_time() {
+        let now = NaiveDate::from_ymd_opt(2024, 3, 10)
+            .unwrap()
+            .and_hms_opt(12, 30, 0)
+            .unwrap();
+        let value = |datetime: NaiveDateTime| {
+            AnyValue::Datetime(
+                datetime.and_utc().timestamp_millis(),
+                TimeUnit::Milliseconds,
+                None,
+            )
+        };
+        let past = now - chrono::Duration::days(3);
+        let iso = CellFormat::default().with_time(TimeFormat::Iso, now);
+        assert_eq!(iso.format("t", value(past)), "2024-03-07T12:30:00");
+        let short = CellFormat::default().with_time(TimeFormat::Short, now);
+        assert_eq!(short.format("t", value(past)), "2024-03-07 12:30");
+        assert!(!short.affects("d", &DataType::Date));
+
+        let relative = CellFormat::default().with_time(TimeFormat::Relative, now);
+        assert_eq!(relative.format("t", value(past)), "3 days ago");
+        assert_eq!(
+            relative.format("t", value(now + chrono::Duration::hours(2))),
+            "in 2 hours"
+        );
+        assert_eq!(relative.format("t", value(now)), "just now");
+        let today = (now.date() - NaiveDate::default()).num_days() as i32;
+        assert_eq!(relative.format("d", AnyValue::Date(today)), "today");
+        assert_eq!(relative.format("d", AnyValue::Date(today - 1)), "1 day ago");
+        assert!(relative.affects("d", &DataType::Date));
+        assert_eq!(relative_time(400 * 86_400), "1 year ago");
+    }
+}
@@ -1,15 +1,21 @@
 use std::{
+    cell::RefCell,
     fs,
     ops::Deref,
+    path::Path,
     sync::{
         OnceLock, RwLock,
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-use crate::{AppResult, misc::paths::config_path, tui::themes::theme::LoadedTheme};
+use crate::{
+    AppResult,
+    misc::{cell_format::TimeFormat, paths::config_path},
+    tui::themes::theme::LoadedTheme,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(default)]
@@ -17,32 +23,80 @@ pub struct Config {
     theme: RwLock<LoadedTheme>,
     show_table_borders: AtomicBool,
     show_table_row_numbers: AtomicBool,
+    zebra_stripes: AtomicBool,
+    history_size: AtomicUsize,
+    mouse: AtomicBool,
+    thousands_separator: RwLock<String>,
+    float_precision: RwLock<Option<usize>>,
+    format_numbers: AtomicBool,
+    time_format: RwLock<TimeFormat>,
+    max_column_width: RwLock<Option<usize>>,
+    confirm_reset: AtomicBool,
+    message_log_size: AtomicUsize,
+    clipboard_max_bytes: AtomicUsize,
 }
 
 impl Config {
+    /// Replaces the settings with the ones stored in the config file, the theme included.
     pub fn reload(&self) -> AppResult<()> {
-        let path = config_path()?;
+        self.reload_from(&config_path()?)
+    }
+
+    fn reload_from(&self, path: &Path) -> AppResult<()> {
         let contents = fs::read_to_string(path)?;
         let Config {
             theme,
             show_table_borders: table_borders,
             show_table_row_numbers: table_row_numbers,
+            zebra_stripes,
+            history_size,
+            mouse,
+            thousands_separator,
+            float_precision,
+            format_numbers,
+            time_format,
+            max_column_width,
+            confirm_reset,
+            message_log_size,
+            clipboard_max_bytes,
         } = toml::from_str(&contents)?;
         self.set_theme(theme.into_inner()?);
         self.show_table_borders
             .swap(table_borders.into_inner(), Ordering::Relaxed);
         self.show_table_row_numbers
             .swap(table_row_numbers.into_inner(), Ordering::Relaxed);
+        self.zebra_stripes
+            .swap(zebra_stripes.into_inner(), Ordering::Relaxed);
+        self.history_size
+            .swap(history_size.into_inner(), Ordering::Relaxed);
+        self.mouse.swap(mouse.into_inner(), Ordering::Relaxed);
+        *self.thousands_separator.write().unwrap() = thousands_separator.into_inner()?;
+        *self.float_precision.write().unwrap() = float_precision.into_inner()?;
+        self.format_numbers
+            .swap(format_numbers.into_inner(), Ordering::Relaxed);
+        *self.time_format.write().unwrap() = time_format.into_inner()?;
+        *self.max_column_width.write().unwrap() = max_column_width.into_inner()?;
+        self.confirm_reset
+            .swap(confirm_reset.into_inner(), Ordering::Relaxed);
+        self.message_log_size
+            .swap(message_log_size.into_inner(), Ordering::Relaxed);
+        self.clipboard_max_bytes
+            .swap(clipboard_max_bytes.into_inner(), Ordering::Relaxed);
         Ok(())
     }
 
+    /// Writes the current settings to the config file, the active theme is stored by its name
+    /// so the next run starts with it.
     pub fn store(&self) -> AppResult<()> {
-        let config_path = config_path()?;
-        if let Some(parent) = config_path.parent() {
+        self.store_to(&config_path()?)
+    }
+
+    fn store_to(&self, path: &Path) -> AppResult<()> {
+        if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
         let contents = toml::to_string_pretty(self)?;
-        Ok(fs::write(config_path, contents)?)
+        Ok(fs::write(path, contents)?)
     }
 
     pub fn theme(&self) -> impl Deref<Target = LoadedTheme> {
@@ -69,6 +123,75 @@ impl Config {
         self.show_table_row_numbers
             .fetch_xor(true, Ordering::Relaxed);
     }
+
+    /// Default for tabs that have not toggled the alternating row colors themselves.
+    pub fn zebra_stripes(&self) -> bool {
+        self.zebra_stripes.load(Ordering::Relaxed)
+    }
+
+    /// Maximum number of queries kept in the history file.
+    pub fn history_size(&self) -> usize {
+        self.history_size.load(Ordering::Relaxed)
+    }
+
+    /// Whether mouse events are captured, which disables the text selection of the terminal.
+    pub fn mouse(&self) -> bool {
+        self.mouse.load(Ordering::Relaxed)
+    }
+
+    /// Separator grouping the digits of numeric cells on screen, None if unset or toggled off.
+    pub fn thousands_separator(&self) -> Option<String> {
+        let separator = self.thousands_separator.read().unwrap();
+        (self.format_numbers() && !separator.is_empty()).then(|| separator.clone())
+    }
+
+    /// Decimal places of float cells on screen for tabs without their own precision.
+    pub fn float_precision(&self) -> Option<usize> {
+        self.format_numbers()
+            .then(|| *self.float_precision.read().unwrap())
+            .flatten()
+    }
+
+    pub fn format_numbers(&self) -> bool {
+        self.format_numbers.load(Ordering::Relaxed)
+    }
+
+    pub fn toggle_format_numbers(&self) {
+        self.format_numbers.fetch_xor(true, Ordering::Relaxed);
+    }
+
+    /// On-screen format of date and datetime cells, exports always write the raw values.
+    pub fn time_format(&self) -> TimeFormat {
+        *self.time_format.read().unwrap()
+    }
+
+    pub fn set_time_format(&self, time_format: TimeFormat) {
+        *self.time_format.write().unwrap() = time_format;
+    }
+
+    /// Upper bound of the on-screen width of every column, None leaves them unbounded.
+    pub fn max_column_width(&self) -> Option<usize> {
+        *self.max_column_width.read().unwrap()
+    }
+
+    pub fn set_max_column_width(&self, max_column_width: Option<usize>) {
+        *self.max_column_width.write().unwrap() = max_column_width;
+    }
+
+    /// Whether resetting a tab with views on top of its table asks first.
+    pub fn confirm_reset(&self) -> bool {
+        self.confirm_reset.load(Ordering::Relaxed)
+    }
+
+    /// Maximum number of toasts and errors kept for the messages command.
+    pub fn message_log_size(&self) -> usize {
+        self.message_log_size.load(Ordering::Relaxed)
+    }
+
+    /// Largest table in bytes that is copied to the clipboard.
+    pub fn clipboard_max_bytes(&self) -> usize {
+        self.clipboard_max_bytes.load(Ordering::Relaxed)
+    }
 }
 
 impl Default for Config {
@@ -77,6 +200,17 @@ impl Default for Config {
             theme: RwLock::new(LoadedTheme::default()),
             show_table_borders: AtomicBool::new(true),
             show_table_row_numbers: AtomicBool::new(true),
+            zebra_stripes: AtomicBool::new(true),
+            history_size: AtomicUsize::new(1000),
+            mouse: AtomicBool::new(false),
+            thousands_separator: RwLock::new(String::new()),
+            float_precision: RwLock::new(None),
+            format_numbers: AtomicBool::new(true),
+            time_format: RwLock::new(TimeFormat::Default),
+            max_column_width: RwLock::new(None),
+            confirm_reset: AtomicBool::new(true),
+            message_log_size: AtomicUsize::new(100),
+            clipboard_max_bytes: AtomicUsize::new(1 << 20),
         }
     }
 }
@@ -86,6 +220,81 @@ pub fn config() -> &'static Config {
     CONFIG.get_or_init(Config::default)
 }
 
+thread_local! {
+    static TAB_THEME: RefCell<Option<LoadedTheme>> = const { RefCell::new(None) };
+}
+
+/// Theme of the tab being rendered if it overrides one, the global theme otherwise.
 pub fn theme() -> impl Deref<Target = LoadedTheme> {
-    config().theme()
+    ThemeRef(
+        TAB_THEME
+            .with_borrow(Clone::clone)
+            .unwrap_or_else(|| config().theme().clone()),
+    )
+}
+
+struct ThemeRef(LoadedTheme);
+
+impl Deref for ThemeRef {
+    type Target = LoadedTheme;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Makes [`theme`] return the given theme on this thread until dropped, None keeps the global
+/// theme.
+pub struct ThemeOverride(Option<LoadedTheme>);
+
+impl ThemeOverride {
+    pub fn new(theme: Option<LoadedTheme>) -> Self {
+        Self(TAB_THEME.replace(theme))
+    }
+}
+
+impl Drop for ThemeOverride {
+    fn drop(&mut self) {
+        TAB_THEME.set(self.0.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::themes::theme::Theme;
+
+    #[test]
+    fn test_theme_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tabiew").join("config.toml");
+        let config = Config::default();
+        config.store_to(&path).unwrap();
+        config.reload_from(&path).unwrap();
+        assert_eq!(
+            config.theme().app_theme(),
+            LoadedTheme::default().app_theme()
+        );
+
+        config.set_theme(Theme::Andromeda);
+        config.store_to(&path).unwrap();
+        let reloaded = Config::default();
+        reloaded.reload_from(&path).unwrap();
+        assert_eq!(reloaded.theme().app_theme(), &Theme::Andromeda);
+    }
+
+    #[test]
+    fn test_theme_override() {
+        let global = *theme().app_theme();
+        {
+            let _theme = ThemeOverride::new(Some(Theme::Andromeda.into()));
+            assert_eq!(theme().app_theme(), &Theme::Andromeda);
+            {
+                let _theme = ThemeOverride::new(None);
+                assert_eq!(theme().app_theme(), &global);
+            }
+            assert_eq!(theme().app_theme(), &Theme::Andromeda);
+        }
+        assert_eq!(theme().app_theme(), &global);
+    }
 }
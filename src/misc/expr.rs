@@ -0,0 +1,332 @@
+use std::{iter::Peekable, str::Chars};
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use polars::prelude::{Expr, Operator, RoundMode, binary_expr, coalesce, col, lit};
+
+use crate::{AppResult, misc::type_ext::HasSubsequence};
+
+const FUNCTIONS: [&str; 8] = [
+    "abs", "coalesce", "cummax", "cummin", "cumprod", "cumsum", "round", "sqrt",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Int(i64),
+    Float(f64),
+    Ident(String),
+    /// Double quoted column name, never taken for a function.
+    Quoted(String),
+    Op(char),
+}
+
+/// Splits `name = expression` into the name of the computed column and the expression, the
+/// columns the expression refers to have to be one of the given ones.
+pub fn parse_computed_column(spec: &str, columns: &[String]) -> AppResult<(String, Expr)> {
+    let (name, expr) = spec.split_once('=').ok_or(anyhow!(
+        "Expected 'name = expression', e.g. 'total = price * qty'"
+    ))?;
+    let name = name.trim().trim_matches('"');
+    if name.is_empty() {
+        Err(anyhow!("The computed column needs a name"))?
+    }
+    Ok((name.to_owned(), parse_expr(expr, columns)?))
+}
+
+/// Parses arithmetic over columns and numbers with `+ - * / %`, parentheses and the functions
+/// in [`FUNCTIONS`] into a polars expression.
+pub fn parse_expr(text: &str, columns: &[String]) -> AppResult<Expr> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?.into_iter().peekable(),
+        columns,
+    };
+    let expr = parser.sum()?;
+    match parser.tokens.next() {
+        None => Ok(expr),
+        Some(token) => Err(anyhow!(
+            "Unexpected {} after the expression",
+            describe(&token)
+        )),
+    }
+}
+
+fn tokenize(text: &str) -> AppResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '0'..='9' | '.' => tokens.push(number(&mut chars)?),
+            c if c.is_alphabetic() || c == '_' => {
+                let ident = take_while(&mut chars, |c| c.is_alphanumeric() || c == '_');
+                tokens.push(Token::Ident(ident));
+            }
+            '"' => {
+                chars.next();
+                let quoted = take_while(&mut chars, |c| c != '"');
+                if chars.next() != Some('"') {
+                    Err(anyhow!("Unterminated quoted column name \"{quoted}"))?
+                }
+                tokens.push(Token::Quoted(quoted));
+            }
+            '+' | '-' | '*' | '/' | '%' | '(' | ')' | ',' => {
+                chars.next();
+                tokens.push(Token::Op(c));
+            }
+            c => Err(anyhow!("Unexpected character '{c}'"))?,
+        }
+    }
+    Ok(tokens)
+}
+
+fn number(chars: &mut Peekable<Chars>) -> AppResult<Token> {
+    let text = take_while(chars, |c| c.is_ascii_digit() || c == '.');
+    if text.contains('.') {
+        Ok(Token::Float(
+            text.parse()
+                .map_err(|_| anyhow!("Invalid number '{text}'"))?,
+        ))
+    } else {
+        Ok(Token::Int(
+            text.parse()
+                .map_err(|_| anyhow!("Invalid number '{text}'"))?,
+        ))
+    }
+}
+
+fn take_while(chars: &mut Peekable<Chars>, pred: impl Fn(char) -> bool) -> String {
+    let mut text = String::new();
+    while let Some(c) = chars.next_if(|c| pred(*c)) {
+        text.push(c);
+    }
+    text
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Int(value) => format!("'{value}'"),
+        Token::Float(value) => format!("'{value}'"),
+        Token::Ident(ident) => format!("'{ident}'"),
+        Token::Quoted(quoted) => format!("'\"{quoted}\"'"),
+        Token::Op(op) => format!("'{op}'"),
+    }
+}
+
+struct Parser<'a, I: Iterator<Item = Token>> {
+    tokens: Peekable<I>,
+    columns: &'a [String],
+}
+
+impl<I: Iterator<Item = Token>> Parser<'_, I> {
+    fn sum(&mut self) -> AppResult<Expr> {
+        let mut expr = self.product()?;
+        while let Some(Token::Op(op @ ('+' | '-'))) = self.tokens.peek().cloned() {
+            self.tokens.next();
+            let rhs = self.product()?;
+            expr = if op == '+' { expr + rhs } else { expr - rhs };
+        }
+        Ok(expr)
+    }
+
+    fn product(&mut self) -> AppResult<Expr> {
+        let mut expr = self.unary()?;
+        while let Some(Token::Op(op @ ('*' | '/' | '%'))) = self.tokens.peek().cloned() {
+            self.tokens.next();
+            let rhs = self.unary()?;
+            expr = match op {
+                '*' => expr * rhs,
+                // Dividing integers gives a fraction, like it does in a spreadsheet
+                '/' => binary_expr(expr, Operator::TrueDivide, rhs),
+                _ => expr % rhs,
+            };
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> AppResult<Expr> {
+        if self.tokens.next_if_eq(&Token::Op('-')).is_some() {
+            Ok(lit(0) - self.unary()?)
+        } else {
+            self.primary()
+        }
+    }
+
+    fn primary(&mut self) -> AppResult<Expr> {
+        match self.tokens.next() {
+            Some(Token::Int(value)) => Ok(lit(value)),
+            Some(Token::Float(value)) => Ok(lit(value)),
+            Some(Token::Op('(')) => {
+                let expr = self.sum()?;
+                self.expect(')')?;
+                Ok(expr)
+            }
+            Some(Token::Ident(name)) if self.tokens.next_if_eq(&Token::Op('(')).is_some() => {
+                let args = self.args()?;
+                function(&name, args)
+            }
+            Some(Token::Ident(name)) | Some(Token::Quoted(name)) => self.column(&name),
+            Some(token) => Err(anyhow!("Unexpected {}", describe(&token))),
+            None => Err(anyhow!("Unexpected end of the expression")),
+        }
+    }
+
+    fn args(&mut self) -> AppResult<Vec<Expr>> {
+        let mut args = Vec::new();
+        if self.tokens.next_if_eq(&Token::Op(')')).is_some() {
+            return Ok(args);
+        }
+        loop {
+            args.push(self.sum()?);
+            match self.tokens.next() {
+                Some(Token::Op(',')) => (),
+                Some(Token::Op(')')) => return Ok(args),
+                Some(token) => Err(anyhow!("Expected ',' or ')', found {}", describe(&token)))?,
+                None => Err(anyhow!("Missing ')'"))?,
+            }
+        }
+    }
+
+    fn expect(&mut self, op: char) -> AppResult<()> {
+        match self.tokens.next() {
+            Some(Token::Op(found)) if found == op => Ok(()),
+            Some(token) => Err(anyhow!("Expected '{op}', found {}", describe(&token))),
+            None => Err(anyhow!("Missing '{op}'")),
+        }
+    }
+
+    fn column(&self, name: &str) -> AppResult<Expr> {
+        if self.columns.iter().any(|column| column == name) {
+            return Ok(col(name));
+        }
+        let lower = name.to_lowercase();
+        let matches = self
+            .columns
+            .iter()
+            .filter(|column| column.to_lowercase().has_subsequence(&lower))
+            .join(", ");
+        if matches.is_empty() {
+            Err(anyhow!("Unknown column '{name}'"))
+        } else {
+            Err(anyhow!("Unknown column '{name}', close matches: {matches}"))
+        }
+    }
+}
+
+fn function(name: &str, args: Vec<Expr>) -> AppResult<Expr> {
+    let name = name.to_lowercase();
+    let unary = |expr: fn(Expr) -> Expr| match args.as_slice() {
+        [arg] => Ok(expr(arg.clone())),
+        _ => Err(anyhow!("{name} takes 1 argument, got {}", args.len())),
+    };
+    match name.as_str() {
+        "abs" => unary(Expr::abs),
+        "sqrt" => unary(Expr::sqrt),
+        "cumsum" => unary(|arg| arg.cum_sum(false)),
+        "cumprod" => unary(|arg| arg.cum_prod(false)),
+        "cummax" => unary(|arg| arg.cum_max(false)),
+        "cummin" => unary(|arg| arg.cum_min(false)),
+        "round" => match args.as_slice() {
+            [arg] => Ok(arg.clone().round(0, RoundMode::HalfAwayFromZero)),
+            [arg, Expr::Literal(decimals)] => {
+                let decimals = decimals
+                    .to_any_value()
+                    .and_then(|decimals| decimals.extract::<u32>())
+                    .ok_or(anyhow!("round takes a whole number of decimals"))?;
+                Ok(arg.clone().round(decimals, RoundMode::HalfAwayFromZero))
+            }
+            [_, _] => Err(anyhow!("round takes a whole number of decimals")),
+            _ => Err(anyhow!("round takes 1 or 2 arguments, got {}", args.len())),
+        },
+        "coalesce" if !args.is_empty() => Ok(coalesce(&args)),
+        "coalesce" => Err(anyhow!("coalesce takes at least 1 argument")),
+        _ => Err(anyhow!(
+            "Unknown function '{name}', supported: {}",
+            FUNCTIONS.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::{
+        df,
+        prelude::{DataType, IntoLazy},
+    };
+
+    use super::*;
+
+    fn evaluate(spec: &str) -> AppResult<Vec<Option<f64>>> {
+        let df = df!(
+            "price" => [2.0, 1.5, 4.0],
+            "qty" => [3, 2, 1],
+            "unit price" => [Some(1.0), None, Some(-3.0)],
+        )
+        .unwrap();
+        let columns = df
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect_vec();
+        let (name, expr) = parse_computed_column(spec, &columns)?;
+        let df = df.lazy().with_column(expr.alias(&name)).collect()?;
+        Ok(df
+            .column(&name)?
+            .cast(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .collect())
+    }
+
+    #[test]
+    fn test_parse_computed_column() {
+        assert_eq!(
+            evaluate("total = price * qty").unwrap(),
+            [Some(6.0), Some(3.0), Some(4.0)]
+        );
+        assert_eq!(
+            evaluate("x = 1 + 2 * (qty - -1) / 4").unwrap(),
+            [Some(3.0), Some(2.5), Some(2.0)]
+        );
+        assert_eq!(
+            evaluate("running = cumsum(qty)").unwrap(),
+            [Some(3.0), Some(5.0), Some(6.0)]
+        );
+        assert_eq!(
+            evaluate("a = abs(\"unit price\")").unwrap(),
+            [Some(1.0), None, Some(3.0)]
+        );
+        assert_eq!(
+            evaluate("r = round(price / qty, 2)").unwrap(),
+            [Some(0.67), Some(0.75), Some(4.0)]
+        );
+        assert_eq!(
+            evaluate("c = coalesce(\"unit price\", 0)").unwrap(),
+            [Some(1.0), Some(0.0), Some(-3.0)]
+        );
+        assert_eq!(
+            evaluate("t = prices * qty").unwrap_err().to_string(),
+            "Unknown column 'prices'"
+        );
+        assert_eq!(
+            evaluate("t = prc * qty").unwrap_err().to_string(),
+            "Unknown column 'prc', close matches: price, unit price"
+        );
+        assert_eq!(
+            evaluate("t = median(qty)").unwrap_err().to_string(),
+            "Unknown function 'median', supported: abs, coalesce, cummax, cummin, cumprod, \
+             cumsum, round, sqrt"
+        );
+        assert_eq!(
+            evaluate("t = abs(qty, 2)").unwrap_err().to_string(),
+            "abs takes 1 argument, got 2"
+        );
+        assert_eq!(
+            evaluate("t = (qty + 1").unwrap_err().to_string(),
+            "Missing ')'"
+        );
+        assert!(evaluate("price * qty").is_err());
+        assert!(evaluate("t = qty qty").is_err());
+    }
+}
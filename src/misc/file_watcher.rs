@@ -0,0 +1,189 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc::{Receiver, channel},
+    time::{Duration, Instant},
+};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::AppResult;
+
+/// Time a file has to stay unchanged before a change is reported, so a burst of writes
+/// results in a single reload.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Detects changes of a file from the change notifications of the file system.
+#[derive(Debug)]
+pub struct FileWatcher {
+    path: PathBuf,
+    events: Receiver<notify::Result<Event>>,
+    changed_at: Option<Instant>,
+    _watcher: RecommendedWatcher,
+}
+
+impl FileWatcher {
+    /// Watches the directory of the file, editors that save by replacing the file would end a
+    /// watch on the file itself.
+    pub fn new(path: impl Into<PathBuf>) -> AppResult<Self> {
+        let path = path.into();
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        }
+        .canonicalize()?;
+        let path = match path.file_name() {
+            Some(name) => dir.join(name),
+            None => path,
+        };
+        let (sender, events) = channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+        Ok(Self {
+            path,
+            events,
+            changed_at: None,
+            _watcher: watcher,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns true once the file has changed and then stayed unchanged for the debounce
+    /// period. A missing file is never reported, it is likely in the middle of being rewritten.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        // Reading the file is reported as an access and must not trigger another reload. All
+        // pending events are drained, the rest of a save must not restart the debounce later
+        let changed = self
+            .events
+            .try_iter()
+            .filter(|event| {
+                event.as_ref().is_ok_and(|event| {
+                    matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                        && event.paths.contains(&self.path)
+                })
+            })
+            .count()
+            > 0;
+        if changed {
+            self.changed_at = Some(now);
+        }
+        match self.changed_at {
+            Some(changed_at) if now - changed_at >= DEBOUNCE && self.path.exists() => {
+                self.changed_at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, sync::mpsc::Sender, thread};
+
+    use notify::event::{AccessKind, CreateKind, DataChange, ModifyKind, RenameMode};
+
+    use super::*;
+
+    /// Watcher fed with the events sent by the test instead of the file system.
+    fn fake_watcher(path: &Path) -> (FileWatcher, Sender<notify::Result<Event>>) {
+        let (sender, events) = channel();
+        let watcher = FileWatcher {
+            path: path.to_owned(),
+            events,
+            changed_at: None,
+            _watcher: notify::recommended_watcher(|_| ()).unwrap(),
+        };
+        (watcher, sender)
+    }
+
+    fn event(kind: EventKind, path: &Path) -> notify::Result<Event> {
+        Ok(Event::new(kind).add_path(path.to_owned()))
+    }
+
+    #[test]
+    fn test_poll_debounces_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        fs::write(&path, "a,b\n").unwrap();
+        let (mut watcher, sender) = fake_watcher(&path);
+        let modify = || event(EventKind::Modify(ModifyKind::Data(DataChange::Any)), &path);
+        let start = Instant::now();
+        assert!(!watcher.poll(start));
+
+        sender.send(modify()).unwrap();
+        assert!(!watcher.poll(start));
+        sender.send(modify()).unwrap();
+        assert!(!watcher.poll(start + DEBOUNCE / 2));
+        assert!(!watcher.poll(start + DEBOUNCE));
+        assert!(watcher.poll(start + DEBOUNCE / 2 + DEBOUNCE));
+        assert!(!watcher.poll(start + DEBOUNCE * 3));
+    }
+
+    #[test]
+    fn test_poll_ignores_reads_and_other_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        let tmp = dir.path().join("data.csv.tmp");
+        fs::write(&path, "a,b\n").unwrap();
+        let (mut watcher, sender) = fake_watcher(&path);
+        let start = Instant::now();
+
+        sender
+            .send(event(EventKind::Access(AccessKind::Any), &path))
+            .unwrap();
+        sender
+            .send(event(EventKind::Create(CreateKind::File), &tmp))
+            .unwrap();
+        assert!(!watcher.poll(start));
+        assert!(!watcher.poll(start + DEBOUNCE * 2));
+
+        // Saving by replacing the file reports the rename a few times, it is a single change
+        for mode in [RenameMode::From, RenameMode::To, RenameMode::Both] {
+            let kind = EventKind::Modify(ModifyKind::Name(mode));
+            sender
+                .send(Ok(Event::new(kind)
+                    .add_path(tmp.clone())
+                    .add_path(path.clone())))
+                .unwrap();
+        }
+        assert!(!watcher.poll(start + DEBOUNCE * 3));
+        assert!(watcher.poll(start + DEBOUNCE * 4));
+        assert!(!watcher.poll(start + DEBOUNCE * 5));
+    }
+
+    #[test]
+    fn test_poll_waits_for_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        let (mut watcher, sender) = fake_watcher(&path);
+        let start = Instant::now();
+
+        sender
+            .send(event(EventKind::Create(CreateKind::File), &path))
+            .unwrap();
+        assert!(!watcher.poll(start));
+        assert!(!watcher.poll(start + DEBOUNCE));
+        fs::write(&path, "a,b\n").unwrap();
+        assert!(watcher.poll(start + DEBOUNCE * 2));
+    }
+
+    #[test]
+    fn test_new_watches_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        fs::write(&path, "a,b\n").unwrap();
+        let mut watcher = FileWatcher::new(&path).unwrap();
+        fs::write(&path, "a,b\n1,2\n").unwrap();
+
+        // The file system delivers the change at some point, a loaded machine may take a while
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while watcher.changed_at.is_none() && Instant::now() < deadline {
+            watcher.poll(Instant::now());
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(watcher.changed_at.is_some());
+    }
+}
@@ -5,11 +5,16 @@ use std::{
     sync::{Mutex, MutexGuard, Once, OnceLock, RwLockReadGuard},
 };
 
+use clap::Parser;
+
+use crate::args::Args;
+
 use super::sql::SqlBackend;
 
 // static CONFIG: Config = Config::new();
 static SQL_BACKEND: Mutex<Lazy<SqlBackend>> = Mutex::new(Lazy::new(SqlBackend::default));
 static STDIN_CONTENT: OnceLock<Vec<u8>> = OnceLock::new();
+static ARGS: OnceLock<Args> = OnceLock::new();
 
 pub fn sql() -> impl DerefMut<Target = SqlBackend> {
     Global {
@@ -17,6 +22,16 @@ pub fn sql() -> impl DerefMut<Target = SqlBackend> {
     }
 }
 
+/// Keeps the command line arguments around so files can be re-read with the same options.
+pub fn set_args(args: Args) -> &'static Args {
+    ARGS.get_or_init(|| args)
+}
+
+/// Arguments given to [`set_args`], or the defaults if it was never called.
+pub fn args() -> &'static Args {
+    ARGS.get_or_init(|| Args::parse_from(["tw"]))
+}
+
 pub fn stdin() -> Cursor<&'static Vec<u8>> {
     Cursor::new(STDIN_CONTENT.get_or_init(|| {
         let mut buf = Vec::new();
@@ -0,0 +1,102 @@
+use std::{
+    collections::VecDeque,
+    fs,
+    sync::{Mutex, MutexGuard, OnceLock},
+};
+
+use crate::{
+    AppResult,
+    misc::{config::config, paths::history_path},
+};
+
+/// Previously executed queries, most recent last, kept across sessions in the history file.
+#[derive(Debug, Default)]
+pub struct History {
+    entries: VecDeque<String>,
+}
+
+impl History {
+    pub fn load(&mut self) -> AppResult<()> {
+        let contents = fs::read_to_string(history_path()?)?;
+        self.entries.clear();
+        for line in contents.lines() {
+            self.push(line);
+        }
+        Ok(())
+    }
+
+    pub fn store(&self) -> AppResult<()> {
+        let history_path = history_path()?;
+        if let Some(parent) = history_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut contents = String::new();
+        for entry in self.entries.iter() {
+            contents.push_str(entry);
+            contents.push('\n');
+        }
+        Ok(fs::write(history_path, contents)?)
+    }
+
+    pub fn push(&mut self, entry: &str) {
+        self.push_capped(entry, config().history_size());
+    }
+
+    /// Returns the entry `back` steps before the most recent one.
+    pub fn get(&self, back: usize) -> Option<&str> {
+        self.entries
+            .len()
+            .checked_sub(back + 1)
+            .and_then(|idx| self.entries.get(idx))
+            .map(String::as_str)
+    }
+
+    fn push_capped(&mut self, entry: &str, cap: usize) {
+        let entry = entry.trim();
+        if entry.is_empty()
+            || entry.contains('\n')
+            || self.entries.back().is_some_and(|last| last == entry)
+        {
+            return;
+        }
+        self.entries.push_back(entry.to_owned());
+        while self.entries.len() > cap {
+            self.entries.pop_front();
+        }
+    }
+}
+
+pub fn history() -> MutexGuard<'static, History> {
+    static HISTORY: OnceLock<Mutex<History>> = OnceLock::new();
+    HISTORY.get_or_init(Default::default).lock().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_deduplicates_consecutive() {
+        let mut history = History::default();
+        history.push_capped("SELECT 1", 10);
+        history.push_capped("SELECT 1", 10);
+        history.push_capped("SELECT 2", 10);
+        history.push_capped("SELECT 1", 10);
+        history.push_capped("  ", 10);
+        assert_eq!(history.get(0), Some("SELECT 1"));
+        assert_eq!(history.get(1), Some("SELECT 2"));
+        assert_eq!(history.get(2), Some("SELECT 1"));
+        assert_eq!(history.get(3), None);
+    }
+
+    #[test]
+    fn test_push_caps_entries() {
+        let mut history = History::default();
+        for idx in 0..5 {
+            history.push_capped(&format!("SELECT {idx}"), 3);
+        }
+        assert_eq!(history.get(0), Some("SELECT 4"));
+        assert_eq!(history.get(2), Some("SELECT 2"));
+        assert_eq!(history.get(3), None);
+    }
+}
@@ -1,5 +1,9 @@
+pub mod cell_format;
 pub mod config;
+pub mod expr;
+pub mod file_watcher;
 pub mod globals;
+pub mod history;
 pub mod iter_ext;
 pub mod jagged_vec;
 pub mod non_empty_stack;
@@ -7,6 +11,7 @@ pub mod osc52;
 pub mod paths;
 pub mod polars_ext;
 pub mod search;
+pub mod session;
 pub mod snake_case_name_gen;
 pub mod sql;
 pub mod type_ext;
@@ -36,6 +36,12 @@ impl<T> NonEmptyStack<T> {
         &self.base
     }
 
+    /// Replaces the base and drops everything stacked on top of it.
+    pub fn reset(&mut self, base: T) {
+        self.base = base;
+        self.stack.clear();
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         std::iter::once(&self.base).chain(self.stack.iter())
     }
@@ -5,15 +5,18 @@ use std::{
 
 use base64::Engine;
 
+use crate::tui::terminal::output;
+
 static OSC52_BUFFER: LazyLock<Mutex<String>> = LazyLock::new(|| Mutex::new(String::default()));
 
 pub fn flush_osc52_buffer() {
     let mut buffer = OSC52_BUFFER.lock().unwrap();
     if !buffer.is_empty() {
-        for seq in buffer.drain(..) {
-            print!("{}", seq);
+        if let Ok(mut output) = output() {
+            let _ = output.write_all(buffer.as_bytes());
+            let _ = output.flush();
         }
-        std::io::stdout().flush().unwrap();
+        buffer.clear();
     }
 }
 
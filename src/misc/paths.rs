@@ -10,6 +10,24 @@ pub fn theme_path() -> AppResult<PathBuf> {
         .ok_or(anyhow!("Home dir not found"))
 }
 
+pub fn themes_dir() -> AppResult<PathBuf> {
+    home::home_dir()
+        .map(|path| path.join(".config").join("tabiew").join("themes"))
+        .ok_or(anyhow!("Home dir not found"))
+}
+
+pub fn sessions_dir() -> AppResult<PathBuf> {
+    home::home_dir()
+        .map(|path| path.join(".config").join("tabiew").join("sessions"))
+        .ok_or(anyhow!("Home dir not found"))
+}
+
+pub fn history_path() -> AppResult<PathBuf> {
+    home::home_dir()
+        .map(|path| path.join(".config").join("tabiew").join("history"))
+        .ok_or(anyhow!("Home dir not found"))
+}
+
 pub fn config_path() -> AppResult<PathBuf> {
     home::home_dir()
         .map(|path| path.join(".config").join("tabiew").join("config.toml"))
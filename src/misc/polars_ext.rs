@@ -1,5 +1,6 @@
 use std::{
-    ops::{Add, Div},
+    collections::HashMap,
+    ops::{Add, Div, Range},
     sync::{
         Arc,
         atomic::{AtomicBool, Ordering},
@@ -7,22 +8,28 @@ use std::{
 };
 
 use anyhow::anyhow;
-use itertools::{Itertools, izip};
+use itertools::{Either, Itertools, izip};
 use polars::{
     frame::DataFrame,
-    prelude::{AnyValue, ChunkAgg, DataType, NamedFrom, SeriesMethods},
+    lazy::frame::pivot::pivot_stable,
+    prelude::{
+        AnyValue, ChunkAgg, ChunkCompareEq, DataType, Expr, IdxCa, IdxSize, IntoLazy, NamedFrom,
+        Null, QuantileMethod, SeriesMethods, UnpivotArgsIR, UnpivotDF, col, lit, pearson_corr,
+    },
     series::Series,
 };
 use ratatui::widgets::Cell;
-use rayon::iter::{ParallelBridge, ParallelIterator};
+use rayon::iter::{IntoParallelIterator, ParallelBridge, ParallelIterator};
+use strum_macros::{Display, EnumIter, EnumString};
 use unicode_width::UnicodeWidthStr;
 
 use crate::{AppResult, misc::jagged_vec::JaggedVec, tui::sheet::SheetSection};
 
-use super::type_ext::HasSubsequence;
+use super::{cell_format::CellFormat, type_ext::HasSubsequence};
 
 pub trait AnyValueExt {
     fn into_single_line(self) -> String;
+    fn into_single_line_with_precision(self, precision: Option<usize>) -> String;
     fn width(self, num_buffer: &mut NumBuffer) -> usize;
     fn into_multi_line(self) -> String;
     fn into_cell(self, width: usize) -> Cell<'static>;
@@ -47,6 +54,14 @@ impl AnyValueExt for AnyValue<'_> {
         }
     }
 
+    fn into_single_line_with_precision(self, precision: Option<usize>) -> String {
+        match (self, precision) {
+            (AnyValue::Float32(f), Some(p)) => format!("{f:.p$}"),
+            (AnyValue::Float64(f), Some(p)) => format!("{f:.p$}"),
+            (val, _) => val.into_single_line(),
+        }
+    }
+
     fn width(self, num_buffer: &mut NumBuffer) -> usize {
         match self {
             AnyValue::Null => 0,
@@ -117,8 +132,162 @@ pub struct NumBuffer {
     itoa: itoa::Buffer,
 }
 
+/// Aggregation applied to the values falling into each cell of a pivot table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, EnumIter, Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum Aggregation {
+    First,
+    Last,
+    Sum,
+    Mean,
+    Median,
+    Min,
+    Max,
+    Count,
+}
+
+impl Aggregation {
+    fn expr(&self) -> Expr {
+        // The pivot evaluates the aggregation on an unnamed column holding the grouped values
+        let element = || col("");
+        match self {
+            Aggregation::First => element().first(),
+            Aggregation::Last => element().last(),
+            Aggregation::Sum => element().sum(),
+            Aggregation::Mean => element().mean(),
+            Aggregation::Median => element().median(),
+            Aggregation::Min => element().min(),
+            Aggregation::Max => element().max(),
+            Aggregation::Count => element().count(),
+        }
+    }
+}
+
+/// Size of a random sample, either a number of rows or a percentage of the table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampleSpec {
+    Rows(usize),
+    Percent(f64),
+}
+
+impl std::str::FromStr for SampleSpec {
+    type Err = anyhow::Error;
+
+    /// Parses a row count like `100` or a percentage like `5%`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.strip_suffix('%') {
+            Some(percent) => match percent.trim().parse::<f64>() {
+                Ok(percent) if (0.0..=100.0).contains(&percent) => Ok(SampleSpec::Percent(percent)),
+                _ => Err(anyhow!("'{s}' is not a percentage between 0% and 100%")),
+            },
+            None => s
+                .parse()
+                .map(SampleSpec::Rows)
+                .map_err(|_| anyhow!("'{s}' is neither a row count nor a percentage")),
+        }
+    }
+}
+
+impl std::fmt::Display for SampleSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SampleSpec::Rows(rows) => write!(f, "{rows} rows"),
+            SampleSpec::Percent(percent) => write!(f, "{percent}%"),
+        }
+    }
+}
+
+/// Id and value columns of an unpivot, written as `id, name :: jan, feb`. Without value columns
+/// every other column is unpivoted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnpivotSpec {
+    pub index: Vec<String>,
+    pub on: Vec<String>,
+}
+
+impl std::str::FromStr for UnpivotSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let columns = |list: &str| {
+            list.split(',')
+                .map(|name| name.trim().trim_matches('"').to_owned())
+                .filter(|name| !name.is_empty())
+                .collect_vec()
+        };
+        let (index, on) = s.split_once("::").unwrap_or((s, ""));
+        let spec = UnpivotSpec {
+            index: columns(index),
+            on: columns(on),
+        };
+        if spec.index.is_empty() && spec.on.is_empty() {
+            Err(anyhow!("Unpivot needs id columns, value columns or both"))?
+        }
+        Ok(spec)
+    }
+}
+
+/// Pairs of old and new column names, given as `old new, other_old other_new`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameSpec {
+    pub pairs: Vec<(String, String)>,
+}
+
+impl std::str::FromStr for RenameSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pairs = s
+            .split(',')
+            .filter(|pair| !pair.trim().is_empty())
+            .map(|pair| {
+                match pair
+                    .split_whitespace()
+                    .map(|name| name.trim_matches('"'))
+                    .collect_tuple()
+                {
+                    Some((old, new)) => Ok((old.to_owned(), new.to_owned())),
+                    None => Err(anyhow!(
+                        "Invalid rename '{}', expected 'old_name new_name'",
+                        pair.trim()
+                    )),
+                }
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+        if pairs.is_empty() {
+            Err(anyhow!(
+                "Rename needs at least one 'old_name new_name' pair"
+            ))?
+        }
+        Ok(RenameSpec { pairs })
+    }
+}
+
+impl std::fmt::Display for RenameSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.pairs
+                .iter()
+                .map(|(old, new)| format!("{old} {new}"))
+                .join(", ")
+        )
+    }
+}
+
+/// Column of a diff telling whether a row was added, removed or modified.
+pub const DIFF_CHANGE: &str = "_change";
+/// Column of a diff listing the modified columns of a row, the table highlights their cells.
+pub const DIFF_CHANGED: &str = "_changed";
+
+/// Upper bound of rows for transpose, each of them becomes a column.
+const TRANSPOSE_MAX_ROWS: usize = 10_000;
+
 pub trait DataFrameExt {
     fn widths(&self) -> Vec<usize>;
+    fn widths_with_format(&self, format: &CellFormat) -> Vec<usize>;
     fn get_sheet_sections(&self, pos: usize) -> Vec<SheetSection>;
     fn scatter_plot_data(&self, x_label: &str, y_label: &str) -> AppResult<JaggedVec<(f64, f64)>>;
     #[allow(clippy::type_complexity)]
@@ -129,6 +298,30 @@ pub trait DataFrameExt {
         group_by: &str,
     ) -> AppResult<(JaggedVec<(f64, f64)>, Vec<String>)>;
     fn histogram_plot_data(&self, col: &str, buckets: usize) -> AppResult<Vec<(String, u64)>>;
+    fn bar_plot_data(&self, col: &str) -> AppResult<Vec<(String, u64)>>;
+    fn density_plot_data(
+        &self,
+        col: &str,
+        bandwidth: Option<f64>,
+    ) -> AppResult<(f64, Vec<(f64, f64)>)>;
+    fn null_map_data(&self, buckets: usize) -> Vec<(Range<usize>, Vec<f64>)>;
+    fn value_counts(&self, col: &str) -> AppResult<DataFrame>;
+    fn column_stats(&self, col: &str) -> AppResult<Vec<(&'static str, String)>>;
+    fn corr_matrix_data(&self) -> AppResult<(Vec<String>, Vec<Vec<f64>>)>;
+    fn describe(&self) -> AppResult<DataFrame>;
+    fn set_cell(&mut self, row: usize, column: &str, value: &str) -> AppResult<()>;
+    fn transpose_as_text(&self) -> AppResult<DataFrame>;
+    fn random_sample(&self, spec: SampleSpec, seed: Option<u64>) -> AppResult<DataFrame>;
+    fn melt(&self, spec: &UnpivotSpec) -> AppResult<(DataFrame, Option<DataType>)>;
+    fn diff_with(&self, other: &DataFrame, key: Option<&str>) -> AppResult<DataFrame>;
+    fn rename_columns(&self, spec: &RenameSpec) -> AppResult<DataFrame>;
+    fn pivot(
+        &self,
+        index: &str,
+        columns: &str,
+        values: &str,
+        agg: Aggregation,
+    ) -> AppResult<DataFrame>;
 }
 
 pub trait TryMapAll {
@@ -167,6 +360,18 @@ impl DataFrameExt for DataFrame {
         self.iter().map(series_width).collect()
     }
 
+    fn widths_with_format(&self, format: &CellFormat) -> Vec<usize> {
+        self.iter()
+            .map(|series| {
+                if format.affects(series.name(), series.dtype()) {
+                    formatted_series_width(series, format)
+                } else {
+                    series_width(series)
+                }
+            })
+            .collect()
+    }
+
     fn get_sheet_sections(&self, pos: usize) -> Vec<SheetSection> {
         izip!(
             self.get_column_names().into_iter(),
@@ -223,6 +428,469 @@ impl DataFrameExt for DataFrame {
         Ok((data, groups))
     }
 
+    fn bar_plot_data(&self, col: &str) -> AppResult<Vec<(String, u64)>> {
+        discrete_histogram(self.value_counts(col)?)
+    }
+
+    fn density_plot_data(
+        &self,
+        col: &str,
+        bandwidth: Option<f64>,
+    ) -> AppResult<(f64, Vec<(f64, f64)>)> {
+        let values = self
+            .column(col)?
+            .cast(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .flatten()
+            .filter(|v| v.is_finite())
+            .collect_vec();
+        if values.len() < KDE_MIN_VALUES {
+            Err(anyhow!(
+                "Density plot needs at least {KDE_MIN_VALUES} non-null values in column '{col}'"
+            ))?
+        }
+        let bandwidth = match bandwidth {
+            Some(bandwidth) if bandwidth.is_finite() && bandwidth > 0.0 => bandwidth,
+            Some(bandwidth) => Err(anyhow!("Invalid bandwidth {bandwidth}"))?,
+            None => silverman_bandwidth(&values)
+                .ok_or(anyhow!("Values of column '{col}' are all the same"))?,
+        };
+        Ok((bandwidth, gaussian_kde(&values, bandwidth, KDE_POINTS)))
+    }
+
+    /// Splits the rows into at most `buckets` consecutive ranges of about the same size and
+    /// returns the share of nulls of every column within each of them.
+    fn null_map_data(&self, buckets: usize) -> Vec<(Range<usize>, Vec<f64>)> {
+        let height = self.height();
+        let buckets = buckets.clamp(1, height.max(1));
+        (0..buckets)
+            .map(|bucket| bucket * height / buckets..(bucket + 1) * height / buckets)
+            .filter(|range| !range.is_empty())
+            .map(|range| {
+                let fractions = self
+                    .get_columns()
+                    .iter()
+                    .map(|column| {
+                        column.slice(range.start as i64, range.len()).null_count() as f64
+                            / range.len() as f64
+                    })
+                    .collect();
+                (range, fractions)
+            })
+            .collect()
+    }
+
+    fn value_counts(&self, col: &str) -> AppResult<DataFrame> {
+        let mut counts = self.column(col)?.as_materialized_series().value_counts(
+            true,
+            true,
+            "count".into(),
+            false,
+        )?;
+        counts.rechunk_mut();
+        Ok(counts)
+    }
+
+    fn column_stats(&self, col_name: &str) -> AppResult<Vec<(&'static str, String)>> {
+        let column = self.column(col_name)?;
+        let mut stats = vec![
+            ("Count", (column.len() - column.null_count()).to_string()),
+            ("Null Count", column.null_count().to_string()),
+        ];
+        if column.dtype().is_primitive_numeric() {
+            let float = || col(col_name).cast(DataType::Float64);
+            let aggs = [
+                ("Min", col(col_name).min()),
+                ("Max", col(col_name).max()),
+                ("Mean", float().mean()),
+                ("Median", float().median()),
+                ("Std", float().std(1)),
+                ("25%", float().quantile(lit(0.25), QuantileMethod::Linear)),
+                ("50%", float().quantile(lit(0.5), QuantileMethod::Linear)),
+                ("75%", float().quantile(lit(0.75), QuantileMethod::Linear)),
+            ];
+            let names = aggs.each_ref().map(|(name, _)| *name);
+            let values = self
+                .clone()
+                .lazy()
+                .select(aggs.map(|(name, expr)| expr.alias(name)))
+                .collect()?;
+            for (name, column) in names.into_iter().zip(values.get_columns()) {
+                stats.push((name, column.get(0)?.into_single_line()));
+            }
+        } else {
+            stats.push(("Distinct Count", column.n_unique()?.to_string()));
+            let counts = self.value_counts(col_name)?;
+            if counts.height() > 0 {
+                stats.push((
+                    "Most Frequent",
+                    format!(
+                        "{} ({})",
+                        counts[0].get(0)?.into_single_line(),
+                        counts[1].get(0)?.into_single_line()
+                    ),
+                ));
+            }
+        }
+        Ok(stats)
+    }
+
+    fn corr_matrix_data(&self) -> AppResult<(Vec<String>, Vec<Vec<f64>>)> {
+        let names = self
+            .get_columns()
+            .iter()
+            .filter(|column| column.dtype().is_primitive_numeric())
+            .map(|column| column.name().to_string())
+            .collect_vec();
+        if names.len() < 2 {
+            Err(anyhow!(
+                "Correlation matrix needs at least two numeric columns, found {}",
+                names.len()
+            ))?
+        }
+        let float = |name: &str| col(name).cast(DataType::Float64);
+        let values = self
+            .clone()
+            .lazy()
+            .select(
+                names
+                    .iter()
+                    .tuple_combinations()
+                    .enumerate()
+                    .map(|(idx, (a, b))| pearson_corr(float(a), float(b)).alias(idx.to_string()))
+                    .collect_vec(),
+            )
+            .collect()?;
+        let mut matrix = (0..names.len())
+            .map(|i| {
+                (0..names.len())
+                    .map(|j| if i == j { 1.0 } else { f64::NAN })
+                    .collect_vec()
+            })
+            .collect_vec();
+        for ((i, j), column) in (0..names.len())
+            .tuple_combinations()
+            .zip(values.get_columns())
+        {
+            let value = column.get(0)?.extract::<f64>().unwrap_or(f64::NAN);
+            matrix[i][j] = value;
+            matrix[j][i] = value;
+        }
+        Ok((names, matrix))
+    }
+
+    /// One row per statistic and one column per numeric or string column, string columns only
+    /// have the counts and are null elsewhere.
+    fn describe(&self) -> AppResult<DataFrame> {
+        const STATISTICS: [&str; 9] = [
+            "count", "unique", "mean", "std", "min", "25%", "50%", "75%", "max",
+        ];
+        let columns = self
+            .get_columns()
+            .iter()
+            .filter(|column| column.dtype().is_primitive_numeric() || column.dtype().is_string())
+            .collect_vec();
+        if columns.is_empty() {
+            Err(anyhow!(
+                "Describe needs at least one numeric or string column"
+            ))?
+        }
+        let exprs = columns
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, column)| {
+                let name = column.name().as_str();
+                let float = || col(name).cast(DataType::Float64);
+                let quantile = |q| float().quantile(lit(q), QuantileMethod::Linear);
+                let numeric = if column.dtype().is_primitive_numeric() {
+                    [
+                        float().mean(),
+                        float().std(1),
+                        float().min(),
+                        quantile(0.25),
+                        quantile(0.5),
+                        quantile(0.75),
+                        float().max(),
+                    ]
+                } else {
+                    std::array::from_fn(|_| lit(Null {}).cast(DataType::Float64))
+                };
+                [
+                    col(name).count().cast(DataType::Float64),
+                    col(name).drop_nulls().n_unique().cast(DataType::Float64),
+                ]
+                .into_iter()
+                .chain(numeric)
+                .enumerate()
+                .map(move |(stat, expr)| expr.alias(format!("{idx}_{stat}")))
+            })
+            .collect_vec();
+        let values = self.clone().lazy().select(exprs).collect()?;
+        let mut described = vec![Series::new("statistic".into(), STATISTICS).into()];
+        for (idx, column) in columns.iter().enumerate() {
+            let stats = (0..STATISTICS.len())
+                .map(|stat| {
+                    Ok(values
+                        .column(&format!("{idx}_{stat}"))?
+                        .get(0)?
+                        .extract::<f64>())
+                })
+                .collect::<AppResult<Vec<_>>>()?;
+            described.push(Series::new(column.name().clone(), stats).into());
+        }
+        Ok(DataFrame::new(described)?)
+    }
+
+    /// Parses the value as the type of the column and writes it at the row, an empty value sets
+    /// null unless the column holds strings.
+    fn set_cell(&mut self, row: usize, column: &str, value: &str) -> AppResult<()> {
+        let series = self.column(column)?.as_materialized_series().clone();
+        if row >= series.len() {
+            Err(anyhow!("Row {row} is out of bounds"))?
+        }
+        let dtype = series.dtype();
+        let parsed = match dtype {
+            DataType::String => Series::new(series.name().clone(), [value]),
+            _ if value.is_empty() => Series::full_null(series.name().clone(), 1, dtype),
+            DataType::Boolean => match value.to_lowercase().as_str() {
+                "true" => Series::new(series.name().clone(), [true]),
+                "false" => Series::new(series.name().clone(), [false]),
+                _ => Err(anyhow!("Invalid boolean value '{value}'"))?,
+            },
+            _ => Series::new(series.name().clone(), [value.trim()])
+                .strict_cast(dtype)
+                .map_err(|_| anyhow!("Invalid {dtype} value '{value}'"))?,
+        };
+        let mut edited = series.slice(0, row);
+        edited.append(&parsed)?;
+        edited.append(&series.slice(row as i64 + 1, series.len()))?;
+        self.with_column(edited)?;
+        Ok(())
+    }
+
+    /// Headers become the first column and rows become columns named row_1, row_2, ... Every
+    /// value is cast to text first, as a column of the result mixes values of all the types.
+    fn transpose_as_text(&self) -> AppResult<DataFrame> {
+        if self.height() > TRANSPOSE_MAX_ROWS {
+            Err(anyhow!(
+                "Transpose is limited to {TRANSPOSE_MAX_ROWS} rows, filter the table first"
+            ))?
+        }
+        let mut text = DataFrame::new(
+            self.get_columns()
+                .iter()
+                .map(|column| column.cast(&DataType::String))
+                .collect::<Result<Vec<_>, _>>()?,
+        )?;
+        let names = (1..=self.height())
+            .map(|idx| format!("row_{idx}"))
+            .collect();
+        Ok(text.transpose(Some("column"), Some(Either::Right(names)))?)
+    }
+
+    fn random_sample(&self, spec: SampleSpec, seed: Option<u64>) -> AppResult<DataFrame> {
+        let rows = match spec {
+            SampleSpec::Rows(rows) => rows.min(self.height()),
+            SampleSpec::Percent(percent) => {
+                (self.height() as f64 * percent / 100.0).round() as usize
+            }
+        };
+        Ok(self.sample_n_literal(rows, false, true, seed)?)
+    }
+
+    /// Unpivots the value columns into variable and value columns, the values are cast to their
+    /// common supertype, or to strings if there is none, which is returned if any column changed.
+    fn melt(&self, spec: &UnpivotSpec) -> AppResult<(DataFrame, Option<DataType>)> {
+        for name in &spec.index {
+            self.column(name)?;
+        }
+        let on = if spec.on.is_empty() {
+            self.get_column_names()
+                .into_iter()
+                .filter(|name| !spec.index.iter().any(|index| index == name.as_str()))
+                .map(|name| name.to_string())
+                .collect_vec()
+        } else {
+            spec.on.clone()
+        };
+        if on.is_empty() {
+            Err(anyhow!("No columns are left to unpivot"))?
+        }
+        let args = UnpivotArgsIR {
+            on: on.iter().map(Into::into).collect(),
+            index: spec.index.iter().map(Into::into).collect(),
+            ..Default::default()
+        };
+        // Polars casts the values to their supertype and fails if there is none
+        let df = match self.unpivot2(args.clone()) {
+            Ok(df) => df,
+            Err(_) => {
+                let mut df = self.clone();
+                for name in &on {
+                    let column = df.column(name)?.cast(&DataType::String)?;
+                    df.with_column(column)?;
+                }
+                df.unpivot2(args)?
+            }
+        };
+        let dtype = df.column("value")?.dtype().clone();
+        let cast = on
+            .iter()
+            .map(|name| self.column(name))
+            .any(|column| column.is_ok_and(|column| *column.dtype() != dtype));
+        Ok((df, cast.then_some(dtype)))
+    }
+
+    /// Renames the columns pair by pair, so a later pair can refer to a name given by an earlier
+    /// one.
+    fn rename_columns(&self, spec: &RenameSpec) -> AppResult<DataFrame> {
+        let mut df = self.clone();
+        for (old, new) in &spec.pairs {
+            if df.column(old).is_err() {
+                Err(anyhow!(
+                    "Column '{old}' does not exist, available columns: {}",
+                    df.get_column_names().into_iter().join(", ")
+                ))?
+            }
+            df.rename(old, new.into())?;
+        }
+        Ok(df)
+    }
+
+    /// Rows that differ from the other table, aligned by position or by a unique key column.
+    /// Modified and added rows show the values of the other table.
+    fn diff_with(&self, other: &DataFrame, key: Option<&str>) -> AppResult<DataFrame> {
+        let (schema, other_schema) = (self.schema(), other.schema());
+        for (name, dtype) in schema.iter() {
+            match other_schema.get(name) {
+                None => Err(anyhow!("Column '{name}' is missing in the other table"))?,
+                Some(other) if other != dtype => Err(anyhow!(
+                    "Column '{name}' is {dtype} here but {other} in the other table"
+                ))?,
+                _ => (),
+            }
+        }
+        if let Some(name) = other_schema
+            .iter_names()
+            .find(|name| !schema.contains(name))
+        {
+            Err(anyhow!("Column '{name}' only exists in the other table"))?
+        }
+        let other = other.select(self.get_column_names().into_iter().cloned())?;
+
+        let (matched, removed, added) = match key {
+            None => {
+                let common = self.height().min(other.height());
+                (
+                    (0..common).map(|idx| (idx, idx)).collect_vec(),
+                    (common..self.height()).collect_vec(),
+                    (common..other.height()).collect_vec(),
+                )
+            }
+            Some(key) => {
+                let keys = |df: &DataFrame, table: &str| -> AppResult<HashMap<_, usize>> {
+                    let values = df.column(key)?.cast(&DataType::String)?;
+                    let mut rows = HashMap::new();
+                    for (idx, value) in values.str()?.into_iter().enumerate() {
+                        if rows.insert(value.map(str::to_owned), idx).is_some() {
+                            Err(anyhow!(
+                                "Key '{}' appears more than once in {table}",
+                                value.unwrap_or("null")
+                            ))?
+                        }
+                    }
+                    Ok(rows)
+                };
+                let mut other_rows = keys(&other, "the other table")?;
+                let mut matched = Vec::new();
+                let mut removed = Vec::new();
+                for (value, idx) in keys(self, "this table")?
+                    .into_iter()
+                    .sorted_by_key(|(_, idx)| *idx)
+                {
+                    match other_rows.remove(&value) {
+                        Some(other_idx) => matched.push((idx, other_idx)),
+                        None => removed.push(idx),
+                    }
+                }
+                (
+                    matched,
+                    removed,
+                    other_rows.into_values().sorted().collect_vec(),
+                )
+            }
+        };
+
+        let take = |df: &DataFrame, rows: Vec<usize>| {
+            df.take(&IdxCa::from_vec(
+                "".into(),
+                rows.into_iter().map(|idx| idx as IdxSize).collect(),
+            ))
+        };
+        let left = take(self, matched.iter().map(|(idx, _)| *idx).collect())?;
+        let right = take(&other, matched.iter().map(|(_, idx)| *idx).collect())?;
+        let mut changed = vec![Vec::new(); matched.len()];
+        for (left, right) in left.get_columns().iter().zip(right.get_columns()) {
+            let equal = left
+                .as_materialized_series()
+                .equal_missing(right.as_materialized_series())?;
+            for (row, equal) in equal.into_iter().enumerate() {
+                if equal != Some(true) {
+                    changed[row].push(left.name().as_str());
+                }
+            }
+        }
+
+        // Rows of the other table follow the rows of this one in the stacked frame
+        let offset = self.height();
+        let modified = matched
+            .iter()
+            .zip(changed)
+            .filter(|(_, columns)| !columns.is_empty())
+            .collect_vec();
+        let rows = modified
+            .iter()
+            .map(|((_, idx), _)| offset + idx)
+            .chain(removed.iter().copied())
+            .chain(added.iter().map(|idx| offset + idx))
+            .collect();
+        let mut df = take(&self.vstack(&other)?, rows)?;
+        let changes = std::iter::repeat_n("modified", modified.len())
+            .chain(std::iter::repeat_n("removed", removed.len()))
+            .chain(std::iter::repeat_n("added", added.len()))
+            .collect_vec();
+        let columns = modified
+            .iter()
+            .map(|(_, columns)| columns.join(", "))
+            .chain(std::iter::repeat_n(
+                String::new(),
+                removed.len() + added.len(),
+            ))
+            .collect_vec();
+        df.insert_column(0, Series::new(DIFF_CHANGE.into(), changes))?;
+        df.with_column(Series::new(DIFF_CHANGED.into(), columns))?;
+        Ok(df)
+    }
+
+    fn pivot(
+        &self,
+        index: &str,
+        columns: &str,
+        values: &str,
+        agg: Aggregation,
+    ) -> AppResult<DataFrame> {
+        Ok(pivot_stable(
+            self,
+            [columns],
+            Some([index]),
+            Some([values]),
+            true,
+            Some(agg.expr()),
+            None,
+        )?)
+    }
+
     fn histogram_plot_data(&self, col_name: &str, buckets: usize) -> AppResult<Vec<(String, u64)>> {
         let col = self.column(col_name)?;
         match col.dtype() {
@@ -275,6 +943,17 @@ fn series_width(series: &Series) -> usize {
     )
 }
 
+fn formatted_series_width(series: &Series, format: &CellFormat) -> usize {
+    series.name().width().max(
+        series
+            .iter()
+            .par_bridge()
+            .map(|val| format.format(series.name(), val).len())
+            .max()
+            .unwrap_or_default(),
+    )
+}
+
 impl TryMapAll for Series {
     fn try_map_all(
         &self,
@@ -351,3 +1030,329 @@ fn continues_histogram(counts: DataFrame, buckets: usize) -> AppResult<Vec<(Stri
         })
         .collect())
 }
+
+const KDE_MIN_VALUES: usize = 3;
+const KDE_POINTS: usize = 200;
+
+/// Silverman's rule of thumb, None if the values have no spread.
+fn silverman_bandwidth(values: &[f64]) -> Option<f64> {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let std = (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+    let sorted = values
+        .iter()
+        .copied()
+        .sorted_by(f64::total_cmp)
+        .collect_vec();
+    let quantile = |q: f64| {
+        let pos = q * (n - 1.0);
+        let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+        sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+    };
+    let iqr = quantile(0.75) - quantile(0.25);
+    let spread = if iqr > 0.0 { std.min(iqr / 1.34) } else { std };
+    (spread > 0.0).then(|| 0.9 * spread * n.powf(-0.2))
+}
+
+/// Gaussian kernel density estimate sampled at evenly spaced points, reaching three bandwidths
+/// past the smallest and largest value.
+fn gaussian_kde(values: &[f64], bandwidth: f64, points: usize) -> Vec<(f64, f64)> {
+    let (min, max) = values
+        .iter()
+        .copied()
+        .minmax_by(f64::total_cmp)
+        .into_option()
+        .unwrap_or_default();
+    let (start, end) = (min - 3.0 * bandwidth, max + 3.0 * bandwidth);
+    let step = (end - start) / points.saturating_sub(1).max(1) as f64;
+    let norm = 1.0 / (values.len() as f64 * bandwidth * (2.0 * std::f64::consts::PI).sqrt());
+    (0..points)
+        .into_par_iter()
+        .map(|idx| {
+            let x = start + step * idx as f64;
+            let density = values
+                .iter()
+                .map(|v| (-0.5 * ((x - v) / bandwidth).powi(2)).exp())
+                .sum::<f64>();
+            (x, density * norm)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::df;
+
+    use super::*;
+
+    #[test]
+    fn test_set_cell() {
+        let mut df = df!(
+            "int" => [1, 2, 3],
+            "text" => ["a", "b", "c"],
+            "flag" => [true, false, true],
+        )
+        .unwrap();
+        df.set_cell(1, "int", "20").unwrap();
+        df.set_cell(2, "int", "").unwrap();
+        df.set_cell(0, "text", "").unwrap();
+        df.set_cell(1, "flag", "True").unwrap();
+        assert!(df.set_cell(0, "int", "abc").is_err());
+        assert!(df.set_cell(3, "int", "4").is_err());
+        assert_eq!(
+            df,
+            df!(
+                "int" => [Some(1), Some(20), None],
+                "text" => ["", "b", "c"],
+                "flag" => [true, true, true],
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_transpose_as_text() {
+        let df = df!(
+            "name" => ["a", "b"],
+            "value" => [Some(1.5), None],
+        )
+        .unwrap();
+        let transposed = df.transpose_as_text().unwrap();
+        assert_eq!(
+            transposed,
+            df!(
+                "column" => ["name", "value"],
+                "row_1" => [Some("a"), Some("1.5")],
+                "row_2" => [Some("b"), None],
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_diff_with() {
+        let base = df!(
+            "id" => [1, 2, 3],
+            "price" => [10, 20, 30],
+            "name" => ["a", "b", "c"],
+        )
+        .unwrap();
+        let other = df!(
+            "id" => [2, 1, 4],
+            "name" => ["b", "x", "d"],
+            "price" => [25, 10, 40],
+        )
+        .unwrap();
+        let changes = |df: &DataFrame| {
+            df.column(DIFF_CHANGE)
+                .unwrap()
+                .str()
+                .unwrap()
+                .into_no_null_iter()
+                .map(str::to_owned)
+                .collect_vec()
+        };
+
+        let diff = base.diff_with(&other, Some("id")).unwrap();
+        assert_eq!(changes(&diff), ["modified", "modified", "removed", "added"]);
+        assert_eq!(
+            diff.column(DIFF_CHANGED).unwrap().str().unwrap().get(0),
+            Some("name")
+        );
+        assert_eq!(
+            diff.column("price").unwrap().i32().unwrap().get(1),
+            Some(25)
+        );
+        assert_eq!(diff.column("id").unwrap().i32().unwrap().get(3), Some(4));
+
+        let diff = base.diff_with(&other.head(Some(2)), None).unwrap();
+        assert_eq!(changes(&diff), ["modified", "modified", "removed"]);
+        assert_eq!(
+            diff.column(DIFF_CHANGED).unwrap().str().unwrap().get(0),
+            Some("id, price, name")
+        );
+        assert_eq!(base.diff_with(&base, None).unwrap().height(), 0);
+
+        let renamed = df!("id" => [1], "cost" => [1], "name" => ["a"]).unwrap();
+        let err = base.diff_with(&renamed, None).unwrap_err().to_string();
+        assert!(err.contains("'price' is missing"), "{err}");
+        let duplicated = base.vstack(&base).unwrap();
+        assert!(base.diff_with(&duplicated, Some("id")).is_err());
+    }
+
+    #[test]
+    fn test_melt() {
+        let df = df!(
+            "id" => [1, 2],
+            "jan" => [10, 20],
+            "feb" => [1.5, 2.5],
+            "note" => ["a", "b"],
+        )
+        .unwrap();
+        let spec = "id :: jan, feb".parse::<UnpivotSpec>().unwrap();
+        assert_eq!(spec.on, ["jan", "feb"]);
+        let (melted, cast) = df.melt(&spec).unwrap();
+        assert_eq!(melted.shape(), (4, 3));
+        assert_eq!(cast, Some(DataType::Float64));
+        assert_eq!(
+            melted.column("variable").unwrap().str().unwrap().get(2),
+            Some("feb")
+        );
+
+        let (melted, cast) = df.melt(&"id".parse().unwrap()).unwrap();
+        assert_eq!(melted.shape(), (6, 3));
+        assert_eq!(cast, Some(DataType::String));
+        let (_, cast) = df.melt(&":: jan".parse().unwrap()).unwrap();
+        assert_eq!(cast, None);
+
+        assert!("::".parse::<UnpivotSpec>().is_err());
+        assert!(df.melt(&"missing :: jan".parse().unwrap()).is_err());
+        assert!(df.melt(&"id, jan, feb, note".parse().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_rename_columns() {
+        let df = df!("a" => [1], "b" => ["x"]).unwrap();
+        let spec = "a id, b name".parse::<RenameSpec>().unwrap();
+        assert_eq!(spec.to_string(), "a id, b name");
+        let renamed = df.rename_columns(&spec).unwrap();
+        assert_eq!(renamed.get_column_names(), ["id", "name"]);
+        let renamed = df
+            .rename_columns(&"a tmp, b a, tmp b".parse().unwrap())
+            .unwrap();
+        assert_eq!(renamed.get_column_names(), ["b", "a"]);
+
+        let err = df
+            .rename_columns(&"c id".parse().unwrap())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("available columns: a, b"), "{err}");
+        assert!(df.rename_columns(&"a b".parse().unwrap()).is_err());
+        assert!("a".parse::<RenameSpec>().is_err());
+        assert!(" , ".parse::<RenameSpec>().is_err());
+    }
+
+    #[test]
+    fn test_random_sample() {
+        let df = df!("a" => (0..100).collect::<Vec<i32>>()).unwrap();
+        assert_eq!("10".parse::<SampleSpec>().unwrap(), SampleSpec::Rows(10));
+        assert_eq!(
+            " 5 % ".parse::<SampleSpec>().unwrap(),
+            SampleSpec::Percent(5.0)
+        );
+        assert!("150%".parse::<SampleSpec>().is_err());
+        assert!("ten".parse::<SampleSpec>().is_err());
+
+        let sample = df.random_sample(SampleSpec::Rows(10), Some(7)).unwrap();
+        assert_eq!(sample.height(), 10);
+        assert_eq!(
+            sample,
+            df.random_sample(SampleSpec::Rows(10), Some(7)).unwrap()
+        );
+        assert_eq!(
+            df.random_sample(SampleSpec::Percent(25.0), None)
+                .unwrap()
+                .height(),
+            25
+        );
+        assert_eq!(
+            df.random_sample(SampleSpec::Rows(500), None)
+                .unwrap()
+                .height(),
+            100
+        );
+    }
+
+    #[test]
+    fn test_null_map_data() {
+        let df = df!(
+            "a" => [None, Some(1), Some(2), Some(3), None],
+            "b" => [Some(1), Some(2), Some(3), Some(4), Some(5)],
+        )
+        .unwrap();
+        assert_eq!(
+            df.null_map_data(2),
+            [(0..2, vec![0.5, 0.0]), (2..5, vec![1.0 / 3.0, 0.0])]
+        );
+        assert_eq!(df.null_map_data(10).len(), 5);
+        assert!(df.clear().null_map_data(10).is_empty());
+    }
+
+    #[test]
+    fn test_describe() {
+        let df = df!(
+            "num" => [Some(1), Some(2), None, Some(5)],
+            "text" => [Some("a"), Some("b"), Some("a"), None],
+            "flag" => [true, false, true, true],
+        )
+        .unwrap();
+        let described = df.describe().unwrap();
+        assert_eq!(described.get_column_names(), ["statistic", "num", "text"]);
+        let stats = |name: &str| {
+            described
+                .column(name)
+                .unwrap()
+                .f64()
+                .unwrap()
+                .into_iter()
+                .map(|value| value.map(|v| (v * 1000.0).round() / 1000.0))
+                .collect_vec()
+        };
+        assert_eq!(
+            stats("num"),
+            [
+                Some(3.0),
+                Some(3.0),
+                Some(2.667),
+                Some(2.082),
+                Some(1.0),
+                Some(1.5),
+                Some(2.0),
+                Some(3.5),
+                Some(5.0)
+            ]
+        );
+        assert_eq!(
+            stats("text"),
+            [
+                Some(3.0),
+                Some(2.0),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_density_plot_data() {
+        let df = df!(
+            "num" => [Some(1.0), Some(2.0), None, Some(3.0), Some(4.0), Some(5.0)],
+            "few" => [Some(1.0), None, None, None, None, Some(2.0)],
+            "same" => [7, 7, 7, 7, 7, 7],
+        )
+        .unwrap();
+        let bandwidth = silverman_bandwidth(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert!((bandwidth - 0.97357).abs() < 1e-4);
+
+        let (used, data) = df.density_plot_data("num", None).unwrap();
+        assert_eq!(used, bandwidth);
+        assert_eq!(data.len(), KDE_POINTS);
+        assert!((data[0].0 - (1.0 - 3.0 * bandwidth)).abs() < 1e-9);
+        assert!((data[KDE_POINTS - 1].0 - (5.0 + 3.0 * bandwidth)).abs() < 1e-9);
+        let step = data[1].0 - data[0].0;
+        let area = data.iter().map(|(_, y)| y * step).sum::<f64>();
+        assert!((area - 1.0).abs() < 0.01);
+        assert!(data.iter().all(|(_, y)| y.is_finite()));
+
+        assert!(df.density_plot_data("num", Some(0.5)).is_ok());
+        assert!(df.density_plot_data("num", Some(0.0)).is_err());
+        assert!(df.density_plot_data("few", None).is_err());
+        assert!(df.density_plot_data("same", None).is_err());
+        assert!(df.density_plot_data("same", Some(1.0)).is_ok());
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     fmt::Debug,
     marker::PhantomData,
     sync::{
-        Arc, Mutex,
+        Arc, Mutex, OnceLock,
         atomic::{AtomicBool, Ordering},
         mpsc::{Receiver, TryRecvError, channel},
     },
@@ -48,6 +48,21 @@ impl Score for Contain {
     }
 }
 
+/// Matches values against the pattern compiled as a regular expression on first use.
+#[derive(Debug, Default)]
+pub struct Regex {
+    regex: OnceLock<Option<regex::Regex>>,
+}
+
+impl Score for Regex {
+    fn score(&self, a: &str, b: &str) -> Option<i64> {
+        self.regex
+            .get_or_init(|| regex::Regex::new(b).ok())
+            .as_ref()
+            .and_then(|regex| regex.is_match(a).then_some(1))
+    }
+}
+
 #[derive(Debug)]
 pub struct Search<S> {
     pat: String,
@@ -60,10 +75,13 @@ impl<S> Search<S>
 where
     S: Score + Default + Sync + Send + 'static,
 {
+    /// Searches every column, or only the column named before a colon like `city:berlin`.
     pub fn new(df: DataFrame, pat: String) -> Self {
         let sync_df = SyncDataFrame::new();
         let alive = Arc::new(AtomicBool::new(true));
-        if pat.is_empty() {
+        let (column, query) = split_scope(&df.get_column_names_str(), &pat);
+        let query = query.to_owned();
+        if query.is_empty() {
             // avoid search
             sync_df.insert(df);
             Self {
@@ -82,11 +100,13 @@ where
                 let matcher = S::default();
                 let alive = alive.clone();
                 let df = df.clone();
-                let pat = pat.clone();
+                let pat = query;
                 move || {
                     let _ = df
                         .iter()
-                        .flat_map(|series| series.iter().enumerate())
+                        .enumerate()
+                        .filter(|(idx, _)| column.is_none_or(|column| column == *idx))
+                        .flat_map(|(_, series)| series.iter().enumerate())
                         .par_bridge()
                         .take_any_while(|_| alive.load(Ordering::Relaxed))
                         .filter_map(|(idx, value)| {
@@ -150,6 +170,20 @@ where
     }
 }
 
+/// Splits a `column:query` pattern into the index of the column and the query, patterns without
+/// a known column before a colon search every column for the whole pattern.
+fn split_scope<'a>(columns: &[&str], pat: &'a str) -> (Option<usize>, &'a str) {
+    pat.match_indices(':')
+        .find_map(|(idx, _)| {
+            let name = pat[..idx].trim();
+            columns
+                .iter()
+                .position(|column| *column == name)
+                .map(|column| (Some(column), pat[idx + 1..].trim_start()))
+        })
+        .unwrap_or((None, pat))
+}
+
 #[derive(Debug, Clone)]
 struct SyncDataFrame(Arc<Mutex<Option<DataFrame>>>);
 
@@ -231,3 +265,22 @@ impl Interval {
         self.last_tick = Instant::now();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_scope() {
+        let columns = ["name", "city", "time:utc"];
+        assert_eq!(split_scope(&columns, "city:berlin"), (Some(1), "berlin"));
+        assert_eq!(
+            split_scope(&columns, "city: new york"),
+            (Some(1), "new york")
+        );
+        assert_eq!(split_scope(&columns, "time:utc:12:00"), (Some(2), "12:00"));
+        assert_eq!(split_scope(&columns, "12:00"), (None, "12:00"));
+        assert_eq!(split_scope(&columns, "berlin"), (None, "berlin"));
+        assert_eq!(split_scope(&columns, "name:"), (Some(0), ""));
+    }
+}
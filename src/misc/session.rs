@@ -0,0 +1,196 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+use polars::frame::DataFrame;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AppResult,
+    handler::message::Message,
+    misc::{globals::sql, paths::sessions_dir, sql::Source},
+    reader::{self, DetectedToDataFrames, ReadToDataFrames},
+    tui::pane::TableDescription,
+};
+
+/// Open tabs and the files behind their tables, enough to rebuild them in a later run.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    tables: Vec<SessionTable>,
+    tabs: Vec<SessionTab>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionTable {
+    name: String,
+    path: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionTab {
+    Table(String),
+    Query(String),
+}
+
+impl Session {
+    /// Captures the given tab descriptions and every registered table read from a file.
+    pub fn new<'a>(tabs: impl IntoIterator<Item = &'a TableDescription>) -> Self {
+        let tables = sql()
+            .schema()
+            .iter()
+            .filter_map(|(name, info)| match info.source() {
+                Source::File(path) => Some(SessionTable {
+                    name: name.to_owned(),
+                    path: fs::canonicalize(path).unwrap_or(path.to_owned()),
+                }),
+                _ => None,
+            })
+            .collect();
+        let tabs = tabs
+            .into_iter()
+            .filter_map(|desc| match desc {
+                TableDescription::Table(name) => Some(SessionTab::Table(name.to_owned())),
+                TableDescription::Query(query) => Some(SessionTab::Query(query.to_owned())),
+                _ => None,
+            })
+            .collect();
+        Self { tables, tabs }
+    }
+
+    pub fn load(name: &str) -> AppResult<Self> {
+        let contents = fs::read_to_string(session_path(name)?)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    pub fn store(&self, name: &str) -> AppResult<()> {
+        let path = session_path(name)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Ok(fs::write(path, toml::to_string_pretty(self)?)?)
+    }
+
+    /// Names of the stored sessions, sorted alphabetically.
+    pub fn names() -> Vec<String> {
+        sessions_dir()
+            .and_then(|dir| Ok(fs::read_dir(dir)?))
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .map(|entry| entry.path())
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+                    .filter_map(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+                    .sorted()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Re-imports the files and re-runs the queries, opening a tab for each restored entry.
+    /// Tables get back their saved names, replacing any open table of the same name, so the
+    /// restored tabs and queries refer to them. Returns a description of every table or tab that
+    /// could not be restored.
+    pub fn restore(&self) -> Vec<String> {
+        let mut failed = Vec::new();
+        let mut frames = HashMap::new();
+        for path in self.tables.iter().map(|table| &table.path).unique() {
+            match read_path(path) {
+                Ok(named_frames) => {
+                    // The tables of a file were saved in the order the reader returned them
+                    let mut saved = self
+                        .tables
+                        .iter()
+                        .filter(|table| &table.path == path)
+                        .map(|table| table.name.as_str());
+                    for (name, df) in named_frames {
+                        let source = Source::File(path.to_owned());
+                        let name = match saved.next() {
+                            Some(saved) => {
+                                sql().replace(saved, df.clone(), source);
+                                saved.to_owned()
+                            }
+                            None => sql().register(&name, df.clone(), source),
+                        };
+                        frames.insert(name, df);
+                    }
+                }
+                Err(err) => failed.push(format!("{}: {err}", path.display())),
+            }
+        }
+        for tab in self.tabs.iter() {
+            match tab {
+                SessionTab::Table(name) => match frames.get(name) {
+                    Some(df) => Message::TabsAddNamePane(df.clone(), name.to_owned()).enqueue(),
+                    None => failed.push(format!("Table {name}")),
+                },
+                SessionTab::Query(query) => match sql().execute(query, None) {
+                    Ok(df) => Message::TabsAddQueryPane(df, query.to_owned()).enqueue(),
+                    Err(err) => failed.push(format!("Query {query}: {err}")),
+                },
+            }
+        }
+        failed
+    }
+}
+
+fn session_path(name: &str) -> AppResult<PathBuf> {
+    Ok(sessions_dir()?.join(format!("{name}.toml")))
+}
+
+fn read_path(path: &Path) -> AppResult<Box<[(String, DataFrame)]>> {
+    DetectedToDataFrames.named_frames(reader::Source::File(path.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_round_trip() {
+        let session = Session {
+            tables: vec![SessionTable {
+                name: "sales".to_owned(),
+                path: "/data/sales.csv".into(),
+            }],
+            tabs: vec![
+                SessionTab::Table("sales".to_owned()),
+                SessionTab::Query("SELECT * FROM sales".to_owned()),
+            ],
+        };
+        let contents = toml::to_string_pretty(&session).unwrap();
+        assert_eq!(toml::from_str::<Session>(&contents).unwrap(), session);
+    }
+
+    #[test]
+    fn test_session_restore_saved_names() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session_sales.csv");
+        fs::write(&path, "id,amount\n1,10\n2,20\n3,30\n").unwrap();
+        // The same table opened from the command line before the session is loaded
+        sql().register(
+            "session_sales",
+            polars::df!("id" => [1]).unwrap(),
+            Source::User,
+        );
+        let session = Session {
+            tables: vec![SessionTable {
+                name: "session_sales".to_owned(),
+                path: path.clone(),
+            }],
+            tabs: vec![
+                SessionTab::Table("session_sales".to_owned()),
+                SessionTab::Query("SELECT COUNT(*) AS rows FROM session_sales".to_owned()),
+            ],
+        };
+        assert_eq!(session.restore(), Vec::<String>::new());
+        let backend = sql();
+        let info = backend.schema().get("session_sales").unwrap();
+        assert_eq!(info.height(), 3);
+        assert_eq!(info.source(), &Source::File(path));
+        assert!(backend.schema().get("session_sales_2").is_none());
+    }
+}
@@ -1,23 +1,46 @@
-use std::path::PathBuf;
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::{
+        Arc, LazyLock, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
+use anyhow::anyhow;
 use indexmap::IndexMap;
 use polars::{
-    error::PolarsResult,
+    error::{PolarsResult, polars_bail},
     frame::DataFrame,
-    prelude::{AnyValue, DataType, IntoLazy, LazyFrame},
+    prelude::{AnyValue, DataType, Engine, IntoLazy, LazyFrame, PlanCallback, Schema, col, len},
     series::Series,
 };
 use polars_sql::SQLContext;
+use regex::Regex;
 
-use crate::misc::snake_case_name_gen::SnakeCaseNameGenExt;
+use crate::{
+    AppResult,
+    handler::message::Message,
+    misc::snake_case_name_gen::SnakeCaseNameGenExt,
+    reader::{Preview, ReadToDataFrames},
+};
 
 use super::polars_ext::AnyValueExt;
 
 const DEFAULT_TABLE_NAME: &str = "_";
 
+/// Returns true if the name appears in the query as a word of its own, ignoring case.
+fn refers_to(query: &str, name: &str) -> bool {
+    Regex::new(&format!(r"(?i)\b{}\b", regex::escape(name))).is_ok_and(|re| re.is_match(query))
+}
+
 pub struct SqlBackend {
     sql: SQLContext,
     schema: BackendSchema,
+    views: IndexMap<String, String>,
+    readers: IndexMap<String, Arc<dyn ReadToDataFrames + Send + Sync>>,
+    streaming: bool,
 }
 
 impl SqlBackend {
@@ -25,9 +48,18 @@ impl SqlBackend {
         Self {
             sql: SQLContext::new(),
             schema: Default::default(),
+            views: Default::default(),
+            readers: Default::default(),
+            streaming: false,
         }
     }
 
+    /// Collects query results on the streaming engine, which processes the registered lazy
+    /// tables in batches instead of loading them whole.
+    pub fn set_streaming(&mut self, streaming: bool) {
+        self.streaming = streaming;
+    }
+
     pub fn schema(&self) -> &BackendSchema {
         &self.schema
     }
@@ -45,27 +77,292 @@ impl SqlBackend {
         name
     }
 
+    /// Registers the table under exactly this name, a table or view already registered under it
+    /// is dropped.
+    pub fn replace(&mut self, name: &str, data_frame: DataFrame, input: impl Into<Source>) {
+        self.unregister(name);
+        self.schema
+            .insert(name.to_owned(), TableInfo::new(input.into(), &data_frame));
+        self.sql.register(name, data_frame.lazy());
+    }
+
+    /// Registers a scanned table under exactly this name, a table or view already registered
+    /// under it is dropped.
+    pub fn replace_lazy(
+        &mut self,
+        name: &str,
+        lazy_frame: LazyFrame,
+        input: impl Into<Source>,
+    ) -> PolarsResult<()> {
+        let info = TableInfo::from_lazy(input.into(), lazy_frame.clone())?;
+        self.unregister(name);
+        self.schema.insert(name.to_owned(), info);
+        self.sql.register(name, lazy_frame);
+        Ok(())
+    }
+
+    /// Remembers the reader a table was imported with, a watched file is read again with it.
+    pub fn set_reader(&mut self, name: &str, reader: Arc<dyn ReadToDataFrames + Send + Sync>) {
+        self.readers.insert(name.to_owned(), reader);
+    }
+
+    pub fn reader(&self, name: &str) -> Option<Arc<dyn ReadToDataFrames + Send + Sync>> {
+        self.readers.get(name).cloned()
+    }
+
+    /// Notes that only a window of the rows of the file was loaded into the table.
+    pub fn set_preview(&mut self, name: &str, preview: Preview) {
+        if let Some(info) = self.schema.schema.get_mut(name) {
+            info.preview = Some(preview);
+        }
+    }
+
+    /// Registers a scanned table without collecting it, its statistics are computed in one pass
+    /// over the source.
+    pub fn register_lazy(
+        &mut self,
+        name: &str,
+        lazy_frame: LazyFrame,
+        input: impl Into<Source>,
+    ) -> PolarsResult<String> {
+        let name = self.schema.available_name(name);
+        let info = TableInfo::from_lazy(input.into(), lazy_frame.clone())?;
+        self.schema.insert(name.clone(), info);
+        self.sql.register(&name, lazy_frame);
+        Ok(name)
+    }
+
+    /// Saves the query under the name, it is planned again against the registered tables every
+    /// time a query refers to it instead of being collected once. The query can not read `_`,
+    /// which stands for whichever tab runs a query.
+    pub fn create_view(&mut self, name: &str, query: &str) -> PolarsResult<()> {
+        if self.schema.get(name).is_some() {
+            polars_bail!(SQLInterface: "Table name '{name}' already exists")
+        }
+        if refers_to(query, DEFAULT_TABLE_NAME) {
+            polars_bail!(SQLInterface: "Views can not read '_', name the table of the tab instead")
+        }
+        let lazy_frame = self.sql.execute(query)?;
+        let info = TableInfo::from_lazy(Source::View(query.to_owned()), lazy_frame.clone())?;
+        self.schema.insert(name.to_owned(), info);
+        self.views.insert(name.to_owned(), query.to_owned());
+        self.sql.register(name, lazy_frame);
+        Ok(())
+    }
+
     pub fn unregister(&mut self, name: &str) {
         self.schema.remove(name);
+        self.views.shift_remove(name);
+        self.readers.shift_remove(name);
         self.sql.unregister(name);
     }
 
+    /// Plans the views the query refers to again in the order they were created, a view whose
+    /// tables are gone fails the query.
+    fn resolve_views(&mut self, query: &str) -> PolarsResult<()> {
+        let referenced = self.referenced_views(query);
+        for (name, view) in self.views.clone() {
+            if !referenced.contains(&name) {
+                continue;
+            }
+            match self.sql.execute(&view) {
+                Ok(lazy_frame) => self.sql.register(&name, lazy_frame),
+                Err(err) => {
+                    self.sql.unregister(&name);
+                    polars_bail!(SQLInterface: "View '{name}' cannot be resolved: {err}")
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Views the query refers to, directly or through other views.
+    fn referenced_views(&self, query: &str) -> HashSet<String> {
+        let mut referenced = HashSet::new();
+        let mut pending = vec![query];
+        while let Some(query) = pending.pop() {
+            for (name, view) in self.views.iter() {
+                if !referenced.contains(name) && refers_to(query, name) {
+                    referenced.insert(name.to_owned());
+                    pending.push(view);
+                }
+            }
+        }
+        referenced
+    }
+
+    /// Makes `_` refer to the registered table, a lazily scanned table is queried whole instead
+    /// of through its preview.
+    pub fn set_default(&mut self, name: &str) -> PolarsResult<()> {
+        let lazy_frame = self
+            .sql
+            .execute(&format!("SELECT * FROM \"{}\"", name.replace('"', "\"\"")))?;
+        self.sql.register(DEFAULT_TABLE_NAME, lazy_frame);
+        Ok(())
+    }
+
     pub fn unset_default(&mut self) {
         self.sql.unregister("_");
     }
 
+    /// Executes a query the user ran, its duration is shown in the status bar.
+    pub fn execute_timed(
+        &mut self,
+        query: &str,
+        default_table: impl Into<Option<DataFrame>>,
+    ) -> PolarsResult<DataFrame> {
+        let started = Instant::now();
+        let df = self.execute(query, default_table)?;
+        Message::TabsShowQueryTime(started.elapsed()).enqueue();
+        Ok(df)
+    }
+
     pub fn execute(
         &mut self,
         query: &str,
         default_table: impl Into<Option<DataFrame>>,
     ) -> PolarsResult<DataFrame> {
+        self.prepare(query, default_table)?.collect()
+    }
+
+    /// Plans the query without running it, so it can be collected away from the backend lock.
+    pub fn prepare(
+        &mut self,
+        query: &str,
+        default_table: impl Into<Option<DataFrame>>,
+    ) -> PolarsResult<PreparedQuery> {
+        if let Some(data_frame) = default_table.into() {
+            self.sql.register("_", data_frame.lazy());
+        }
+        self.resolve_views(query)?;
+        Ok(PreparedQuery {
+            lazy_frame: self.sql.execute(query)?,
+            engine: if self.streaming {
+                Engine::Streaming
+            } else {
+                Engine::InMemory
+            },
+        })
+    }
+
+    /// Optimized logical plan of the query, the query itself is not run.
+    pub fn explain(
+        &mut self,
+        query: &str,
+        default_table: impl Into<Option<DataFrame>>,
+    ) -> PolarsResult<String> {
         if let Some(data_frame) = default_table.into() {
             self.sql.register("_", data_frame.lazy());
         }
-        let mut df = self.sql.execute(query).and_then(LazyFrame::collect)?;
+        self.resolve_views(query)?;
+        self.sql.execute(query)?.explain(true)
+    }
+}
+
+/// A planned query together with the engine it will be collected on.
+pub struct PreparedQuery {
+    lazy_frame: LazyFrame,
+    engine: Engine,
+}
+
+impl PreparedQuery {
+    pub fn collect(self) -> PolarsResult<DataFrame> {
+        let mut df = self.lazy_frame.collect_with_engine(self.engine)?;
         df.as_single_chunk_par();
         Ok(df)
     }
+
+    /// Collects the result batch by batch on the streaming engine and stops at the next batch
+    /// once the query is cancelled, a cancelled query returns None.
+    pub fn collect_cancellable(
+        mut self,
+        progress: &QueryProgress,
+    ) -> PolarsResult<Option<DataFrame>> {
+        let schema = self.lazy_frame.collect_schema()?;
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let callback = PlanCallback::new({
+            let batches = batches.clone();
+            let progress = progress.clone();
+            move |df: DataFrame| {
+                if progress.is_cancelled() {
+                    return Ok(true);
+                }
+                batches.lock().unwrap().push(df);
+                Ok(false)
+            }
+        });
+        self.lazy_frame
+            .sink_batches(callback, true, None)?
+            .collect_with_engine(Engine::Streaming)?;
+        if progress.is_cancelled() {
+            return Ok(None);
+        }
+        let mut df = DataFrame::empty_with_schema(&schema);
+        for batch in batches.lock().unwrap().iter() {
+            df.vstack_mut(batch)?;
+        }
+        df.as_single_chunk_par();
+        Ok(Some(df))
+    }
+
+    /// Collects the query on a worker thread with its progress shown in the status bar, `done`
+    /// gets the result unless the query is cancelled first.
+    pub fn spawn(self, done: impl FnOnce(DataFrame) + Send + 'static) {
+        let progress = QueryProgress::new();
+        Message::TabsShowQueryProgress(progress.clone()).enqueue();
+        std::thread::spawn(move || {
+            let result = self.collect_cancellable(&progress);
+            Message::TabsDismissQueryProgress(progress.clone()).enqueue();
+            match result {
+                Ok(Some(df)) => {
+                    Message::TabsShowQueryTime(progress.elapsed()).enqueue();
+                    done(df)
+                }
+                Ok(None) => (),
+                Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+            }
+        });
+    }
+}
+
+/// State of a query running in the background, shared with the status bar which can also cancel
+/// it. A cancelled query stops at the next batch of its result.
+#[derive(Debug, Clone)]
+pub struct QueryProgress {
+    cancelled: Arc<AtomicBool>,
+    started: Instant,
+}
+
+impl QueryProgress {
+    pub fn new() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Returns true if both refer to the same query.
+    pub fn same(&self, other: &QueryProgress) -> bool {
+        Arc::ptr_eq(&self.cancelled, &other.cancelled)
+    }
+}
+
+impl Default for QueryProgress {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Default for SqlBackend {
@@ -124,6 +421,8 @@ pub struct TableInfo {
     total_null: usize,
     total_est_size: usize,
     schema: TableSchema,
+    lazy: bool,
+    preview: Option<Preview>,
 }
 
 impl TableInfo {
@@ -136,9 +435,45 @@ impl TableInfo {
             total_null: schema.iter().map(|(_, info)| info.null_count()).sum(),
             total_est_size: schema.iter().map(|(_, info)| info.estimated_size()).sum(),
             schema,
+            lazy: false,
+            preview: None,
         }
     }
 
+    /// Collects the row count, null counts and bounds of a lazy frame, sizes are unknown and
+    /// left as zero.
+    pub fn from_lazy(input: Source, lazy_frame: LazyFrame) -> PolarsResult<Self> {
+        let columns = lazy_frame.clone().collect_schema()?;
+        let mut exprs = vec![len().alias("len")];
+        for (idx, (name, dtype)) in columns.iter().enumerate() {
+            exprs.push(col(name.clone()).null_count().alias(format!("null_{idx}")));
+            if has_min_max(dtype) {
+                exprs.push(col(name.clone()).min().alias(format!("min_{idx}")));
+                exprs.push(col(name.clone()).max().alias(format!("max_{idx}")));
+            }
+        }
+        let stats = lazy_frame
+            .select(exprs)
+            .collect_with_engine(Engine::Streaming)?;
+        let stat = |name: &str| {
+            stats
+                .column(name)
+                .and_then(|column| column.get(0))
+                .map(AnyValue::into_static)
+        };
+        let schema = TableSchema::from_lazy(&columns, stat)?;
+        Ok(Self {
+            source: input,
+            height: stat("len")?.extract().unwrap_or_default(),
+            width: columns.len(),
+            total_null: schema.iter().map(|(_, info)| info.null_count()).sum(),
+            total_est_size: 0,
+            schema,
+            lazy: true,
+            preview: None,
+        })
+    }
+
     pub fn source(&self) -> &Source {
         &self.source
     }
@@ -162,6 +497,16 @@ impl TableInfo {
     pub fn schema(&self) -> &TableSchema {
         &self.schema
     }
+
+    /// Returns true if the table was registered without being collected.
+    pub fn is_lazy(&self) -> bool {
+        self.lazy
+    }
+
+    /// Rows that were loaded if the file was read with a limit or an offset.
+    pub fn preview(&self) -> Option<&Preview> {
+        self.preview.as_ref()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -169,6 +514,7 @@ pub enum Source {
     File(PathBuf),
     Stdin,
     User,
+    View(String),
 }
 
 impl Source {
@@ -177,6 +523,7 @@ impl Source {
             Source::File(path_buf) => path_buf.to_string_lossy().into_owned(),
             Source::Stdin => "Standard Input".to_owned(),
             Source::User => "User".to_owned(),
+            Source::View(query) => format!("View of {query}"),
         }
     }
 }
@@ -205,6 +552,32 @@ impl TableSchema {
         }
     }
 
+    fn from_lazy(
+        columns: &Schema,
+        stat: impl Fn(&str) -> PolarsResult<AnyValue<'static>>,
+    ) -> PolarsResult<Self> {
+        let mut schema = IndexMap::new();
+        for (idx, (name, dtype)) in columns.iter().enumerate() {
+            let (min, max) = if has_min_max(dtype) {
+                (
+                    stat(&format!("min_{idx}"))?.into_single_line(),
+                    stat(&format!("max_{idx}"))?.into_single_line(),
+                )
+            } else {
+                ("-".to_owned(), "-".to_owned())
+            };
+            let info = FieldInfo {
+                dtype: dtype.to_owned(),
+                est_size: 0,
+                null_count: stat(&format!("null_{idx}"))?.extract().unwrap_or_default(),
+                min,
+                max,
+            };
+            schema.insert(name.to_string(), info);
+        }
+        Ok(Self { schema })
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&String, &FieldInfo)> {
         self.schema.iter()
     }
@@ -259,14 +632,16 @@ impl FieldInfo {
     }
 }
 
-fn min_max(series: &Series) -> (String, String) {
-    let dtype = series.dtype();
-    if dtype.is_primitive_numeric()
+fn has_min_max(dtype: &DataType) -> bool {
+    dtype.is_primitive_numeric()
         || matches!(
             dtype,
             DataType::Time | DataType::Date | DataType::Datetime(_, _)
         )
-    {
+}
+
+fn min_max(series: &Series) -> (String, String) {
+    if has_min_max(series.dtype()) {
         let (a, b) =
             series
                 .iter()
@@ -283,3 +658,480 @@ fn min_max(series: &Series) -> (String, String) {
         ("-".to_owned(), "-".to_owned())
     }
 }
+
+/// Splits a script into its semicolon separated statements, ignoring semicolons inside quotes
+/// and line comments. Empty statements are dropped.
+pub fn split_statements(script: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut quote = None;
+    let mut comment = false;
+    let mut chars = script.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        match (c, quote, comment) {
+            ('\n', _, true) => comment = false,
+            (_, _, true) => (),
+            ('\'' | '"', None, _) => quote = Some(c),
+            (c, Some(q), _) if c == q => quote = None,
+            ('-', None, _) if chars.peek().is_some_and(|(_, n)| *n == '-') => comment = true,
+            (';', None, _) => {
+                statements.push(&script[start..idx]);
+                start = idx + 1;
+            }
+            _ => (),
+        }
+    }
+    statements.push(&script[start..]);
+    statements
+        .into_iter()
+        .map(str::trim)
+        .filter(|stmt| {
+            stmt.lines()
+                .any(|line| !line.trim().is_empty() && !line.trim().starts_with("--"))
+        })
+        .collect()
+}
+
+/// Comma separated column list for a `SELECT`, names that are not plain identifiers are quoted.
+pub fn select_list<'a>(columns: impl IntoIterator<Item = &'a str>) -> String {
+    columns
+        .into_iter()
+        .map(|column| {
+            let plain = column.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && column
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if plain {
+                column.to_owned()
+            } else {
+                format!("\"{}\"", column.replace('"', "\"\""))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Predicate matching the rows where the column equals the value, strings are quoted and a null
+/// value matches the null rows.
+pub fn equals_predicate(column: &str, value: AnyValue) -> AppResult<String> {
+    let column = format!("\"{}\"", column.replace('"', "\"\""));
+    Ok(match value {
+        AnyValue::Null => format!("{column} IS NULL"),
+        AnyValue::String(text) => format!("{column} = '{}'", text.replace('\'', "''")),
+        AnyValue::StringOwned(text) => format!("{column} = '{}'", text.replace('\'', "''")),
+        AnyValue::Boolean(flag) => format!("{column} = {flag}"),
+        AnyValue::Float64(value) => float_predicate(&column, format!("{value:?}"), "DOUBLE"),
+        AnyValue::Float32(value) => float_predicate(&column, format!("{value:?}"), "REAL"),
+        value if value.dtype().is_primitive_numeric() => format!("{column} = {value}"),
+        value => Err(anyhow!(
+            "Filtering by a {} value is not supported",
+            value.dtype()
+        ))?,
+    })
+}
+
+/// Comparison with a float written with every digit it needs to round trip. Exponents, NaN and
+/// the infinities have no SQL literal, their text is cast to the float type instead.
+fn float_predicate(column: &str, literal: String, sql_type: &str) -> String {
+    if literal.contains(|c: char| c.is_ascii_alphabetic()) {
+        format!("{column} = CAST('{literal}' AS {sql_type})")
+    } else {
+        format!("{column} = {literal}")
+    }
+}
+
+/// Predicate matching the rows where every column contains its text, compared as lowercase
+/// strings. Columns with an empty text are left out, None if all of them are.
+pub fn contains_predicate<'a>(
+    filters: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Option<String> {
+    let predicate = filters
+        .into_iter()
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(column, text)| {
+            format!(
+                "STRPOS(LOWER(CAST(\"{}\" AS VARCHAR)), '{}') > 0",
+                column.replace('"', "\"\""),
+                text.to_lowercase().replace('\'', "''")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    (!predicate.is_empty()).then_some(predicate)
+}
+
+/// Expands `other on left = right [and ...] [inner|left|outer]` into a join of `_` with the
+/// registered table `other`, inner if the join type is left out. Returns the other table and the
+/// query.
+pub fn join_query(spec: &str) -> AppResult<(String, String)> {
+    static JOIN: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"(?i)^\s*("[^"]+"|\S+)\s+on\s+(.+?)(?:\s+(inner|left|outer))?\s*$"#).unwrap()
+    });
+    static AND: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\s+and\s+").unwrap());
+
+    let caps = JOIN.captures(spec).ok_or(anyhow!(
+        "Expected 'table on left = right [inner|left|outer]'"
+    ))?;
+    let other = caps[1].trim_matches('"').to_owned();
+    let join = match caps
+        .get(3)
+        .map(|how| how.as_str().to_lowercase())
+        .as_deref()
+    {
+        Some("left") => "LEFT JOIN",
+        Some("outer") => "FULL OUTER JOIN",
+        _ => "INNER JOIN",
+    };
+    let quote = |ident: &str| format!("\"{}\"", ident.trim_matches('"').replace('"', "\"\""));
+    let keys = AND
+        .split(&caps[2])
+        .map(|pair| match pair.split_once('=') {
+            Some((left, right)) if !left.trim().is_empty() && !right.trim().is_empty() => {
+                Ok(format!(
+                    "_.{} = {}.{}",
+                    quote(left.trim()),
+                    quote(&other),
+                    quote(right.trim())
+                ))
+            }
+            _ => Err(anyhow!(
+                "Invalid join key '{}', expected 'left = right'",
+                pair.trim()
+            )),
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+    let query = format!(
+        "SELECT * FROM _ {join} {} ON {}",
+        quote(&other),
+        keys.join(" AND ")
+    );
+    Ok((other, query))
+}
+
+/// Expands `keys :: fn(col) ...` into a grouped query over `_`, each aggregation is aliased as
+/// `col_fn` and `mean` is accepted for `AVG`. Without aggregations the query returns the distinct
+/// keys.
+pub fn group_by_query(spec: &str) -> AppResult<String> {
+    static AGGREGATION: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(\w+)\s*\(\s*([^()]*?)\s*\)").unwrap());
+
+    let (keys, aggs) = spec.split_once("::").unwrap_or((spec, ""));
+    let keys = keys
+        .split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if keys.is_empty() {
+        Err(anyhow!("Group by needs at least one key column"))?
+    }
+
+    let mut columns = vec![keys.clone()];
+    let mut end = 0;
+    for caps in AGGREGATION.captures_iter(aggs) {
+        let whole = caps.get(0).unwrap();
+        let gap = aggs[end..whole.start()].trim_matches(|c: char| c.is_whitespace() || c == ',');
+        if !gap.is_empty() {
+            Err(anyhow!("Invalid aggregation '{gap}'"))?
+        }
+        end = whole.end();
+        let (func, col) = (&caps[1], &caps[2]);
+        let alias = match col.trim_matches('"') {
+            "*" | "" => func.to_lowercase(),
+            name => format!("{name}_{}", func.to_lowercase()),
+        };
+        let func = match func.to_uppercase().as_str() {
+            "MEAN" => "AVG".to_owned(),
+            func => func.to_owned(),
+        };
+        columns.push(format!(
+            "{func}({col}) AS \"{}\"",
+            alias.replace('"', "\"\"")
+        ));
+    }
+    let rest = aggs[end..].trim_matches(|c: char| c.is_whitespace() || c == ',');
+    if !rest.is_empty() {
+        Err(anyhow!("Invalid aggregation '{rest}'"))?
+    }
+
+    Ok(if columns.len() == 1 {
+        format!("SELECT DISTINCT {keys} FROM _ ORDER BY {keys}")
+    } else {
+        format!(
+            "SELECT {} FROM _ GROUP BY {keys} ORDER BY {keys}",
+            columns.join(", ")
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::df;
+
+    use super::*;
+
+    #[test]
+    fn test_register_lazy() {
+        let lf = df!(
+            "a" => [Some(3), None, Some(1)],
+            "b" => ["x", "y", "z"],
+        )
+        .unwrap()
+        .lazy();
+        let mut backend = SqlBackend::new();
+        backend.set_streaming(true);
+        let name = backend.register_lazy("t", lf, Source::User).unwrap();
+        let info = backend.schema().get(&name).unwrap();
+        assert!(info.is_lazy());
+        assert_eq!((info.height(), info.width(), info.total_null()), (3, 2, 1));
+        let (_, a) = info.schema().iter().next().unwrap();
+        assert_eq!((a.min(), a.max()), ("1", "3"));
+        let df = backend
+            .execute(&format!("SELECT b FROM {name} WHERE a > 2"), None)
+            .unwrap();
+        assert_eq!(df.height(), 1);
+    }
+
+    #[test]
+    fn test_view() {
+        let mut backend = SqlBackend::new();
+        backend.register("t", df!("a" => [1, 2, 3]).unwrap(), Source::User);
+        backend.register("u", df!("b" => [1]).unwrap(), Source::User);
+        backend
+            .create_view("v", "SELECT a FROM t WHERE a > 1")
+            .unwrap();
+        assert!(backend.create_view("u", "SELECT 1").is_err());
+        assert!(backend.create_view("w", "SELECT a FROM missing").is_err());
+        assert_eq!(
+            backend.execute("SELECT * FROM v", None).unwrap().height(),
+            2
+        );
+
+        backend.unregister("t");
+        backend.register("t", df!("a" => [5, 6, 7, 8]).unwrap(), Source::User);
+        assert_eq!(
+            backend.execute("SELECT * FROM v", None).unwrap().height(),
+            4
+        );
+
+        backend
+            .create_view("w", "SELECT * FROM v WHERE a > 6")
+            .unwrap();
+        assert!(backend.create_view("x", "SELECT * FROM _").is_err());
+        assert!(backend.create_view("x", "SELECT * FROM \"_\"").is_err());
+
+        backend.unregister("t");
+        assert!(backend.execute("SELECT * FROM u", None).is_ok());
+        let err = backend.execute("SELECT * FROM V", None).unwrap_err();
+        assert!(err.to_string().contains("View 'v'"), "{err}");
+        // A view is resolved through the views it reads
+        let err = backend.execute("SELECT * FROM w", None).unwrap_err();
+        assert!(err.to_string().contains("View 'v'"), "{err}");
+    }
+
+    #[test]
+    fn test_explain() {
+        let mut backend = SqlBackend::new();
+        let df = df!("a" => [1, 2, 3]).unwrap();
+        let plan = backend
+            .explain("SELECT a FROM _ WHERE a > 1", df.clone())
+            .unwrap();
+        assert!(
+            plan.contains("FILTER") || plan.contains("SELECTION"),
+            "{plan}"
+        );
+        assert!(backend.explain("SELECT b FROM _", df).is_err());
+        assert!(backend.explain("SELEC", None).is_err());
+    }
+
+    #[test]
+    fn test_prepare_collect() {
+        let mut backend = SqlBackend::new();
+        let df = df!("a" => [1, 2, 3]).unwrap();
+        let prepared = backend
+            .prepare("SELECT a FROM _ WHERE a > 1", df.clone())
+            .unwrap();
+        assert_eq!(prepared.collect().unwrap(), df!("a" => [2, 3]).unwrap());
+        assert!(backend.prepare("SELEC", None).is_err());
+    }
+
+    #[test]
+    fn test_collect_cancellable() {
+        let mut backend = SqlBackend::new();
+        let df = df!("a" => [3, 1, 2]).unwrap();
+        let query = "SELECT a FROM _ WHERE a > 1 ORDER BY a";
+        let progress = QueryProgress::new();
+        let prepared = backend.prepare(query, df.clone()).unwrap();
+        assert_eq!(
+            prepared.collect_cancellable(&progress).unwrap(),
+            Some(df!("a" => [2, 3]).unwrap())
+        );
+        let prepared = backend
+            .prepare("SELECT a FROM _ WHERE a > 5", None)
+            .unwrap();
+        assert_eq!(
+            prepared
+                .collect_cancellable(&progress)
+                .unwrap()
+                .unwrap()
+                .height(),
+            0
+        );
+        progress.cancel();
+        let prepared = backend.prepare(query, None).unwrap();
+        assert_eq!(prepared.collect_cancellable(&progress).unwrap(), None);
+    }
+
+    #[test]
+    fn test_equals_predicate() {
+        assert_eq!(
+            equals_predicate("name", AnyValue::String("O'Brien")).unwrap(),
+            "\"name\" = 'O''Brien'"
+        );
+        assert_eq!(
+            equals_predicate("a\"b", AnyValue::Int64(-3)).unwrap(),
+            "\"a\"\"b\" = -3"
+        );
+        assert_eq!(
+            equals_predicate("flag", AnyValue::Boolean(true)).unwrap(),
+            "\"flag\" = true"
+        );
+        assert_eq!(
+            equals_predicate("x", AnyValue::Null).unwrap(),
+            "\"x\" IS NULL"
+        );
+        assert!(equals_predicate("d", AnyValue::Date(0)).is_err());
+        assert_eq!(
+            equals_predicate("f", AnyValue::Float64(12.0934509341)).unwrap(),
+            "\"f\" = 12.0934509341"
+        );
+        assert_eq!(
+            equals_predicate("f", AnyValue::Float64(f64::NAN)).unwrap(),
+            "\"f\" = CAST('NaN' AS DOUBLE)"
+        );
+
+        let mut backend = SqlBackend::new();
+        let df = df!("name" => [Some("O'Brien"), None, Some("Smith")]).unwrap();
+        let predicate = equals_predicate("name", AnyValue::String("O'Brien")).unwrap();
+        let filtered = backend
+            .execute(&format!("SELECT * FROM _ WHERE {predicate}"), df)
+            .unwrap();
+        assert_eq!(filtered.height(), 1);
+
+        let df = df!(
+            "f" => [12.0934509341, 1e-9, f64::NAN, f64::NEG_INFINITY, 12.093451],
+            "g" => [0.1f32, 2.5, 3.0, 4.0, 1e-9],
+        )
+        .unwrap();
+        for (column, value) in [
+            ("f", AnyValue::Float64(12.0934509341)),
+            ("f", AnyValue::Float64(1e-9)),
+            ("f", AnyValue::Float64(f64::NAN)),
+            ("f", AnyValue::Float64(f64::NEG_INFINITY)),
+            ("g", AnyValue::Float32(0.1)),
+            ("g", AnyValue::Float32(2.5)),
+            ("g", AnyValue::Float32(1e-9)),
+        ] {
+            let predicate = equals_predicate(column, value).unwrap();
+            let filtered = backend
+                .execute(&format!("SELECT * FROM _ WHERE {predicate}"), df.clone())
+                .unwrap();
+            assert_eq!(filtered.height(), 1, "{predicate}");
+        }
+    }
+
+    #[test]
+    fn test_contains_predicate() {
+        assert_eq!(contains_predicate([("a", ""), ("b", "")]), None);
+        let predicate = contains_predicate([("name", "O'b"), ("id", ""), ("id", "2")]).unwrap();
+        assert_eq!(
+            predicate,
+            "STRPOS(LOWER(CAST(\"name\" AS VARCHAR)), 'o''b') > 0 AND \
+             STRPOS(LOWER(CAST(\"id\" AS VARCHAR)), '2') > 0"
+        );
+
+        let mut backend = SqlBackend::new();
+        let df = df!(
+            "name" => [Some("O'Brien"), None, Some("o'bannon"), Some("Smith")],
+            "id" => [12, 2, 3, 20],
+        )
+        .unwrap();
+        let filtered = backend
+            .execute(&format!("SELECT * FROM _ WHERE {predicate}"), df)
+            .unwrap();
+        assert_eq!(filtered.height(), 1);
+    }
+
+    #[test]
+    fn test_select_list() {
+        assert_eq!(
+            select_list(["price", "_id2", "unit price", "2nd", "say \"hi\""]),
+            "price, _id2, \"unit price\", \"2nd\", \"say \"\"hi\"\"\""
+        );
+    }
+
+    #[test]
+    fn test_join_query() {
+        assert_eq!(
+            join_query("orders on id = customer_id").unwrap(),
+            (
+                "orders".to_owned(),
+                "SELECT * FROM _ INNER JOIN \"orders\" ON _.\"id\" = \"orders\".\"customer_id\""
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            join_query("\"my orders\" ON a = b AND c = \"d\" Left")
+                .unwrap()
+                .1,
+            "SELECT * FROM _ LEFT JOIN \"my orders\" ON _.\"a\" = \"my orders\".\"b\" AND \
+             _.\"c\" = \"my orders\".\"d\""
+        );
+        assert!(join_query("orders").is_err());
+        assert!(join_query("orders on id").is_err());
+
+        let mut backend = SqlBackend::new();
+        backend.register(
+            "orders",
+            df!("customer_id" => [1, 1, 3], "total" => [10, 20, 30]).unwrap(),
+            Source::User,
+        );
+        let customers = df!("id" => [1, 2], "name" => ["a", "b"]).unwrap();
+        let (_, query) = join_query("orders on id = customer_id outer").unwrap();
+        let joined = backend.execute(&query, customers).unwrap();
+        assert_eq!(joined.height(), 4);
+    }
+
+    #[test]
+    fn test_group_by_query() {
+        assert_eq!(
+            group_by_query("region :: sum(sales) mean(units)").unwrap(),
+            "SELECT region, SUM(sales) AS \"sales_sum\", AVG(units) AS \"units_mean\" FROM _ \
+             GROUP BY region ORDER BY region"
+        );
+        assert_eq!(
+            group_by_query("region, year :: count(*)").unwrap(),
+            "SELECT region, year, COUNT(*) AS \"count\" FROM _ GROUP BY region, year \
+             ORDER BY region, year"
+        );
+        assert_eq!(
+            group_by_query("region ::").unwrap(),
+            "SELECT DISTINCT region FROM _ ORDER BY region"
+        );
+        assert!(group_by_query(":: sum(sales)").is_err());
+        assert!(group_by_query("region :: sum(sales) units").is_err());
+    }
+
+    #[test]
+    fn test_split_statements() {
+        assert_eq!(
+            split_statements("SELECT 1; SELECT 2;\n\n"),
+            vec!["SELECT 1", "SELECT 2"]
+        );
+        assert_eq!(
+            split_statements("SELECT ';' AS a; -- done; really\nSELECT \"b;\" FROM t"),
+            vec!["SELECT ';' AS a", "-- done; really\nSELECT \"b;\" FROM t"]
+        );
+        assert!(split_statements(" ; -- only a comment").is_empty());
+    }
+}
@@ -1,6 +1,7 @@
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 
 use ratatui::layout::Constraint;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::{AppResult, handler::message::Message};
 
@@ -36,6 +37,33 @@ impl HasSubsequence for str {
     }
 }
 
+pub trait FitToWidth {
+    fn fit_to_width(&self, width: usize) -> String;
+}
+
+impl FitToWidth for str {
+    /// Truncates the string to the display width, marking the cut with an ellipsis.
+    fn fit_to_width(&self, width: usize) -> String {
+        if self.width() <= width {
+            return self.to_owned();
+        }
+        let mut fitted = String::new();
+        let mut fitted_width = 0;
+        for chr in self.chars() {
+            let chr_width = chr.width().unwrap_or_default();
+            if fitted_width + chr_width + 1 > width {
+                break;
+            }
+            fitted.push(chr);
+            fitted_width += chr_width;
+        }
+        if width > 0 {
+            fitted.push('…');
+        }
+        fitted
+    }
+}
+
 pub trait UnwrapOrGracefulShutdown<T> {
     fn unwrap_or_graceful_shutdown(self) -> T;
 }
@@ -105,3 +133,15 @@ pub fn human_readable_size(volume: u64) -> String {
         )
     }
 }
+
+/// Formats a duration in the largest unit that keeps it above one, fast queries are shown in
+/// microseconds.
+pub fn human_readable_duration(duration: Duration) -> String {
+    if duration < Duration::from_millis(1) {
+        format!("{}µs", duration.as_micros())
+    } else if duration < Duration::from_secs(1) {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
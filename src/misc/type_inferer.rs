@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::LazyLock};
 
 use anyhow::anyhow;
 use chrono::{NaiveDate, NaiveDateTime};
@@ -7,6 +7,7 @@ use polars::{
     prelude::{AnyValue, DataType, PlSmallStr, TimeUnit},
     series::{ChunkCompareEq, Series},
 };
+use regex::Regex;
 
 use crate::{
     AppResult,
@@ -21,6 +22,8 @@ pub struct TypeInferer {
     boolean: bool,
     date: bool,
     datetime: bool,
+    currency: bool,
+    percent: bool,
 }
 
 impl TypeInferer {
@@ -36,6 +39,8 @@ impl TypeInferer {
                     Type::Boolean => type_infer.boolean(),
                     Type::Date => type_infer.date(),
                     Type::Datetime => type_infer.datetime(),
+                    Type::Currency => type_infer.currency(),
+                    Type::Percent => type_infer.percent(),
                     Type::All => type_infer.int().float().boolean().date().datetime(),
                 };
             }
@@ -54,6 +59,14 @@ impl TypeInferer {
                 vec.push(cast_float);
             }
 
+            if self.currency {
+                vec.push(cast_currency);
+            }
+
+            if self.percent {
+                vec.push(cast_percent);
+            }
+
             if self.boolean {
                 vec.push(cast_boolean);
             }
@@ -109,6 +122,16 @@ impl TypeInferer {
         self.datetime = true;
         self
     }
+
+    pub fn currency(mut self) -> Self {
+        self.currency = true;
+        self
+    }
+
+    pub fn percent(mut self) -> Self {
+        self.percent = true;
+        self
+    }
 }
 
 pub fn cast_string(series: &Series) -> AppResult<Series> {
@@ -173,48 +196,179 @@ fn parse_boolean(slice: &str) -> Option<AnyValue<'static>> {
     }
 }
 
+/// Casts values like `$1,234.50`, `-€12` or `1,000` to floats, the thousands separators have to
+/// group the digits by three.
+pub fn cast_currency(series: &Series) -> AppResult<Series> {
+    series
+        .try_map_all(|val| match val {
+            AnyValue::String(s) => parse_currency(s),
+            AnyValue::StringOwned(s) => parse_currency(s.as_str()),
+            AnyValue::Null => Some(AnyValue::Null),
+            _ => None,
+        })
+        .ok_or(anyhow!(
+            "Column '{}' cannot be casted to {}",
+            series.name(),
+            DataType::Float64
+        ))
+}
+
+fn parse_currency(slice: &str) -> Option<AnyValue<'static>> {
+    static CURRENCY: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^([-+]?)\s*[$€£¥₹]?\s*([-+]?)(\d{1,3}(?:,\d{3})+|\d+)(\.\d+)?\s*[$€£¥₹]?$")
+            .unwrap()
+    });
+    let caps = CURRENCY.captures(slice.trim())?;
+    if !caps[1].is_empty() && !caps[2].is_empty() {
+        return None;
+    }
+    let number = format!(
+        "{}{}{}{}",
+        &caps[1],
+        &caps[2],
+        caps[3].replace(',', ""),
+        caps.get(4).map_or("", |frac| frac.as_str())
+    );
+    number.parse().ok().map(AnyValue::Float64)
+}
+
+/// Casts values like `45%` or `-0.5 %` to the fraction they stand for, `45%` becomes 0.45.
+pub fn cast_percent(series: &Series) -> AppResult<Series> {
+    series
+        .try_map_all(|val| match val {
+            AnyValue::String(s) => parse_percent(s),
+            AnyValue::StringOwned(s) => parse_percent(s.as_str()),
+            AnyValue::Null => Some(AnyValue::Null),
+            _ => None,
+        })
+        .ok_or(anyhow!(
+            "Column '{}' cannot be casted to {}",
+            series.name(),
+            DataType::Float64
+        ))
+}
+
+fn parse_percent(slice: &str) -> Option<AnyValue<'static>> {
+    slice
+        .trim()
+        .strip_suffix('%')?
+        .trim_end()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .map(|value| AnyValue::Float64(value / 100.0))
+}
+
+const DATE_FORMATS: [&str; 18] = [
+    "%Y-%m-%d", "%Y/%m/%d", "%Y.%m.%d", "%Y %m %d", "%Y%m%d", "%d-%m-%Y", "%d/%m/%Y", "%d.%m.%Y",
+    "%d %m %Y", "%d%m%Y", "%m-%d-%Y", "%m/%d/%Y", "%m.%d.%Y", "%m %d %Y", "%m%d%Y", "%B %d %Y",
+    "%B-%d-%Y", "%Y-%j",
+];
+
+const DATETIME_FORMATS: [&str; 17] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y/%m/%d %H:%M:%S",
+    "%Y %m %d %H:%M:%S",
+    "%Y.%m.%d %H:%M:%S",
+    "%d-%m-%Y %H:%M:%S",
+    "%d/%m/%Y %H:%M:%S",
+    "%d %m %Y %H:%M:%S",
+    "%d.%m.%Y %H:%M:%S",
+    "%m-%d-%Y %H:%M:%S",
+    "%m/%d/%Y %H:%M:%S",
+    "%m %d %Y %H:%M:%S",
+    "%m.%d.%Y %H:%M:%S",
+    "%B %d %Y %H:%M:%S",
+    "%B-%d-%Y %H:%M:%S",
+    "%Y%m%dT%H%M%S",
+];
+
 pub fn cast_date(series: &Series) -> AppResult<Series> {
-    [
-        "%Y-%m-%d", "%Y/%m/%d", "%Y.%m.%d", "%Y %m %d", "%Y%m%d", "%d-%m-%Y", "%d/%m/%Y",
-        "%d.%m.%Y", "%d %m %Y", "%d%m%Y", "%m-%d-%Y", "%m/%d/%Y", "%m.%d.%Y", "%m %d %Y", "%m%d%Y",
-        "%B %d %Y", "%B-%d-%Y", "%Y-%j",
-    ]
-    .into_iter()
-    .find_map(|fmt| cast_date_with_format(series, fmt))
-    .ok_or(anyhow!(
-        "Column '{}' cannot be casted to {}",
-        series.name(),
-        DataType::Date
-    ))
+    DATE_FORMATS
+        .into_iter()
+        .find_map(|fmt| cast_date_with_format(series, fmt))
+        .ok_or(anyhow!(
+            "Column '{}' cannot be casted to {}",
+            series.name(),
+            DataType::Date
+        ))
 }
 
 pub fn cast_datetime(series: &Series) -> AppResult<Series> {
-    [
-        "%Y-%m-%d %H:%M:%S",
-        "%Y-%m-%dT%H:%M:%S",
-        "%Y-%m-%dT%H:%M:%S%.f",
-        "%Y/%m/%d %H:%M:%S",
-        "%Y %m %d %H:%M:%S",
-        "%Y.%m.%d %H:%M:%S",
-        "%d-%m-%Y %H:%M:%S",
-        "%d/%m/%Y %H:%M:%S",
-        "%d %m %Y %H:%M:%S",
-        "%d.%m.%Y %H:%M:%S",
-        "%m-%d-%Y %H:%M:%S",
-        "%m/%d/%Y %H:%M:%S",
-        "%m %d %Y %H:%M:%S",
-        "%m.%d.%Y %H:%M:%S",
-        "%B %d %Y %H:%M:%S",
-        "%B-%d-%Y %H:%M:%S",
-        "%Y%m%dT%H%M%S",
-    ]
-    .into_iter()
-    .find_map(|fmt| cast_datetime_with_format(series, fmt))
-    .ok_or(anyhow!(
-        "Column '{}' cannot be casted to {}",
-        series.name(),
-        DataType::Datetime(TimeUnit::Milliseconds, None)
-    ))
+    DATETIME_FORMATS
+        .into_iter()
+        .find_map(|fmt| cast_datetime_with_format(series, fmt))
+        .ok_or(anyhow!(
+            "Column '{}' cannot be casted to {}",
+            series.name(),
+            DataType::Datetime(TimeUnit::Milliseconds, None)
+        ))
+}
+
+/// Casts like [`cast_boolean`], values that can not be parsed become null.
+pub fn cast_boolean_lossy(series: &Series) -> AppResult<Series> {
+    cast_lossy(series, DataType::Boolean, parse_boolean)
+}
+
+/// Casts like [`cast_date`] with the format that parses the most values, the other values
+/// become null.
+pub fn cast_date_lossy(series: &Series) -> AppResult<Series> {
+    cast_lossy_with_formats(series, &DATE_FORMATS, DataType::Date, parse_date)
+}
+
+/// Casts like [`cast_datetime`] with the format that parses the most values, the other values
+/// become null.
+pub fn cast_datetime_lossy(series: &Series) -> AppResult<Series> {
+    cast_lossy_with_formats(
+        series,
+        &DATETIME_FORMATS,
+        DataType::Datetime(TimeUnit::Milliseconds, None),
+        parse_datetime,
+    )
+}
+
+fn cast_lossy_with_formats(
+    series: &Series,
+    formats: &[&'static str],
+    dtype: DataType,
+    parse: fn(&str, &str) -> Option<AnyValue<'static>>,
+) -> AppResult<Series> {
+    let mut best: Option<Series> = None;
+    for &fmt in formats {
+        let casted = cast_lossy(series, dtype.clone(), move |slice| parse(slice, fmt))?;
+        let parsed_all = casted.null_count() == series.null_count();
+        if best
+            .as_ref()
+            .is_none_or(|best| casted.null_count() < best.null_count())
+        {
+            best = Some(casted);
+        }
+        if parsed_all {
+            break;
+        }
+    }
+    best.ok_or(anyhow!("No formats to cast '{}' with", series.name()))
+}
+
+fn cast_lossy(
+    series: &Series,
+    dtype: DataType,
+    parse: impl Fn(&str) -> Option<AnyValue<'static>> + Sync + Send + 'static,
+) -> AppResult<Series> {
+    let casted = series
+        .try_map_all(move |val| match val {
+            AnyValue::String(s) => Some(parse(s).unwrap_or(AnyValue::Null)),
+            AnyValue::StringOwned(s) => Some(parse(s.as_str()).unwrap_or(AnyValue::Null)),
+            _ => Some(AnyValue::Null),
+        })
+        .ok_or(anyhow!(
+            "Column '{}' cannot be casted to {dtype}",
+            series.name()
+        ))?;
+    // A column without a single parsed value comes back with the null type
+    Ok(casted.cast(&dtype)?)
 }
 
 fn cast_date_with_format(series: &Series, fmt: &'static str) -> Option<Series> {
@@ -257,3 +411,38 @@ fn parse_datetime(slice: &str, fmt: &str) -> Option<AnyValue<'static>> {
         })
         .ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::NamedFrom;
+
+    use super::*;
+
+    #[test]
+    fn test_currency_and_percent() {
+        let mut df = DataFrame::new(vec![
+            Series::new("price".into(), [Some("$1,234.50"), Some("-€12"), None]).into(),
+            Series::new("share".into(), ["45%", "-0.5 %", "100%"]).into(),
+            Series::new("mixed".into(), ["45%", "0.3", "1%"]).into(),
+            Series::new("grouping".into(), ["1,23", "$5", "7"]).into(),
+        ])
+        .unwrap();
+        TypeInferer::default()
+            .float()
+            .currency()
+            .percent()
+            .update(&mut df);
+        let floats = |name: &str| {
+            df.column(name)
+                .unwrap()
+                .f64()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(floats("price"), [Some(1234.5), Some(-12.0), None]);
+        assert_eq!(floats("share"), [Some(0.45), Some(-0.005), Some(1.0)]);
+        assert_eq!(df.column("mixed").unwrap().dtype(), &DataType::String);
+        assert_eq!(df.column("grouping").unwrap().dtype(), &DataType::String);
+    }
+}
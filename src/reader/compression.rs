@@ -0,0 +1,78 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use flate2::read::MultiGzDecoder;
+
+use crate::AppResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Plain,
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// Detects the codec from the magic bytes at the start of the reader and rewinds it.
+    pub fn detect(reader: &mut (impl Read + Seek)) -> AppResult<Self> {
+        let mut magic = Vec::with_capacity(4);
+        reader.by_ref().take(4).read_to_end(&mut magic)?;
+        reader.seek(SeekFrom::Start(0))?;
+        Ok(match magic.as_slice() {
+            [0x1f, 0x8b, ..] => Compression::Gzip,
+            [0x28, 0xb5, 0x2f, 0xfd] => Compression::Zstd,
+            _ => Compression::Plain,
+        })
+    }
+
+    pub fn decompress(&self, mut reader: impl Read) -> AppResult<Vec<u8>> {
+        let mut buf = Vec::new();
+        match self {
+            Compression::Plain => reader.read_to_end(&mut buf)?,
+            Compression::Gzip => MultiGzDecoder::new(reader).read_to_end(&mut buf)?,
+            Compression::Zstd => zstd::Decoder::new(reader)?.read_to_end(&mut buf)?,
+        };
+        Ok(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use flate2::{Compression as GzLevel, write::GzEncoder};
+
+    use super::*;
+
+    fn round_trip(compressed: Vec<u8>) -> (Compression, Vec<u8>) {
+        let mut reader = Cursor::new(compressed);
+        let compression = Compression::detect(&mut reader).unwrap();
+        (compression, compression.decompress(reader).unwrap())
+    }
+
+    #[test]
+    fn test_gzip_round_trip() {
+        let mut encoder = GzEncoder::new(Vec::new(), GzLevel::default());
+        encoder.write_all(b"a,b\n1,2\n").unwrap();
+        assert_eq!(
+            round_trip(encoder.finish().unwrap()),
+            (Compression::Gzip, b"a,b\n1,2\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_zstd_round_trip() {
+        let compressed = zstd::encode_all(b"a,b\n1,2\n".as_slice(), 0).unwrap();
+        assert_eq!(
+            round_trip(compressed),
+            (Compression::Zstd, b"a,b\n1,2\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_plain() {
+        assert_eq!(
+            round_trip(b"a,b".to_vec()),
+            (Compression::Plain, b"a,b".to_vec())
+        );
+    }
+}
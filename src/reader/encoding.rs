@@ -0,0 +1,52 @@
+use anyhow::anyhow;
+use encoding_rs::{UTF_16LE, WINDOWS_1252};
+
+use crate::{AppResult, args::Encoding};
+
+/// Decodes the bytes of a text file, UTF-16 input without a byte order mark is taken as little
+/// endian.
+pub fn decode(bytes: Vec<u8>, encoding: Encoding) -> AppResult<String> {
+    match encoding {
+        Encoding::Utf8 => Ok(String::from_utf8(bytes)?),
+        // Every byte is the code point of the same value
+        Encoding::Latin1 => Ok(bytes.into_iter().map(char::from).collect()),
+        Encoding::Windows1252 => Ok(WINDOWS_1252
+            .decode_without_bom_handling(&bytes)
+            .0
+            .into_owned()),
+        Encoding::Utf16 => {
+            let (text, _, had_errors) = UTF_16LE.decode(&bytes);
+            if had_errors {
+                Err(anyhow!("Input is not valid UTF-16"))?
+            }
+            Ok(text.into_owned())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            decode(b"caf\xe9".to_vec(), Encoding::Latin1).unwrap(),
+            "café"
+        );
+        assert_eq!(
+            decode(b"\x80 caf\xe9".to_vec(), Encoding::Windows1252).unwrap(),
+            "€ café"
+        );
+        assert_eq!(
+            decode(b"\xff\xfea\x00\xe9\x00".to_vec(), Encoding::Utf16).unwrap(),
+            "aé"
+        );
+        assert_eq!(
+            decode(b"\xfe\xff\x00a\x00\xe9".to_vec(), Encoding::Utf16).unwrap(),
+            "aé"
+        );
+        assert!(decode(b"\x00\xd8".to_vec(), Encoding::Utf16).is_err());
+        assert!(decode(b"caf\xe9".to_vec(), Encoding::Utf8).is_err());
+    }
+}
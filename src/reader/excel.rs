@@ -1,65 +1,197 @@
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Read, Seek};
 
 use calamine::{Data, Range, Reader, open_workbook_auto_from_rs};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use itertools::Itertools;
 use polars::{
     frame::DataFrame,
-    prelude::{AnyValue, NamedFrom},
+    prelude::{AnyValue, NamedFrom, PlSmallStr},
     series::Series,
 };
 
-use crate::{AppResult, args::Args, misc::globals::stdin};
+use crate::{
+    AppResult,
+    args::Args,
+    misc::{globals::stdin, snake_case_name_gen::SnakeCaseNameGenExt},
+};
 
 use super::{NamedFrames, ReadToDataFrames, Source};
 
 #[derive(Debug, Default)]
-pub struct ExcelToDataFarmes;
+pub struct ExcelToDataFarmes {
+    has_header: Option<bool>,
+}
 
 impl ExcelToDataFarmes {
-    pub fn from_args(_args: &Args) -> Self {
-        Self
+    pub fn from_args(args: &Args) -> Self {
+        Self {
+            has_header: args.no_header.then_some(false),
+        }
+    }
+
+    /// Whether the first row of each sheet holds the column names, detected per sheet if `None`.
+    pub fn with_has_header(mut self, has_header: impl Into<Option<bool>>) -> Self {
+        self.has_header = has_header.into();
+        self
     }
 }
 
 impl ReadToDataFrames for ExcelToDataFarmes {
     fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
-        let buffer = match input {
-            Source::File(path) => Cursor::new(std::fs::read(path)?),
-            Source::Stdin => {
-                let mut buf = Vec::new();
-                stdin().read_to_end(&mut buf).unwrap();
-                Cursor::new(buf)
-            }
-        };
-        Ok(open_workbook_auto_from_rs(buffer)?
-            .worksheets()
-            .into_iter()
-            .map(|(name, sheet)| {
-                let df = sheet_to_data_frame(sheet);
-                (name, df)
-            })
-            .collect_vec()
-            .into_boxed_slice())
+        Ok(sheet_frames(
+            open_workbook_auto_from_rs(read_input(input)?)?,
+            self.has_header,
+        ))
+    }
+}
+
+/// Whole file or piped input in memory, spreadsheet readers need to seek.
+pub(super) fn read_input(input: Source) -> AppResult<Cursor<Vec<u8>>> {
+    Ok(Cursor::new(match input {
+        Source::File(path) => std::fs::read(path)?,
+        Source::Stdin => {
+            let mut buf = Vec::new();
+            stdin().read_to_end(&mut buf)?;
+            buf
+        }
+    }))
+}
+
+/// One data frame per non-empty sheet of the workbook, the header is detected per sheet if
+/// `has_header` is `None`.
+pub(super) fn sheet_frames<RS: Read + Seek>(
+    mut workbook: impl Reader<RS>,
+    has_header: Option<bool>,
+) -> NamedFrames {
+    sheets_to_frames(workbook.worksheets(), has_header)
+}
+
+fn sheets_to_frames(sheets: Vec<(String, Range<Data>)>, has_header: Option<bool>) -> NamedFrames {
+    sheets
+        .into_iter()
+        .filter(|(_, sheet)| {
+            sheet
+                .used_cells()
+                .any(|(_, _, cell)| !matches!(cell, Data::Empty))
+        })
+        .map(|(name, sheet)| {
+            let has_header = has_header.unwrap_or_else(|| detect_header(&sheet));
+            let df = sheet_to_data_frame(sheet, has_header);
+            (name, df)
+        })
+        .collect_vec()
+        .into_boxed_slice()
+}
+
+/// Treats the first row as a header if it only contains distinct non-empty strings and, for
+/// sheets with typed data, rows below it contain non-string values.
+fn detect_header(sheet: &Range<Data>) -> bool {
+    let mut rows = sheet.rows();
+    let Some(first) = rows.next() else {
+        return false;
+    };
+    let all_strings = first
+        .iter()
+        .all(|cell| matches!(cell, Data::String(s) if !s.trim().is_empty()));
+    if !all_strings || !first.iter().map(ToString::to_string).all_unique() {
+        return false;
     }
+    let mut rest = rows.flat_map(|row| row.iter()).peekable();
+    rest.peek().is_some()
+        && (rest.any(|cell| !matches!(cell, Data::String(_) | Data::Empty))
+            || sheet.rows().skip(1).all(|row| {
+                row.iter()
+                    .zip(first)
+                    .all(|(cell, header)| cell.to_string() != header.to_string())
+            }))
 }
 
-fn sheet_to_data_frame(sheet: Range<Data>) -> DataFrame {
+fn sheet_to_data_frame(sheet: Range<Data>, has_header: bool) -> DataFrame {
     let col_offset = sheet.start().unwrap_or_default().1 as usize;
     let mut columns = vec![Vec::new(); sheet.width()];
-    for row in sheet.rows() {
+    let mut rows = sheet.rows();
+    let header = if has_header {
+        rows.next().map(|row| {
+            row.iter()
+                .enumerate()
+                .fold(Vec::<String>::new(), |mut names, (idx, cell)| {
+                    let base = match cell {
+                        Data::Empty => col_letter(col_offset + idx),
+                        _ => cell.to_string(),
+                    };
+                    if let Some(name) = base.snake_case_names().find(|name| !names.contains(name)) {
+                        names.push(name);
+                    }
+                    names
+                })
+        })
+    } else {
+        None
+    };
+    for row in rows {
         for (idx, cell) in row.iter().enumerate() {
-            columns[idx].push(match cell {
-                Data::Empty => AnyValue::Null,
-                _ => AnyValue::StringOwned(cell.to_string().into()),
-            });
+            columns[idx].push(cell);
         }
     }
-    DataFrame::from_iter(
-        columns
-            .into_iter()
-            .enumerate()
-            .map(|(idx, column)| Series::new(col_letter(col_offset + idx).into(), column)),
-    )
+    DataFrame::from_iter(columns.into_iter().enumerate().map(|(idx, cells)| {
+        let name = header
+            .as_ref()
+            .and_then(|header| header.get(idx).cloned())
+            .unwrap_or_else(|| col_letter(col_offset + idx));
+        column_series(name.into(), &cells)
+    }))
+}
+
+/// Date or datetime series if every non-empty cell holds an ISO date, as OpenDocument date
+/// cells do, a string series otherwise.
+fn column_series(name: PlSmallStr, cells: &[&Data]) -> Series {
+    let datetimes = cells
+        .iter()
+        .map(|cell| match cell {
+            Data::Empty => Some(None),
+            Data::DateTimeIso(iso) => parse_iso_datetime(iso).map(Some),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .filter(|datetimes| datetimes.iter().any(Option::is_some));
+    match datetimes {
+        Some(datetimes)
+            if datetimes
+                .iter()
+                .flatten()
+                .all(|datetime| datetime.time() == NaiveTime::MIN) =>
+        {
+            Series::new(
+                name,
+                datetimes
+                    .into_iter()
+                    .map(|datetime| datetime.map(|datetime| datetime.date()))
+                    .collect_vec(),
+            )
+        }
+        Some(datetimes) => Series::new(name, datetimes),
+        None => Series::new(
+            name,
+            cells
+                .iter()
+                .map(|cell| match cell {
+                    Data::Empty => AnyValue::Null,
+                    _ => AnyValue::StringOwned(cell.to_string().into()),
+                })
+                .collect_vec(),
+        ),
+    }
+}
+
+/// Parses `2024-03-10` and `2024-03-10T14:30:00`, with optional fractional seconds.
+fn parse_iso_datetime(iso: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(iso, "%Y-%m-%dT%H:%M:%S%.f")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(iso, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
 }
 
 fn col_letter(mut col_index: usize) -> String {
@@ -75,3 +207,118 @@ fn col_letter(mut col_index: usize) -> String {
     }
     col_letter
 }
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::DataType;
+
+    use super::*;
+
+    fn sheet(rows: &[&[Data]]) -> Range<Data> {
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+        let mut range = Range::new((0, 0), (rows.len() as u32 - 1, width as u32 - 1));
+        for (row, cells) in rows.iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                range.set_value((row as u32, column as u32), cell.clone());
+            }
+        }
+        range
+    }
+
+    fn text(value: &str) -> Data {
+        Data::String(value.to_owned())
+    }
+
+    #[test]
+    fn test_detect_header() {
+        let header = sheet(&[
+            &[text("name"), text("amount")],
+            &[text("Ann"), Data::Float(1.5)],
+            &[text("Bob"), Data::Int(2)],
+        ]);
+        assert!(detect_header(&header));
+
+        let numeric = sheet(&[
+            &[Data::Int(1), Data::Float(2.0)],
+            &[Data::Int(3), Data::Float(4.0)],
+        ]);
+        assert!(!detect_header(&numeric));
+
+        let strings = sheet(&[&[text("name"), text("city")], &[text("Ann"), text("Oslo")]]);
+        assert!(detect_header(&strings));
+
+        // A value repeating the first row in its column makes it data rather than names
+        let repeated = sheet(&[&[text("Ann"), text("Oslo")], &[text("Bob"), text("Oslo")]]);
+        assert!(!detect_header(&repeated));
+
+        let single_row = sheet(&[&[text("name"), text("city")]]);
+        assert!(!detect_header(&single_row));
+    }
+
+    #[test]
+    fn test_sheets_to_frames() {
+        let frames = sheets_to_frames(
+            vec![
+                (
+                    "Sales".to_owned(),
+                    sheet(&[
+                        &[text("id"), text("amount")],
+                        &[Data::Int(1), Data::Int(10)],
+                    ]),
+                ),
+                ("Empty".to_owned(), Range::empty()),
+                ("Blank".to_owned(), sheet(&[&[Data::Empty, Data::Empty]])),
+                ("Raw".to_owned(), sheet(&[&[Data::Int(1)], &[Data::Int(2)]])),
+            ],
+            None,
+        );
+        assert_eq!(
+            frames.iter().map(|(name, _)| name.as_str()).collect_vec(),
+            ["Sales", "Raw"]
+        );
+        assert_eq!(frames[0].1.get_column_names(), ["id", "amount"]);
+        assert_eq!(frames[0].1.height(), 1);
+        assert_eq!(frames[1].1.get_column_names(), ["A"]);
+        assert_eq!(frames[1].1.height(), 2);
+
+        let frames = sheets_to_frames(
+            vec![(
+                "Sales".to_owned(),
+                sheet(&[
+                    &[text("id"), text("amount")],
+                    &[Data::Int(1), Data::Int(10)],
+                ]),
+            )],
+            Some(false),
+        );
+        assert_eq!(frames[0].1.get_column_names(), ["A", "B"]);
+        assert_eq!(frames[0].1.height(), 2);
+    }
+
+    #[test]
+    fn test_column_series() {
+        let dates = [
+            Data::DateTimeIso("2024-03-10".to_owned()),
+            Data::Empty,
+            Data::DateTimeIso("2024-12-31".to_owned()),
+        ];
+        let series = column_series("day".into(), &dates.iter().collect_vec());
+        assert_eq!(series.dtype(), &DataType::Date);
+        assert_eq!(series.null_count(), 1);
+
+        let datetimes = [
+            Data::DateTimeIso("2024-03-10".to_owned()),
+            Data::DateTimeIso("2024-03-10T14:30:00.5".to_owned()),
+        ];
+        let series = column_series("at".into(), &datetimes.iter().collect_vec());
+        assert!(matches!(series.dtype(), DataType::Datetime(_, None)));
+
+        let mixed = [
+            Data::DateTimeIso("2024-03-10".to_owned()),
+            Data::String("soon".to_owned()),
+        ];
+        let series = column_series("when".into(), &mixed.iter().collect_vec());
+        assert_eq!(series.dtype(), &DataType::String);
+        assert_eq!(series.str().unwrap().get(0), Some("2024-03-10"));
+    }
+}
@@ -1,37 +1,58 @@
 use std::{
     collections::HashSet,
-    fs::read_to_string,
-    io::{self, Cursor, Read},
+    fs::{read, read_to_string},
+    io::{Cursor, Read},
     iter::once,
+    path::Path,
 };
 
+use anyhow::anyhow;
+
 use fwf_rs::Reader;
 use itertools::Itertools;
 use polars::{frame::DataFrame, prelude::NamedFrom, series::Series};
 
 use crate::{
     AppResult,
-    args::Args,
-    misc::{iter_ext::ZipItersExt, snake_case_name_gen::SnakeCaseNameGenExt},
+    args::{Args, Encoding},
+    handler::message::Message,
+    misc::{globals::stdin, iter_ext::ZipItersExt, snake_case_name_gen::SnakeCaseNameGenExt},
 };
 
-use super::{NamedFrames, ReadToDataFrames, Source};
+use super::{NamedFrames, ReadToDataFrames, Source, decode};
 
 pub struct FwfToDataFrame {
     widths: Vec<usize>,
+    names: Vec<String>,
     has_header: bool,
     separator_length: usize,
     flexible_width: bool,
+    encoding: Encoding,
 }
 
 impl FwfToDataFrame {
-    pub fn from_args(args: &Args) -> Self {
-        Self {
+    pub fn from_args(args: &Args) -> AppResult<Self> {
+        let reader = Self {
             widths: parse_width(&args.widths).unwrap_or_default(),
+            names: Vec::default(),
             has_header: !args.no_header,
             separator_length: args.separator_length,
             flexible_width: !args.no_flexible_width,
-        }
+            encoding: args.encoding,
+        };
+        Ok(if let Some(path) = &args.schema_file {
+            if !args.widths.is_empty() {
+                Message::AppShowToast(format!(
+                    "Widths '{}' are ignored, {} defines the columns",
+                    args.widths,
+                    path.display()
+                ))
+                .enqueue();
+            }
+            reader.with_schema(read_schema_file(path)?)
+        } else {
+            reader
+        })
     }
 
     pub fn with_widths(mut self, widths: Vec<usize>) -> Self {
@@ -39,6 +60,17 @@ impl FwfToDataFrame {
         self
     }
 
+    /// Overrides the column names read from the header row (if any).
+    pub fn with_names(mut self, names: Vec<String>) -> Self {
+        self.names = names;
+        self
+    }
+
+    pub fn with_schema(self, schema: Vec<(String, usize)>) -> Self {
+        let (names, widths) = schema.into_iter().unzip();
+        self.with_names(names).with_widths(widths)
+    }
+
     pub fn with_has_header(mut self, has_header: bool) -> Self {
         self.has_header = has_header;
         self
@@ -53,15 +85,22 @@ impl FwfToDataFrame {
         self.flexible_width = flexible_width;
         self
     }
+
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
 }
 
 impl Default for FwfToDataFrame {
     fn default() -> Self {
         Self {
             widths: Vec::default(),
+            names: Vec::default(),
             has_header: true,
             separator_length: 0,
             flexible_width: true,
+            encoding: Encoding::Utf8,
         }
     }
 }
@@ -69,11 +108,11 @@ impl Default for FwfToDataFrame {
 impl ReadToDataFrames for FwfToDataFrame {
     fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
         let file_content = match &input {
-            Source::File(path) => read_to_string(path)?,
+            Source::File(path) => decode(read(path)?, self.encoding)?,
             Source::Stdin => {
-                let mut buf = String::new();
-                io::stdin().read_to_string(&mut buf)?;
-                buf
+                let mut buf = Vec::new();
+                stdin().read_to_end(&mut buf)?;
+                decode(buf, self.encoding)?
             }
         };
 
@@ -105,23 +144,29 @@ impl ReadToDataFrames for FwfToDataFrame {
             self.flexible_width,
             self.has_header,
         )?;
-        let header = reader
-            .header()
-            .map(|rec| {
-                rec.iter().fold(Vec::new(), |mut vec, slice| {
-                    if let Some(name) = slice.snake_case_names().find(|name| !vec.contains(name)) {
-                        vec.push(name);
-                    } else {
-                        panic!("Not implemented")
-                    }
-                    vec
+        let header = if self.names.is_empty() {
+            reader
+                .header()
+                .map(|rec| {
+                    rec.iter().fold(Vec::new(), |mut vec, slice| {
+                        if let Some(name) =
+                            slice.snake_case_names().find(|name| !vec.contains(name))
+                        {
+                            vec.push(name);
+                        } else {
+                            panic!("Not implemented")
+                        }
+                        vec
+                    })
                 })
-            })
-            .unwrap_or_else(|| {
-                (0..widths.len())
-                    .map(|idx| format!("column_{}", idx + 1))
-                    .collect_vec()
-            });
+                .unwrap_or_else(|| {
+                    (0..widths.len())
+                        .map(|idx| format!("column_{}", idx + 1))
+                        .collect_vec()
+                })
+        } else {
+            self.names.clone()
+        };
 
         let columns = reader
             .records()
@@ -155,6 +200,27 @@ fn parse_width(widths: impl AsRef<str>) -> AppResult<Vec<usize>> {
         .collect::<Result<Vec<_>, _>>()?)
 }
 
+/// Reads a schema file with one `name:width` pair per line. Empty lines and lines starting
+/// with `#` are ignored.
+pub fn read_schema_file(path: impl AsRef<Path>) -> AppResult<Vec<(String, usize)>> {
+    read_to_string(path)?
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            let (name, width) = line
+                .rsplit_once(':')
+                .ok_or_else(|| anyhow!("Line {}: expected 'name:width'", idx + 1))?;
+            let width = width
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| anyhow!("Line {}: invalid width '{}'", idx + 1, width.trim()))?;
+            Ok((name.trim().to_owned(), width))
+        })
+        .collect()
+}
+
 fn infer_widths(space_indices: Vec<usize>) -> Vec<usize> {
     let mut indices = Vec::default();
     let mut start = 0;
@@ -171,3 +237,36 @@ fn infer_widths(space_indices: Vec<usize>) -> Vec<usize> {
     }
     indices
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use clap::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_schema_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let schema = dir.path().join("layout.txt");
+        fs::write(&schema, "# layout\nid:3\n\nfull name:6\n").unwrap();
+        let args = Args::parse_from([
+            "tw".as_ref(),
+            "--widths".as_ref(),
+            "1,1".as_ref(),
+            "--schema-file".as_ref(),
+            schema.as_os_str(),
+        ]);
+        let reader = FwfToDataFrame::from_args(&args).unwrap();
+        assert_eq!(reader.widths, [3, 6]);
+        assert_eq!(reader.names, ["id", "full name"]);
+
+        fs::write(&schema, "id:3\nname\n").unwrap();
+        let err = read_schema_file(&schema).unwrap_err().to_string();
+        assert!(err.starts_with("Line 2:"), "{err}");
+        fs::write(&schema, "id:three\n").unwrap();
+        let err = read_schema_file(&schema).unwrap_err().to_string();
+        assert!(err.contains("invalid width 'three'"), "{err}");
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Cursor},
+};
+
+use polars::{
+    frame::DataFrame,
+    io::{SerReader, mmap::MmapBytesReader},
+    prelude::JsonLineReader,
+};
+
+use crate::{AppResult, args::Args, handler::message::Message, misc::globals::stdin};
+
+use super::{NamedFrames, ReadToDataFrames, Source};
+
+/// Lines checked together when malformed lines are skipped, only a batch that fails to parse is
+/// checked line by line.
+const BATCH_LINES: usize = 10_000;
+
+pub struct JsonLineToDataFrame {
+    ignore_errors: bool,
+    limit: Option<usize>,
+    offset: usize,
+    skip_errors: bool,
+}
+
+impl JsonLineToDataFrame {
+    pub fn from_args(args: &Args) -> Self {
+        Self {
+            ignore_errors: args.ignore_errors,
+            limit: args.limit,
+            offset: args.offset,
+            skip_errors: args.skip_errors,
+        }
+    }
+
+    /// Reads at most this many records, the rest of the input is never read.
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Skips this many records before reading, the skipped lines are not parsed.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Drops lines that are not valid JSON instead of failing the whole read.
+    pub fn with_skip_errors(mut self, skip_errors: bool) -> Self {
+        self.skip_errors = skip_errors;
+        self
+    }
+
+    fn parse(&self, reader: impl MmapBytesReader) -> AppResult<DataFrame> {
+        Ok(JsonLineReader::new(reader)
+            .with_rechunk(true)
+            .infer_schema_len(None)
+            .with_ignore_errors(self.ignore_errors)
+            .finish()?)
+    }
+
+    /// Collects the lines up to the limit and parses them at once, also returns the number of
+    /// malformed lines that were skipped.
+    fn read_lines(&self, reader: impl BufRead) -> AppResult<(DataFrame, usize)> {
+        let limit = self.limit.unwrap_or(usize::MAX);
+        let mut kept = String::new();
+        let mut count = 0;
+        let mut dropped = 0;
+        let mut lines = reader
+            .lines()
+            .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()));
+        for line in lines.by_ref().take(self.offset) {
+            line?;
+        }
+        loop {
+            // Skipped lines do not count, the next batch fills up to the limit again
+            let batch = lines
+                .by_ref()
+                .take(BATCH_LINES.min(limit - count))
+                .collect::<Result<Vec<_>, _>>()?;
+            if batch.is_empty() {
+                break;
+            }
+            let valid = if !self.skip_errors || self.is_valid(&batch.join("\n")) {
+                batch
+            } else {
+                let (valid, malformed): (Vec<_>, Vec<_>) =
+                    batch.into_iter().partition(|line| self.is_valid(line));
+                dropped += malformed.len();
+                valid
+            };
+            for line in valid {
+                kept.push_str(&line);
+                kept.push('\n');
+                count += 1;
+            }
+        }
+        Ok((self.parse(Cursor::new(kept))?, dropped))
+    }
+
+    fn is_valid(&self, text: &str) -> bool {
+        self.parse(Cursor::new(text)).is_ok()
+    }
+}
+
+impl Default for JsonLineToDataFrame {
+    fn default() -> Self {
+        Self {
+            ignore_errors: true,
+            limit: None,
+            offset: 0,
+            skip_errors: false,
+        }
+    }
+}
+
+impl ReadToDataFrames for JsonLineToDataFrame {
+    fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
+        let df = if self.limit.is_none() && self.offset == 0 && !self.skip_errors {
+            match &input {
+                Source::File(path) => self.parse(File::open(path)?)?,
+                Source::Stdin => self.parse(stdin())?,
+            }
+        } else {
+            let (df, dropped) = match &input {
+                Source::File(path) => self.read_lines(BufReader::new(File::open(path)?))?,
+                Source::Stdin => self.read_lines(stdin())?,
+            };
+            if dropped > 0 {
+                Message::AppShowToast(format!("Skipped {dropped} malformed lines")).enqueue();
+            }
+            df
+        };
+        Ok([(input.table_name(), df)].into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_lines() {
+        let text = "{\"a\": 1}\n\n{\"a\": 2}\n{\"a\": \n{\"a\": 3}\n{\"a\": 4}\n";
+        let reader = JsonLineToDataFrame::default().with_skip_errors(true);
+        let (df, dropped) = reader.read_lines(Cursor::new(text)).unwrap();
+        assert_eq!((df.height(), dropped), (4, 1));
+
+        let (df, dropped) = reader
+            .with_limit(Some(3))
+            .read_lines(Cursor::new(text))
+            .unwrap();
+        assert_eq!((df.height(), dropped), (3, 1));
+        assert_eq!(df.column("a").unwrap().i64().unwrap().get(2), Some(3));
+
+        let reader = JsonLineToDataFrame::default().with_limit(Some(2));
+        assert_eq!(reader.read_lines(Cursor::new(text)).unwrap().0.height(), 2);
+        let (df, _) = JsonLineToDataFrame::default()
+            .with_limit(Some(1))
+            .with_offset(1)
+            .read_lines(Cursor::new(text))
+            .unwrap();
+        assert_eq!(df.column("a").unwrap().i64().unwrap().get(0), Some(2));
+        assert!(
+            JsonLineToDataFrame::default()
+                .with_limit(Some(4))
+                .read_lines(Cursor::new(text))
+                .is_err()
+        );
+    }
+}
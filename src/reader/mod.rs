@@ -1,32 +1,58 @@
+mod compression;
+mod encoding;
 mod excel;
 mod fwf;
+mod jsonl;
 mod logfmt;
+mod multi_file;
+mod ods;
+mod preview;
+mod sniff;
 mod sqlite;
 
 use anyhow::{Ok, anyhow};
+pub use compression::Compression;
+pub use encoding::decode;
 pub use excel::ExcelToDataFarmes;
-pub use fwf::FwfToDataFrame;
+pub use fwf::{FwfToDataFrame, read_schema_file};
+pub use jsonl::JsonLineToDataFrame;
 pub use logfmt::LogfmtToDataFrame;
+pub use multi_file::{GlobMode, GlobToDataFrames, is_glob};
+pub use ods::OdsToDataFrame;
+pub use preview::{Preview, TotalRows};
+pub use sniff::{detect_format, sniff_format, sniff_stdin};
 pub use sqlite::SqliteToDataFrames;
 
 use std::{
     ffi::OsStr,
     fs::File,
+    io::Cursor,
     path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
 };
 
 use polars::{
     frame::DataFrame,
-    io::{SerReader, mmap::MmapBytesReader},
+    io::{SerReader, avro::AvroReader, mmap::MmapBytesReader},
     prelude::{
-        CsvParseOptions, CsvReadOptions, IpcReader, JsonLineReader, JsonReader, ParquetReader,
+        CsvParseOptions, CsvReadOptions, IdxSize, IpcReader, JsonReader, LazyCsvReader,
+        LazyFileListReader, LazyFrame, LazyJsonLineReader, ParquetReader, PlPath, ScanArgsParquet,
     },
 };
 
+use itertools::Itertools;
+
 use crate::{
     AppResult,
-    args::{Args, Format, InferSchema},
-    misc::{globals::stdin, type_ext::ToAscii},
+    args::{Args, Encoding, Format, InferSchema},
+    misc::{
+        globals::{args, stdin},
+        type_ext::ToAscii,
+    },
 };
 
 type NamedFrames = Box<[(String, DataFrame)]>;
@@ -40,11 +66,19 @@ pub enum Source {
 impl Source {
     pub fn table_name(&self) -> String {
         match self {
-            Source::File(path_buf) => path_buf
-                .file_stem()
-                .map(OsStr::to_string_lossy)
-                .unwrap_or("unknown".into())
-                .into_owned(),
+            Source::File(path_buf) => {
+                // `data.csv.gz` is named after `data`, not `data.csv`
+                let path = match path_buf.extension().and_then(OsStr::to_str) {
+                    Some("gz" | "zst" | "zstd") => {
+                        Path::new(path_buf.file_stem().unwrap_or_default())
+                    }
+                    _ => path_buf.as_path(),
+                };
+                path.file_stem()
+                    .map(OsStr::to_string_lossy)
+                    .unwrap_or("unknown".into())
+                    .into_owned()
+            }
             Source::Stdin => String::from("Stdin"),
         }
     }
@@ -53,58 +87,239 @@ impl Source {
 impl From<String> for Source {
     fn from(value: String) -> Self {
         match value.as_str() {
-            "$stdin" => Source::File(value.into()),
-            _ => Source::Stdin,
+            "$stdin" | "-" => Source::Stdin,
+            _ => Source::File(value.into()),
         }
     }
 }
 
 pub trait ReadToDataFrames {
     fn named_frames(&self, input: Source) -> AppResult<NamedFrames>;
+
+    /// Reads the frames of a background import, readers that read in chunks stop at the next
+    /// chunk once the import is cancelled.
+    fn named_frames_with_progress(
+        &self,
+        input: Source,
+        _progress: &ImportProgress,
+    ) -> AppResult<NamedFrames> {
+        self.named_frames(input)
+    }
+}
+
+/// State of a background import shared with the status bar, which can also cancel it.
+#[derive(Debug, Clone)]
+pub struct ImportProgress {
+    cancelled: Arc<AtomicBool>,
+    started: Instant,
+}
+
+impl ImportProgress {
+    pub fn new() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Returns true if both refer to the same import.
+    pub fn same(&self, other: &ImportProgress) -> bool {
+        Arc::ptr_eq(&self.cancelled, &other.cancelled)
+    }
+}
+
+impl Default for ImportProgress {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 pub trait BuildReader {
     fn build_reader(&self, path: impl AsRef<Path>) -> AppResult<Box<dyn ReadToDataFrames>>;
+
+    /// Reader of the piped stdin, its format is taken from `--stdin-format`, `--format` or the
+    /// leading bytes, in that order.
+    fn build_stdin_reader(&self) -> AppResult<Box<dyn ReadToDataFrames>>;
 }
 
 impl BuildReader for Args {
     fn build_reader(&self, path: impl AsRef<Path>) -> AppResult<Box<dyn ReadToDataFrames>> {
-        match self.format {
-            Some(Format::Dsv) | Some(Format::Csv) => Ok(Box::new(CsvToDataFrame::from_args(self))),
-            Some(Format::Tsv) => {
+        let format = self
+            .format
+            .clone()
+            .or_else(|| detect_format(path.as_ref()))
+            .unwrap_or(Format::Csv);
+        self.format_reader(format)
+    }
+
+    fn build_stdin_reader(&self) -> AppResult<Box<dyn ReadToDataFrames>> {
+        let format = self
+            .stdin_format
+            .clone()
+            .or_else(|| self.format.clone())
+            .or_else(sniff_stdin)
+            .unwrap_or(Format::Csv);
+        self.format_reader(format)
+    }
+}
+
+impl Args {
+    fn format_reader(&self, format: Format) -> AppResult<Box<dyn ReadToDataFrames>> {
+        match format {
+            Format::Dsv | Format::Csv => Ok(Box::new(CsvToDataFrame::from_args(self))),
+            Format::Tsv => {
                 let mut reader = CsvToDataFrame::from_args(self);
                 reader.separator_char = '\t';
                 Ok(Box::new(reader))
             }
-            Some(Format::Parquet) => Ok(Box::new(ParquetToDataFrame)),
-            Some(Format::Json) => Ok(Box::new(JsonToDataFrame::from_args(self))),
-            Some(Format::Jsonl) => Ok(Box::new(JsonLineToDataFrame::from_args(self))),
-            Some(Format::Arrow) => Ok(Box::new(ArrowIpcToDataFrame)),
-            Some(Format::Fwf) => Ok(Box::new(FwfToDataFrame::from_args(self))),
-            Some(Format::Sqlite) => Ok(Box::new(SqliteToDataFrames::from_args(self))),
-            Some(Format::Excel) => Ok(Box::new(ExcelToDataFarmes::from_args(self))),
-            Some(Format::Logfmt) => Ok(Box::new(LogfmtToDataFrame::from_args(self))),
-            None => match path.as_ref().extension().and_then(|ext| ext.to_str()) {
-                Some("tsv") => {
-                    let mut reader = CsvToDataFrame::from_args(self);
-                    reader.separator_char = '\t';
-                    Ok(Box::new(reader))
-                }
-                Some("parquet") | Some("pqt") => Ok(Box::new(ParquetToDataFrame)),
-                Some("json") => Ok(Box::new(JsonToDataFrame::from_args(self))),
-                Some("jsonl") => Ok(Box::new(JsonLineToDataFrame::from_args(self))),
-                Some("arrow") => Ok(Box::new(ArrowIpcToDataFrame)),
-                Some("fwf") => Ok(Box::new(FwfToDataFrame::from_args(self))),
-                Some("db") | Some("sqlite") => Ok(Box::new(SqliteToDataFrames::from_args(self))),
-                Some("xls") | Some("xlsx") | Some("xlsm") | Some("xlsb") => {
-                    Ok(Box::new(ExcelToDataFarmes::from_args(self)))
-                }
-                _ => Ok(Box::new(CsvToDataFrame::from_args(self))),
-            },
+            Format::Parquet => Ok(Box::new(ParquetToDataFrame::from_args(self))),
+            Format::Json => Ok(Box::new(JsonToDataFrame::from_args(self))),
+            Format::Jsonl => Ok(Box::new(JsonLineToDataFrame::from_args(self))),
+            Format::Arrow => Ok(Box::new(ArrowIpcToDataFrame::from_args(self))),
+            Format::Avro => Ok(Box::new(AvroToDataFrame)),
+            Format::Fwf => Ok(Box::new(FwfToDataFrame::from_args(self)?)),
+            Format::Sqlite => Ok(Box::new(SqliteToDataFrames::from_args(self))),
+            Format::Excel => Ok(Box::new(ExcelToDataFarmes::from_args(self))),
+            Format::Ods => Ok(Box::new(OdsToDataFrame::from_args(self))),
+            Format::Logfmt => Ok(Box::new(LogfmtToDataFrame::from_args(self))),
         }
     }
 }
 
+/// Reads a file with the reader picked from its extension or leading bytes and the reader
+/// options given on the command line, files of an unknown format are read as comma separated
+/// values.
+pub struct DetectedToDataFrames;
+
+impl ReadToDataFrames for DetectedToDataFrames {
+    fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
+        let format = match &input {
+            Source::File(path) => detect_format(path),
+            Source::Stdin => sniff_stdin(),
+        }
+        .unwrap_or(Format::Csv);
+        args().format_reader(format)?.named_frames(input)
+    }
+
+    fn named_frames_with_progress(
+        &self,
+        input: Source,
+        progress: &ImportProgress,
+    ) -> AppResult<NamedFrames> {
+        let format = match &input {
+            Source::File(path) => detect_format(path),
+            Source::Stdin => sniff_stdin(),
+        }
+        .unwrap_or(Format::Csv);
+        args()
+            .format_reader(format)?
+            .named_frames_with_progress(input, progress)
+    }
+}
+
+/// Rows collected into the tab of a table scanned in lazy mode.
+pub const LAZY_PREVIEW_ROWS: u32 = 10_000;
+
+/// Scans a file into a lazy frame without reading it, returns None if the format cannot be
+/// scanned and has to be read eagerly.
+pub fn scan_path(args: &Args, path: &Path) -> Option<AppResult<LazyFrame>> {
+    scan_format(args, path).map(|scan| {
+        scan.map(|lf| match (args.offset, args.limit) {
+            (0, None) => lf,
+            (offset, limit) => lf.slice(
+                offset as i64,
+                limit.map(|limit| limit as IdxSize).unwrap_or(IdxSize::MAX),
+            ),
+        })
+    })
+}
+
+fn scan_format(args: &Args, path: &Path) -> Option<AppResult<LazyFrame>> {
+    let format = args
+        .format
+        .clone()
+        .or_else(|| detect_format(path))
+        .unwrap_or(Format::Csv);
+    let separator = match format {
+        Format::Dsv | Format::Csv => args.separator,
+        Format::Tsv => '\t',
+        Format::Parquet => {
+            return Some(
+                LazyFrame::scan_parquet(
+                    PlPath::new(&path.to_string_lossy()),
+                    ScanArgsParquet::default(),
+                )
+                .map_err(Into::into),
+            );
+        }
+        Format::Arrow => {
+            return Some(
+                LazyFrame::scan_ipc(
+                    PlPath::new(&path.to_string_lossy()),
+                    Default::default(),
+                    Default::default(),
+                )
+                .map_err(Into::into),
+            );
+        }
+        Format::Jsonl => {
+            return is_plain(path).then(|| {
+                Ok(
+                    LazyJsonLineReader::new(PlPath::new(&path.to_string_lossy()))
+                        .with_ignore_errors(args.ignore_errors)
+                        // Reading the whole file for the schema would defeat the scan
+                        .with_infer_schema_length(InferSchema::Fast.to_json_infer_schema_length())
+                        .finish()?,
+                )
+            });
+        }
+        _ => return None,
+    };
+    // Transcoding needs the whole file in memory as well
+    let plain = is_plain(path) && args.encoding == Encoding::Utf8;
+    // Safe inference reads strings and leaves the types to a pass over the loaded frame, which
+    // never happens for a scan, so the types are inferred from the leading rows instead
+    let infer_schema = match args.infer_schema {
+        InferSchema::Safe => InferSchema::Fast,
+        infer_schema => infer_schema,
+    };
+    plain.then(|| {
+        Ok(LazyCsvReader::new(PlPath::new(&path.to_string_lossy()))
+            .with_ignore_errors(args.ignore_errors)
+            .with_infer_schema_length(infer_schema.to_csv_infer_schema_length())
+            .with_has_header(!args.no_header)
+            .with_truncate_ragged_lines(args.truncate_ragged_lines)
+            .with_quote_char(args.quote_char.to_ascii())
+            .with_separator(
+                separator
+                    .to_ascii()
+                    .ok_or(anyhow!("non-ASCII separator character"))?,
+            )
+            .finish()?)
+    })
+}
+
+/// Compressed text is decompressed into memory by the eager reader anyway, only plain files are
+/// worth scanning.
+fn is_plain(path: &Path) -> bool {
+    File::open(path)
+        .map_err(Into::into)
+        .and_then(|mut file| Compression::detect(&mut file))
+        .is_ok_and(|compression| compression == Compression::Plain)
+}
+
 pub struct CsvToDataFrame {
     infer_schema: InferSchema,
     quote_char: char,
@@ -112,6 +327,10 @@ pub struct CsvToDataFrame {
     no_header: bool,
     ignore_errors: bool,
     truncate_ragged_lines: bool,
+    compression: Option<Compression>,
+    encoding: Encoding,
+    limit: Option<usize>,
+    offset: usize,
 }
 
 impl CsvToDataFrame {
@@ -123,6 +342,10 @@ impl CsvToDataFrame {
             no_header: args.no_header,
             ignore_errors: args.ignore_errors,
             truncate_ragged_lines: args.truncate_ragged_lines,
+            compression: None,
+            encoding: args.encoding,
+            limit: args.limit,
+            offset: args.offset,
         }
     }
 
@@ -141,11 +364,63 @@ impl CsvToDataFrame {
         self
     }
 
-    fn try_into_frame(&self, reader: impl MmapBytesReader) -> AppResult<DataFrame> {
-        let df = CsvReadOptions::default()
+    /// Forces the codec of the input instead of detecting it from the magic bytes.
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Transcodes the input from the encoding to UTF-8 before parsing it.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Parses at most this many rows, the rest of the input is not parsed.
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Skips this many rows after the header.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    fn try_into_frame_decompressed(
+        &self,
+        mut reader: impl MmapBytesReader,
+        progress: &ImportProgress,
+    ) -> AppResult<DataFrame> {
+        let compression = match self.compression {
+            Some(compression) => compression,
+            None => Compression::detect(&mut reader)?,
+        };
+        match (compression, self.encoding) {
+            (Compression::Plain, Encoding::Utf8) => self.try_into_frame(reader, progress),
+            (_, Encoding::Utf8) => {
+                self.try_into_frame(Cursor::new(compression.decompress(reader)?), progress)
+            }
+            (_, encoding) => self.try_into_frame(
+                Cursor::new(decode(compression.decompress(reader)?, encoding)?.into_bytes()),
+                progress,
+            ),
+        }
+    }
+
+    /// Parses the input a few chunks at a time, checking for a cancelled import between them.
+    fn try_into_frame(
+        &self,
+        reader: impl MmapBytesReader,
+        progress: &ImportProgress,
+    ) -> AppResult<DataFrame> {
+        let mut reader = CsvReadOptions::default()
             .with_ignore_errors(self.ignore_errors)
             .with_infer_schema_length(self.infer_schema.to_csv_infer_schema_length())
             .with_has_header(!self.no_header)
+            .with_skip_rows_after_header(self.offset)
+            .with_n_rows(self.limit)
             .with_parse_options(
                 CsvParseOptions::default()
                     .with_truncate_ragged_lines(self.truncate_ragged_lines)
@@ -157,8 +432,28 @@ impl CsvToDataFrame {
                     ),
             )
             .with_rechunk(true)
-            .into_reader_with_file_handle(reader)
-            .finish()?;
+            .into_reader_with_file_handle(reader);
+        let mut batched = reader.batched_borrowed()?;
+        let mut read: Option<DataFrame> = None;
+        while let Some(batches) = batched.next_batches(num_cpus::get())? {
+            if progress.is_cancelled() {
+                Err(anyhow!("Import cancelled"))?
+            }
+            for batch in batches {
+                match read.as_mut() {
+                    Some(df) => {
+                        df.vstack_mut_owned(batch)?;
+                    }
+                    None => read = Some(batch),
+                }
+            }
+        }
+        // An input without rows still has the columns of its header
+        let mut df = match read {
+            Some(df) => df,
+            None => reader.finish()?,
+        };
+        df.as_single_chunk_par();
         Ok(df)
     }
 }
@@ -172,68 +467,97 @@ impl Default for CsvToDataFrame {
             no_header: false,
             ignore_errors: true,
             truncate_ragged_lines: false,
+            compression: None,
+            encoding: Encoding::Utf8,
+            limit: None,
+            offset: 0,
         }
     }
 }
 
 impl ReadToDataFrames for CsvToDataFrame {
     fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
-        let df = match &input {
-            Source::File(path) => self.try_into_frame(File::open(path)?),
-            Source::Stdin => self.try_into_frame(stdin()),
-        }?;
-        Ok([(input.table_name(), df)].into())
+        self.named_frames_with_progress(input, &ImportProgress::new())
     }
-}
-
-pub struct ParquetToDataFrame;
 
-impl ReadToDataFrames for ParquetToDataFrame {
-    fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
+    fn named_frames_with_progress(
+        &self,
+        input: Source,
+        progress: &ImportProgress,
+    ) -> AppResult<NamedFrames> {
         let df = match &input {
-            Source::File(path) => ParquetReader::new(File::open(path)?)
-                .set_rechunk(true)
-                .finish()?,
-
-            Source::Stdin => ParquetReader::new(stdin()).set_rechunk(true).finish()?,
-        };
+            Source::File(path) => self.try_into_frame_decompressed(File::open(path)?, progress),
+            Source::Stdin => self.try_into_frame_decompressed(stdin(), progress),
+        }?;
         Ok([(input.table_name(), df)].into())
     }
 }
 
-pub struct JsonLineToDataFrame {
-    ignore_errors: bool,
+#[derive(Default)]
+pub struct ParquetToDataFrame {
+    limit: Option<usize>,
+    offset: usize,
+    columns: Option<Vec<String>>,
 }
 
-impl JsonLineToDataFrame {
+impl ParquetToDataFrame {
     pub fn from_args(args: &Args) -> Self {
         Self {
-            ignore_errors: args.ignore_errors,
+            limit: args.limit,
+            offset: args.offset,
+            columns: None,
         }
     }
-}
 
-impl Default for JsonLineToDataFrame {
-    fn default() -> Self {
-        Self {
-            ignore_errors: true,
+    /// Reads only these columns, in this order, the others are never decoded. An empty list
+    /// reads every column.
+    pub fn with_columns(mut self, columns: Vec<String>) -> Self {
+        self.columns = (!columns.is_empty()).then_some(columns);
+        self
+    }
+
+    /// Reads at most this many rows, row groups outside of them are skipped.
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    fn slice(&self) -> Option<(usize, usize)> {
+        (self.limit.is_some() || self.offset > 0)
+            .then(|| (self.offset, self.limit.unwrap_or(usize::MAX)))
+    }
+
+    fn read<R: MmapBytesReader>(&self, mut reader: ParquetReader<R>) -> AppResult<DataFrame> {
+        if let Some(columns) = &self.columns {
+            let schema = reader.schema()?;
+            if let Some(column) = columns
+                .iter()
+                .find(|column| !schema.contains(column.as_str()))
+            {
+                Err(anyhow!(
+                    "Unknown column '{column}', available: {}",
+                    schema.iter_names().join(", ")
+                ))?
+            }
         }
+        Ok(reader
+            .with_columns(self.columns.clone())
+            .with_slice(self.slice())
+            .set_rechunk(true)
+            .finish()?)
     }
 }
 
-impl ReadToDataFrames for JsonLineToDataFrame {
+impl ReadToDataFrames for ParquetToDataFrame {
     fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
         let df = match &input {
-            Source::File(path) => JsonLineReader::new(File::open(path)?)
-                .with_rechunk(true)
-                .infer_schema_len(None)
-                .with_ignore_errors(self.ignore_errors)
-                .finish()?,
-            Source::Stdin => JsonLineReader::new(stdin())
-                .with_rechunk(true)
-                .infer_schema_len(None)
-                .with_ignore_errors(self.ignore_errors)
-                .finish()?,
+            Source::File(path) => self.read(ParquetReader::new(File::open(path)?))?,
+            Source::Stdin => self.read(ParquetReader::new(stdin()))?,
         };
         Ok([(input.table_name(), df)].into())
     }
@@ -241,20 +565,37 @@ impl ReadToDataFrames for JsonLineToDataFrame {
 
 pub struct JsonToDataFrame {
     ignore_errors: bool,
+    limit: Option<usize>,
+    offset: usize,
 }
 
 impl JsonToDataFrame {
     pub fn from_args(args: &Args) -> Self {
         Self {
             ignore_errors: args.ignore_errors,
+            limit: args.limit,
+            offset: args.offset,
         }
     }
+
+    /// Keeps at most this many rows, the document is parsed whole anyway.
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
 }
 
 impl Default for JsonToDataFrame {
     fn default() -> Self {
         Self {
             ignore_errors: true,
+            limit: None,
+            offset: 0,
         }
     }
 }
@@ -273,20 +614,136 @@ impl ReadToDataFrames for JsonToDataFrame {
                 .with_ignore_errors(self.ignore_errors)
                 .finish()?,
         };
+        Ok([(input.table_name(), slice(df, self.offset, self.limit))].into())
+    }
+}
+
+/// Reads Avro object container files, timestamps become datetimes and decimals keep their
+/// precision and scale.
+pub struct AvroToDataFrame;
+
+impl ReadToDataFrames for AvroToDataFrame {
+    fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
+        let mut df = match &input {
+            Source::File(path) => AvroReader::new(File::open(path)?).finish()?,
+            Source::Stdin => AvroReader::new(stdin()).finish()?,
+        };
+        df.as_single_chunk_par();
         Ok([(input.table_name(), df)].into())
     }
 }
 
-pub struct ArrowIpcToDataFrame;
+#[derive(Default)]
+pub struct ArrowIpcToDataFrame {
+    limit: Option<usize>,
+    offset: usize,
+}
+
+impl ArrowIpcToDataFrame {
+    pub fn from_args(args: &Args) -> Self {
+        Self {
+            limit: args.limit,
+            offset: args.offset,
+        }
+    }
+
+    /// Reads the batches up to the offset plus this many rows.
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
 
 impl ReadToDataFrames for ArrowIpcToDataFrame {
     fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
+        let n_rows = self.limit.map(|limit| limit.saturating_add(self.offset));
         let df = match &input {
             Source::File(path) => IpcReader::new(File::open(path)?)
+                .with_n_rows(n_rows)
+                .set_rechunk(true)
+                .finish()?,
+            Source::Stdin => IpcReader::new(stdin())
+                .with_n_rows(n_rows)
                 .set_rechunk(true)
                 .finish()?,
-            Source::Stdin => IpcReader::new(stdin()).set_rechunk(true).finish()?,
         };
-        Ok([(input.table_name(), df)].into())
+        Ok([(input.table_name(), slice(df, self.offset, self.limit))].into())
+    }
+}
+
+/// Window of the rows from the offset, up to the limit if there is one.
+fn slice(df: DataFrame, offset: usize, limit: Option<usize>) -> DataFrame {
+    match (offset, limit) {
+        (0, None) => df,
+        (offset, limit) => df.slice(offset.min(df.height()) as i64, limit.unwrap_or(usize::MAX)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::{df, prelude::ParquetWriter};
+
+    use super::*;
+
+    #[test]
+    fn test_table_name() {
+        let name = |path: &str| Source::File(path.into()).table_name();
+        assert_eq!(name("dir/data.csv"), "data");
+        assert_eq!(name("data.csv.gz"), "data");
+        assert_eq!(name("data.jsonl.zst"), "data");
+        assert_eq!(name("archive.gz"), "archive");
+    }
+
+    #[test]
+    fn test_csv_progress() {
+        let read = |text: &str, progress: &ImportProgress| {
+            CsvToDataFrame::default()
+                .try_into_frame(Cursor::new(text.as_bytes().to_vec()), progress)
+                .map(|df| (df.get_column_names_str().join(","), df.height()))
+        };
+        let progress = ImportProgress::new();
+        assert_eq!(
+            read("a,b\n1,\"x\ny\"\n2,z\n", &progress).unwrap(),
+            ("a,b".to_owned(), 2)
+        );
+        // The header alone still names the columns
+        assert_eq!(read("a,b\n", &progress).unwrap(), ("a,b".to_owned(), 0));
+        progress.cancel();
+        assert!(read("a,b\n1,2\n", &progress).is_err());
+    }
+
+    #[test]
+    fn test_parquet_columns() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.parquet");
+        let mut df = df!(
+            "id" => [1, 2],
+            "name" => ["a", "b"],
+            "amount" => [1.5, 2.5],
+        )
+        .unwrap();
+        ParquetWriter::new(File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+        let read = |columns: &[&str]| {
+            ParquetToDataFrame::default()
+                .with_columns(columns.iter().map(|column| column.to_string()).collect())
+                .named_frames(Source::File(path.clone()))
+                .map(|frames| frames[0].1.clone())
+        };
+        assert_eq!(
+            read(&["amount", "id"]).unwrap().get_column_names(),
+            ["amount", "id"]
+        );
+        assert_eq!(read(&[]).unwrap().width(), 3);
+        assert_eq!(
+            read(&["id", "price"]).unwrap_err().to_string(),
+            "Unknown column 'price', available: id, name, amount"
+        );
     }
 }
@@ -0,0 +1,149 @@
+use std::{fmt::Display, path::PathBuf};
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use polars::{frame::DataFrame, functions::concat_df_diagonal};
+use strum_macros::EnumIter;
+
+use crate::AppResult;
+
+use super::{ImportProgress, NamedFrames, ReadToDataFrames, Source};
+
+/// How the files matching a glob pattern end up as tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum GlobMode {
+    Separate,
+    Union,
+    UnionRelaxed,
+}
+
+impl Display for GlobMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GlobMode::Separate => write!(f, "One table per file"),
+            GlobMode::Union => write!(f, "Union, schemas have to match"),
+            GlobMode::UnionRelaxed => write!(f, "Union, missing columns are null"),
+        }
+    }
+}
+
+/// Returns true if the path has a wildcard and is read by expanding it.
+pub fn is_glob(path: &std::path::Path) -> bool {
+    path.to_string_lossy().contains(['*', '?', '['])
+}
+
+/// Reads every file matching a glob pattern with the inner reader, paths without a wildcard and
+/// stdin are handed to the inner reader as they are.
+pub struct GlobToDataFrames<R> {
+    reader: R,
+    mode: GlobMode,
+}
+
+impl<R> GlobToDataFrames<R> {
+    pub fn new(reader: R, mode: GlobMode) -> Self {
+        Self { reader, mode }
+    }
+}
+
+impl<R: ReadToDataFrames> ReadToDataFrames for GlobToDataFrames<R> {
+    fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
+        self.named_frames_with_progress(input, &ImportProgress::new())
+    }
+
+    /// A cancelled import stops before the next matching file.
+    fn named_frames_with_progress(
+        &self,
+        input: Source,
+        progress: &ImportProgress,
+    ) -> AppResult<NamedFrames> {
+        let pattern = match &input {
+            Source::File(path) if is_glob(path) => path.to_string_lossy().into_owned(),
+            _ => return self.reader.named_frames_with_progress(input, progress),
+        };
+        let paths = glob::glob(&pattern)?
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|path| path.is_file())
+            .sorted()
+            .collect_vec();
+        if paths.is_empty() {
+            Err(anyhow!("No files match '{pattern}'"))?
+        }
+        let mut frames = Vec::new();
+        for path in paths {
+            if progress.is_cancelled() {
+                Err(anyhow!("Import cancelled"))?
+            }
+            for (name, df) in self
+                .reader
+                .named_frames_with_progress(Source::File(path.clone()), progress)?
+            {
+                frames.push((path.clone(), name, df));
+            }
+        }
+        match self.mode {
+            GlobMode::Separate => Ok(frames.into_iter().map(|(_, name, df)| (name, df)).collect()),
+            GlobMode::Union => Ok([(input.table_name(), union(frames)?)].into()),
+            GlobMode::UnionRelaxed => {
+                let dfs = frames.into_iter().map(|(_, _, df)| df).collect_vec();
+                let mut df = concat_df_diagonal(&dfs)?;
+                df.as_single_chunk_par();
+                Ok([(input.table_name(), df)].into())
+            }
+        }
+    }
+}
+
+/// Stacks the frames vertically, every frame has to have the schema of the first one.
+fn union(frames: Vec<(PathBuf, String, DataFrame)>) -> AppResult<DataFrame> {
+    let mut frames = frames.into_iter();
+    let (first_path, _, mut df) = frames.next().ok_or(anyhow!("Nothing to union"))?;
+    for (path, _, other) in frames {
+        if other.schema() != df.schema() {
+            Err(anyhow!(
+                "Schema of {} does not match the schema of {}",
+                path.display(),
+                first_path.display()
+            ))?
+        }
+        df.vstack_mut_owned(other)?;
+    }
+    df.as_single_chunk_par();
+    Ok(df)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::reader::CsvToDataFrame;
+
+    #[test]
+    fn test_glob_union() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("sales_1.csv"), "a,b\n1,x\n").unwrap();
+        fs::write(dir.path().join("sales_2.csv"), "a,b\n2,y\n").unwrap();
+        fs::write(dir.path().join("other.csv"), "a\n3\n").unwrap();
+        let read = |pattern: &str, mode| {
+            GlobToDataFrames::new(CsvToDataFrame::default(), mode)
+                .named_frames(Source::File(dir.path().join(pattern)))
+        };
+
+        let separate = read("sales_*.csv", GlobMode::Separate).unwrap();
+        assert_eq!(
+            separate.iter().map(|(name, _)| name.as_str()).collect_vec(),
+            ["sales_1", "sales_2"]
+        );
+        let union = read("sales_*.csv", GlobMode::Union).unwrap();
+        assert_eq!(union[0].1.shape(), (2, 2));
+
+        let err = read("*.csv", GlobMode::Union).unwrap_err().to_string();
+        assert!(err.contains("sales_1.csv"), "{err}");
+        let relaxed = read("*.csv", GlobMode::UnionRelaxed).unwrap();
+        assert_eq!(relaxed[0].1.shape(), (3, 2));
+        assert_eq!(relaxed[0].1.column("b").unwrap().null_count(), 1);
+
+        assert!(read("missing_*.csv", GlobMode::Separate).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use calamine::{Ods, open_workbook_from_rs};
+
+use crate::{AppResult, args::Args};
+
+use super::{
+    NamedFrames, ReadToDataFrames, Source,
+    excel::{read_input, sheet_frames},
+};
+
+/// Reads every non-empty sheet of an OpenDocument spreadsheet, named and detected like the
+/// sheets of an Excel workbook.
+#[derive(Debug, Default)]
+pub struct OdsToDataFrame {
+    has_header: Option<bool>,
+}
+
+impl OdsToDataFrame {
+    pub fn from_args(args: &Args) -> Self {
+        Self {
+            has_header: args.no_header.then_some(false),
+        }
+    }
+
+    /// Whether the first row of each sheet holds the column names, detected per sheet if `None`.
+    pub fn with_has_header(mut self, has_header: impl Into<Option<bool>>) -> Self {
+        self.has_header = has_header.into();
+        self
+    }
+}
+
+impl ReadToDataFrames for OdsToDataFrame {
+    fn named_frames(&self, input: Source) -> AppResult<NamedFrames> {
+        let workbook: Ods<_> = open_workbook_from_rs(read_input(input)?)?;
+        Ok(sheet_frames(workbook, self.has_header))
+    }
+}
@@ -0,0 +1,141 @@
+use std::{fmt::Display, fs::File, io::Read, path::Path};
+
+use polars::{io::SerReader, prelude::ParquetReader};
+
+use crate::args::{Args, Format};
+
+use super::{detect_format, is_plain};
+
+/// Bytes of a text file sampled for the average line length.
+const SAMPLE_BYTES: u64 = 1 << 20;
+
+/// Row count of the whole file a preview was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TotalRows {
+    Exact(usize),
+    About(usize),
+}
+
+impl Display for TotalRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TotalRows::Exact(rows) => write!(f, "{rows}"),
+            TotalRows::About(rows) => write!(f, "~{rows}"),
+        }
+    }
+}
+
+/// Window of rows that was loaded from a file read with a limit or an offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Preview {
+    offset: usize,
+    rows: usize,
+    total: Option<TotalRows>,
+}
+
+impl Preview {
+    pub fn new(offset: usize, rows: usize, total: Option<TotalRows>) -> Self {
+        Self {
+            offset,
+            rows,
+            total,
+        }
+    }
+
+    /// Preview of the rows read from the path, None if neither a limit nor an offset was given.
+    pub fn from_args(args: &Args, path: &Path, rows: usize) -> Option<Self> {
+        (args.limit.is_some() || args.offset > 0)
+            .then(|| Self::new(args.offset, rows, total_rows(args, path)))
+    }
+}
+
+impl Display for Preview {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rows {}..{}", self.offset, self.offset + self.rows)?;
+        if let Some(total) = self.total {
+            write!(f, " of {total}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Row count of Parquet files from their metadata, text files are estimated from the length of
+/// their leading lines.
+fn total_rows(args: &Args, path: &Path) -> Option<TotalRows> {
+    let format = args
+        .format
+        .clone()
+        .or_else(|| detect_format(path))
+        .unwrap_or(Format::Csv);
+    match format {
+        Format::Parquet => ParquetReader::new(File::open(path).ok()?)
+            .num_rows()
+            .ok()
+            .map(TotalRows::Exact),
+        Format::Dsv | Format::Csv | Format::Tsv if is_plain(path) => {
+            let header = usize::from(!args.no_header);
+            estimate_lines(path).map(|total| match total {
+                TotalRows::Exact(lines) => TotalRows::Exact(lines.saturating_sub(header)),
+                TotalRows::About(lines) => TotalRows::About(lines.saturating_sub(header)),
+            })
+        }
+        Format::Jsonl if is_plain(path) => estimate_lines(path),
+        _ => None,
+    }
+}
+
+/// Counts the lines of a small file, larger files are extrapolated from their first megabyte.
+fn estimate_lines(path: &Path) -> Option<TotalRows> {
+    let len = path.metadata().ok()?.len();
+    let mut sample = Vec::new();
+    File::open(path)
+        .ok()?
+        .take(SAMPLE_BYTES)
+        .read_to_end(&mut sample)
+        .ok()?;
+    let lines = sample.iter().filter(|byte| **byte == b'\n').count();
+    if sample.len() as u64 >= len {
+        let unterminated = sample.last().is_some_and(|byte| *byte != b'\n');
+        Some(TotalRows::Exact(lines + unterminated as usize))
+    } else if lines == 0 {
+        None
+    } else {
+        Some(TotalRows::About(
+            (len as f64 * lines as f64 / sample.len() as f64).round() as usize,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use clap::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_preview() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        fs::write(&path, "a\n1\n2\n3").unwrap();
+        let args = |extra: &[&str]| Args::parse_from(["tw"].iter().chain(extra));
+        assert_eq!(Preview::from_args(&args(&[]), &path, 3), None);
+        assert_eq!(
+            Preview::from_args(&args(&["--limit", "2", "--offset", "1"]), &path, 2)
+                .unwrap()
+                .to_string(),
+            "rows 1..3 of 3"
+        );
+        assert_eq!(
+            Preview::from_args(&args(&["--limit", "2", "--no-header"]), &path, 2)
+                .unwrap()
+                .to_string(),
+            "rows 0..2 of 4"
+        );
+        assert_eq!(
+            Preview::new(10, 5, Some(TotalRows::About(1000))).to_string(),
+            "rows 10..15 of ~1000"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use std::{fs::File, io::Read, path::Path};
+
+use crate::{args::Format, misc::globals::stdin};
+
+/// Number of leading bytes looked at, enough to tell a JSON document from JSON lines.
+const SNIFF_LEN: u64 = 4096;
+
+/// Name and content of the first entry of an OpenDocument spreadsheet archive.
+const ODS_MIMETYPE: &[u8] = b"mimetypeapplication/vnd.oasis.opendocument.spreadsheet";
+
+/// Format of the file by its extension, or by its leading bytes if the extension is missing or
+/// unknown. None if neither gives it away.
+pub fn detect_format(path: &Path) -> Option<Format> {
+    Format::from_extension(path).or_else(|| sniff_format(path))
+}
+
+/// Format of the file by its leading bytes, None if the file can not be read or nothing matches.
+pub fn sniff_format(path: &Path) -> Option<Format> {
+    let mut head = Vec::new();
+    File::open(path)
+        .ok()?
+        .take(SNIFF_LEN)
+        .read_to_end(&mut head)
+        .ok()?;
+    sniff(&head)
+}
+
+/// Format of the piped stdin by its leading bytes, None if nothing matches.
+pub fn sniff_stdin() -> Option<Format> {
+    let content = stdin().into_inner();
+    sniff(&content[..content.len().min(SNIFF_LEN as usize)])
+}
+
+fn sniff(head: &[u8]) -> Option<Format> {
+    // OpenDocument files are zip archives starting with an uncompressed mimetype entry
+    if head.starts_with(b"PK\x03\x04")
+        && head
+            .get(30..)
+            .is_some_and(|entry| entry.starts_with(ODS_MIMETYPE))
+    {
+        return Some(Format::Ods);
+    }
+    const MAGIC: [(&[u8], Format); 6] = [
+        (b"PAR1", Format::Parquet),
+        (b"ARROW1", Format::Arrow),
+        (b"Obj\x01", Format::Avro),
+        (b"SQLite format 3\0", Format::Sqlite),
+        (b"PK\x03\x04", Format::Excel),
+        (b"\xd0\xcf\x11\xe0\xa1\xb1\x1a\xe1", Format::Excel),
+    ];
+    if let Some((_, format)) = MAGIC.iter().find(|(magic, _)| head.starts_with(magic)) {
+        return Some(format.clone());
+    }
+    let text = head
+        .strip_prefix(b"\xef\xbb\xbf")
+        .unwrap_or(head)
+        .trim_ascii_start();
+    match text.first()? {
+        b'[' => Some(Format::Json),
+        // One complete object on the first line followed by another line is read as JSON lines
+        b'{' => {
+            let mut lines = text
+                .split(|byte| *byte == b'\n')
+                .map(<[u8]>::trim_ascii)
+                .filter(|line| !line.is_empty());
+            let first = lines.next()?;
+            if first.ends_with(b"}") && lines.next().is_some_and(|line| line.starts_with(b"{")) {
+                Some(Format::Jsonl)
+            } else {
+                Some(Format::Json)
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff() {
+        let sniffed = |head: &[u8]| sniff(head).map(|format| format!("{format:?}"));
+        assert_eq!(sniffed(b"PAR1\x15\x04"), Some("Parquet".to_owned()));
+        assert_eq!(sniffed(b"ARROW1\0\0"), Some("Arrow".to_owned()));
+        assert_eq!(sniffed(b"SQLite format 3\0\x10"), Some("Sqlite".to_owned()));
+        assert_eq!(
+            sniffed(&[b"PK\x03\x04".as_slice(), &[0; 26], ODS_MIMETYPE].concat()),
+            Some("Ods".to_owned())
+        );
+        assert_eq!(sniffed(b"PK\x03\x04\x14\0"), Some("Excel".to_owned()));
+        assert_eq!(sniffed(b"\n  [{\"a\": 1}]"), Some("Json".to_owned()));
+        assert_eq!(sniffed(b"{\"a\": 1,\n \"b\": 2}"), Some("Json".to_owned()));
+        assert_eq!(
+            sniffed(b"\xef\xbb\xbf{\"a\": 1}\n{\"a\": 2}\n"),
+            Some("Jsonl".to_owned())
+        );
+        assert_eq!(sniffed(b"a,b\n1,2\n"), None);
+        assert_eq!(sniffed(b""), None);
+    }
+}
@@ -0,0 +1,142 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use itertools::Itertools;
+use polars::{frame::DataFrame, prelude::ChunkCompareEq, series::Series};
+use ratatui::{
+    layout::Rect,
+    text::Line,
+    widgets::{Clear, List, ListItem, ListState, StatefulWidget, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    AppResult,
+    handler::message::Message,
+    misc::{
+        config::theme,
+        polars_ext::{AnyValueExt, DataFrameExt},
+        type_ext::UnwrapOrEnqueueError,
+    },
+    tui::{component::Component, pane::TableDescription, widgets::block::Block},
+};
+
+/// Distinct values of a column sorted by frequency, selecting one filters the table to its rows.
+#[derive(Debug)]
+pub struct ColumnIndex {
+    df: DataFrame,
+    column: String,
+    values: Series,
+    items: Vec<(String, String)>,
+    list: ListState,
+}
+
+impl ColumnIndex {
+    pub fn new(df: DataFrame, column: impl Into<String>) -> AppResult<Self> {
+        let column = column.into();
+        let counts = df.value_counts(&column)?;
+        let values = counts[0].as_materialized_series().clone();
+        let items = values
+            .iter()
+            .map(AnyValueExt::into_single_line)
+            .zip(
+                counts[1]
+                    .as_materialized_series()
+                    .iter()
+                    .map(AnyValueExt::into_single_line),
+            )
+            .collect_vec();
+        Ok(Self {
+            df,
+            column,
+            values,
+            items,
+            list: ListState::default().with_selected(Some(0)),
+        })
+    }
+
+    /// Preferred width of the index including its borders.
+    pub fn width(&self) -> u16 {
+        let count_width = self.items.iter().map(|(_, c)| c.width()).max().unwrap_or(0);
+        let value_width = self
+            .items
+            .iter()
+            .map(|(v, _)| v.width())
+            .max()
+            .unwrap_or(0)
+            .max(self.column.width());
+        (value_width + count_width + 4) as u16
+    }
+
+    fn filter(&self) -> AppResult<()> {
+        if let Some(idx) = self.list.selected() {
+            let value = self.values.slice(idx as i64, 1);
+            let column = self.df.column(&self.column)?.as_materialized_series();
+            let mask = if value.null_count() > 0 {
+                column.is_null()
+            } else {
+                column.equal_missing(&value)?
+            };
+            Message::PanePushDataFrame(
+                self.df.filter(&mask)?,
+                TableDescription::Filter(format!("{} = {}", self.column, self.items[idx].0)),
+            )
+            .enqueue();
+        }
+        Ok(())
+    }
+}
+
+impl Component for ColumnIndex {
+    fn render(
+        &mut self,
+        area: Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        _focus_state: crate::tui::component::FocusState,
+    ) {
+        Clear.render(area, buf);
+        let inner_width = area.width.saturating_sub(2) as usize;
+        StatefulWidget::render(
+            List::default()
+                .style(theme().text())
+                .highlight_style(theme().row_highlighted())
+                .items(self.items.iter().map(|(value, count)| {
+                    let pad = inner_width.saturating_sub(value.width() + count.width());
+                    ListItem::new(Line::from(format!("{value}{}{count}", " ".repeat(pad))))
+                }))
+                .block(Block::default().title(self.column.as_str()).into_widget()),
+            area,
+            buf,
+            &mut self.list,
+        );
+    }
+
+    fn handle(&mut self, event: KeyEvent) -> bool {
+        match (event.code, event.modifiers) {
+            (KeyCode::Up, KeyModifiers::NONE) | (KeyCode::Char('k'), KeyModifiers::NONE) => {
+                self.list.select_previous();
+                true
+            }
+            (KeyCode::Down, KeyModifiers::NONE) | (KeyCode::Char('j'), KeyModifiers::NONE) => {
+                self.list.select_next();
+                true
+            }
+            (KeyCode::Home, KeyModifiers::NONE) | (KeyCode::Char('g'), KeyModifiers::NONE) => {
+                self.list.select_first();
+                true
+            }
+            (KeyCode::End, KeyModifiers::NONE) | (KeyCode::Char('G'), KeyModifiers::SHIFT) => {
+                self.list.select_last();
+                true
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                self.filter().unwrap_or_enqueue_error();
+                Message::PaneDismissModal.enqueue();
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                Message::PaneDismissModal.enqueue();
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use itertools::Itertools;
+use polars::frame::DataFrame;
+use ratatui::{
+    layout::{Constraint, Layout},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    handler::message::Message,
+    misc::{config::theme, globals::sql, sql::contains_predicate},
+    tui::{component::Component, widgets::block::Block},
+};
+
+use super::widgets::input::Input;
+
+/// Time the filters have to stay unchanged before they are applied, so typing a word runs a
+/// single query.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Substring filter per column, the rows matching all of them are kept while typing.
+#[derive(Debug)]
+pub struct FilterRow {
+    columns: Vec<String>,
+    inputs: Vec<Input>,
+    active: usize,
+    rollback_df: DataFrame,
+    changed_at: Option<Instant>,
+    error: Option<String>,
+}
+
+impl FilterRow {
+    pub fn new(dataframe: DataFrame, active: usize) -> Self {
+        let columns = dataframe
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect_vec();
+        Self {
+            inputs: columns.iter().map(|_| Input::default()).collect(),
+            active: active.min(columns.len().saturating_sub(1)),
+            columns,
+            rollback_df: dataframe,
+            changed_at: None,
+            error: None,
+        }
+    }
+
+    /// Combined predicate of the filled in filters, None if every filter is empty.
+    pub fn predicate(&self) -> Option<String> {
+        contains_predicate(
+            self.columns
+                .iter()
+                .map(String::as_str)
+                .zip(self.inputs.iter().map(Input::value)),
+        )
+    }
+
+    /// Filtered data frame once the filters stayed unchanged for the debounce period.
+    pub fn poll(&mut self, now: Instant) -> Option<DataFrame> {
+        match self.changed_at {
+            Some(changed_at) if now.saturating_duration_since(changed_at) >= DEBOUNCE => {
+                self.flush()
+            }
+            _ => None,
+        }
+    }
+
+    /// Filtered data frame of pending changes without waiting for the debounce period, the full
+    /// data frame once every filter is cleared.
+    pub fn flush(&mut self) -> Option<DataFrame> {
+        self.changed_at.take()?;
+        let Some(predicate) = self.predicate() else {
+            self.error = None;
+            return Some(self.rollback_df.clone());
+        };
+        match sql().execute_timed(
+            &format!("SELECT * FROM _ WHERE {predicate}"),
+            self.rollback_df.clone(),
+        ) {
+            Ok(df) => {
+                self.error = None;
+                Some(df)
+            }
+            Err(err) => {
+                self.error = Some(
+                    err.to_string()
+                        .lines()
+                        .next()
+                        .unwrap_or_default()
+                        .to_owned(),
+                );
+                None
+            }
+        }
+    }
+
+    fn select_next(&mut self) {
+        self.active = (self.active + 1) % self.columns.len().max(1);
+    }
+
+    fn select_previous(&mut self) {
+        self.active = self
+            .active
+            .checked_sub(1)
+            .unwrap_or(self.columns.len().saturating_sub(1));
+    }
+}
+
+impl Component for FilterRow {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: super::component::FocusState,
+    ) {
+        let others = self
+            .columns
+            .iter()
+            .zip(self.inputs.iter())
+            .enumerate()
+            .filter(|(idx, (_, input))| *idx != self.active && !input.value().is_empty())
+            .map(|(_, (column, input))| format!("{column}: {}", input.value()))
+            .join(", ");
+        let area = {
+            let mut block = Block::default().title("Filter Row");
+            block = match self.error.as_deref() {
+                Some(error) => block.bottom(Line::styled(format!(" {error} "), theme().error())),
+                None if !others.is_empty() => {
+                    block.bottom(Line::styled(format!(" {others} "), theme().subtext()))
+                }
+                None => block.bottom(Line::styled(" Tab / Shift+Tab: Column ", theme().subtext())),
+            };
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        };
+        let Some(column) = self.columns.get(self.active) else {
+            return;
+        };
+        let label = format!("{column} ");
+        let [label_area, input_area] = Layout::horizontal([
+            Constraint::Length(label.width() as u16),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+        Span::styled(label, theme().header(self.active)).render(label_area, buf);
+        self.inputs[self.active].render(input_area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: KeyEvent) -> bool {
+        if let Some(input) = self.inputs.get_mut(self.active)
+            && input.handle(event)
+        {
+            self.changed_at = Some(Instant::now());
+            return true;
+        }
+        match (event.code, event.modifiers) {
+            (KeyCode::Tab, KeyModifiers::NONE) => {
+                self.select_next();
+                true
+            }
+            (KeyCode::BackTab, _) => {
+                self.select_previous();
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE) => {
+                Message::PaneDismissModal.enqueue();
+                Message::PanePopDataFrame.enqueue();
+                true
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                Message::PaneToggleFilterRow.enqueue();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::df;
+
+    use super::*;
+
+    #[test]
+    fn test_filter_row() {
+        let df = df!(
+            "name" => ["Ann", "Bob", "Anna"],
+            "city" => ["Oslo", "Rome", "Rome"],
+        )
+        .unwrap();
+        let mut filter_row = FilterRow::new(df, 0);
+        let start = Instant::now();
+        assert!(filter_row.poll(start + DEBOUNCE).is_none());
+
+        for c in "an".chars() {
+            filter_row.handle(KeyEvent::from(KeyCode::Char(c)));
+        }
+        filter_row.handle(KeyEvent::from(KeyCode::Tab));
+        for c in "ROME".chars() {
+            filter_row.handle(KeyEvent::from(KeyCode::Char(c)));
+        }
+        assert!(filter_row.poll(start).is_none());
+        let filtered = filter_row.flush().unwrap();
+        assert_eq!(filtered.height(), 1);
+        assert_eq!(
+            filtered.column("name").unwrap().get(0).unwrap().str_value(),
+            "Anna"
+        );
+        assert!(filter_row.flush().is_none());
+
+        for _ in 0..4 {
+            filter_row.handle(KeyEvent::from(KeyCode::Backspace));
+        }
+        filter_row.handle(KeyEvent::from(KeyCode::BackTab));
+        filter_row.handle(KeyEvent::from(KeyCode::Backspace));
+        filter_row.handle(KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(filter_row.predicate(), None);
+        assert_eq!(filter_row.flush().unwrap().height(), 3);
+    }
+}
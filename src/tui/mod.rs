@@ -1,5 +1,7 @@
+pub mod column_index;
 pub mod component;
 pub mod error_popup;
+pub mod filter_row;
 pub mod pane;
 pub mod pickers;
 pub mod plots;
@@ -13,6 +15,7 @@ pub mod table;
 pub mod tabs;
 pub mod terminal;
 pub mod themes;
+pub mod toast;
 mod utils;
 pub mod widgets;
 
@@ -1,49 +1,149 @@
-use crossterm::event::{KeyCode, KeyModifiers};
+use std::{
+    collections::BTreeSet,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Instant,
+};
+
+use anyhow::anyhow;
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEventKind};
 
-use polars::frame::DataFrame;
+use itertools::Itertools;
+use polars::{
+    frame::{DataFrame, UniqueKeepStrategy},
+    prelude::{Engine, IntoLazy},
+};
 use rand::Rng;
 use ratatui::layout::{Constraint, Layout, Margin, Rect};
 
-use super::{search_bar::SearchBar, sheet::Sheet};
+use super::{
+    column_index::ColumnIndex, filter_row::FilterRow, search_bar::SearchBar, sheet::Sheet,
+};
 use crate::{
     AppResult,
     handler::message::Message,
     misc::{
-        config::config, globals::sql, non_empty_stack::NonEmptyStack, polars_ext::DataFrameExt,
-        sql::Source, type_ext::UnwrapOrEnqueueError,
+        cell_format::{CellFormat, Notation},
+        config::config,
+        expr::parse_computed_column,
+        file_watcher::FileWatcher,
+        globals::{args, sql},
+        non_empty_stack::NonEmptyStack,
+        osc52::CopyToClipboardOsc52,
+        polars_ext::{Aggregation, AnyValueExt, DataFrameExt, RenameSpec, SampleSpec},
+        sql::{Source, TableInfo, equals_predicate, select_list},
+        type_ext::{UnwrapOrEnqueueError, human_readable_size},
+        type_inferer::TypeInferer,
     },
+    reader::{self, BuildReader, LAZY_PREVIEW_ROWS, Preview, ReadToDataFrames},
     tui::{
         component::{Component, FocusState},
-        plots::{histogram_plot::HistogramPlot, scatter_plot::ScatterPlot},
+        plots::{
+            bar_plot::BarPlot, box_plot::BoxPlot, corr_matrix::CorrMatrix,
+            density_plot::DensityPlot, histogram_plot::HistogramPlot, line_plot::LinePlot,
+            null_map::NullMap, scatter_plot::ScatterPlot,
+        },
         popups::{
-            column_caster_wizard::ColumnCastWizard,
+            box_plot_wizard::{self, BoxPlotWizard},
+            cell_editor::CellEditor,
+            column_caster_wizard::{ColumnCastWizard, TargetType, cast_column},
+            column_index_wizard::{self, ColumnIndexWizard},
+            column_list::ColumnList,
+            column_stats::ColumnStats,
+            confirm_picker::ConfirmPicker,
             data_frame_info::DataFrameInfo,
-            export_wizard::ExportWizard,
+            density_wizard::{self, DensityWizard},
+            export_wizard::{self, ExportWizard},
+            go_to_column::GoToColumn,
             go_to_line::GoToLine,
+            head_tail_picker::{HeadTailPicker, Slice},
             histogram_wizard::{self, HistogramWizard},
             inline_query_picker::{InlineQueryPicker, QueryType},
+            max_width_picker::MaxWidthPicker,
+            notation_wizard::{self, NotationWizard},
+            pin_picker::PinPicker,
+            pivot_wizard::{self, PivotWizard},
+            precision_picker::PrecisionPicker,
+            rename_picker::RenamePicker,
+            sample_picker::SamplePicker,
             scatter_plot_wizard::{self, ScatterPlotWizard},
             table_registerer::TableRegisterer,
+            time_format_picker::TimeFormatPicker,
+            where_picker::WherePicker,
             wizard::Wizard,
         },
         table::Table,
+        themes::theme::{LoadedTheme, Theme},
     },
+    writer::{TextFormat, text_writer},
 };
 
+/// Size above which some terminals drop or cut OSC52 clipboard copies.
+const OSC52_WARN_BYTES: usize = 100_000;
+
 #[derive(Debug)]
 pub struct Pane {
+    id: PaneId,
     tstack: NonEmptyStack<Table>,
     dstack: NonEmptyStack<TableDescription>,
     modal: Option<Modal>,
+    format: CellFormat,
+    pinned: usize,
+    hidden: BTreeSet<String>,
+    zebra: Option<bool>,
+    fit_columns: bool,
+    wrap: bool,
+    /// Numeric column whose cells are shaded from its min to its max.
+    shade: Option<String>,
+    /// Theme of this tab instead of the global one.
+    theme: Option<LoadedTheme>,
+    scope: Option<Scope>,
+    name: Option<String>,
+    watcher: Option<FileWatcher>,
+}
+
+/// Identifies a pane, results of queries running in the background go back to the pane that
+/// started them even if another one has the focus by then.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneId(usize);
+
+impl PaneId {
+    fn next() -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Base predicate of a pane alongside the data frame it was applied to.
+#[derive(Debug)]
+struct Scope {
+    predicate: String,
+    unscoped: DataFrame,
 }
 
 impl Pane {
     /// Constructs a new instance of [`App`].
     pub fn new(data_frame: DataFrame, description: TableDescription) -> Self {
         Self {
+            id: PaneId::next(),
             tstack: NonEmptyStack::new(table(data_frame)),
             dstack: NonEmptyStack::new(description),
             modal: None,
+            format: CellFormat::default(),
+            pinned: 0,
+            hidden: BTreeSet::new(),
+            zebra: None,
+            fit_columns: false,
+            wrap: false,
+            shade: None,
+            theme: None,
+            scope: None,
+            name: None,
+            watcher: None,
         }
     }
 
@@ -51,14 +151,48 @@ impl Pane {
         self.tstack.last()
     }
 
+    /// Table the pane was opened with, before any query, filter or selection.
+    pub fn base_table(&self) -> &Table {
+        self.tstack.base()
+    }
+
     pub fn description(&self) -> &TableDescription {
         self.dstack.last()
     }
 
+    pub fn base_description(&self) -> &TableDescription {
+        self.dstack.base()
+    }
+
     pub fn iter_descriptions(&self) -> impl Iterator<Item = &TableDescription> {
         self.dstack.iter()
     }
 
+    /// Moves the table selection by one row while the sheet is open and shows the new record.
+    fn move_sheet_row(&mut self, forward: bool) {
+        if let Some(Modal::Sheet(sheet)) = self.modal.as_ref() {
+            let row = if forward {
+                sheet.row().saturating_add(1)
+            } else {
+                sheet.row().saturating_sub(1)
+            };
+            self.set_sheet_row(row);
+        }
+    }
+
+    /// Selects the row, clamped to the table, while the sheet is open and shows its record.
+    fn set_sheet_row(&mut self, row: usize) {
+        if let Some(Modal::Sheet(sheet)) = self.modal.as_mut() {
+            let table = self.tstack.last_mut();
+            table.select(row);
+            if let Some(row) = table.selected()
+                && row != sheet.row()
+            {
+                sheet.set(row, table.data_frame().get_sheet_sections(row));
+            }
+        }
+    }
+
     pub fn show_sheet(&mut self) {
         if let Some(row) = self.tstack.last().selected() {
             let sections = self.tstack.last().data_frame().get_sheet_sections(row);
@@ -66,20 +200,62 @@ impl Pane {
         }
     }
 
-    fn show_fuzzy_search(&mut self) {
+    fn show_fuzzy_search(&mut self) -> AppResult<()> {
+        let df = self.full_data_frame()?;
         let tbl = self.tstack.last().to_owned();
         self.tstack.push(tbl);
-        self.modal = Some(Modal::SearchBar(SearchBar::fuzzy(
-            self.tstack.last().data_frame().clone(),
-        )));
+        self.modal = Some(Modal::SearchBar(SearchBar::fuzzy(df)));
+        Ok(())
     }
 
-    fn show_exact_search(&mut self) {
+    fn show_regex_search(&mut self) -> AppResult<()> {
+        let df = self.full_data_frame()?;
         let tbl = self.tstack.last().to_owned();
         self.tstack.push(tbl);
-        self.modal = Some(Modal::SearchBar(SearchBar::exact(
-            self.tstack.last().data_frame().clone(),
-        )));
+        self.modal = Some(Modal::SearchBar(SearchBar::regex(df)));
+        Ok(())
+    }
+
+    fn show_exact_search(&mut self) -> AppResult<()> {
+        let df = self.full_data_frame()?;
+        let tbl = self.tstack.last().to_owned();
+        self.tstack.push(tbl);
+        self.modal = Some(Modal::SearchBar(SearchBar::exact(df)));
+        Ok(())
+    }
+
+    /// Opens the filter row on a copy of the current view, or closes it keeping the filtered
+    /// rows.
+    fn toggle_filter_row(&mut self) -> AppResult<()> {
+        match &mut self.modal {
+            Some(Modal::FilterRow(filter_row)) => {
+                if let Some(df) = filter_row.flush() {
+                    self.apply_filter_row(df);
+                }
+                self.cancel_modal();
+            }
+            _ => {
+                let filter_row =
+                    FilterRow::new(self.full_data_frame()?, self.tstack.last().current_column());
+                let tbl = self.tstack.last().to_owned();
+                self.tstack.push(tbl);
+                self.dstack
+                    .push(TableDescription::Filter("filter row".to_owned()));
+                self.modal = Some(Modal::FilterRow(filter_row));
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_filter_row(&mut self, df: DataFrame) {
+        if let Some(Modal::FilterRow(filter_row)) = &self.modal {
+            *self.dstack.last_mut() = TableDescription::Filter(
+                filter_row
+                    .predicate()
+                    .unwrap_or_else(|| "filter row".to_owned()),
+            );
+        }
+        self.tstack.last_mut().set_data_frame(df);
     }
 
     fn show_data_frame_info(&mut self) {
@@ -111,7 +287,7 @@ impl Pane {
         y_label: String,
         group_by: Option<&str>,
     ) -> AppResult<()> {
-        let df = self.tstack.last().data_frame();
+        let df = self.full_data_frame()?;
         let plot = if let Some(group_by) = group_by {
             let (data, groups) = df.scatter_plot_data_grouped(&x_label, &y_label, group_by)?;
             ScatterPlot::new(x_label, y_label, data)?.with_groups(groups)
@@ -123,11 +299,124 @@ impl Pane {
         Ok(())
     }
 
-    fn show_inline_query_picker(&mut self, query_type: QueryType) {
-        self.modal = Some(Modal::InlineQueryPicker(InlineQueryPicker::new(
-            self.tstack.last().data_frame().clone(),
-            query_type,
+    fn show_line_plot(
+        &mut self,
+        x_label: String,
+        y_label: String,
+        group_by: Option<&str>,
+    ) -> AppResult<()> {
+        let df = self.full_data_frame()?;
+        let plot = if let Some(group_by) = group_by {
+            let (data, groups) = df.scatter_plot_data_grouped(&x_label, &y_label, group_by)?;
+            LinePlot::new(x_label, y_label, data)?.with_groups(groups)
+        } else {
+            let data = df.scatter_plot_data(&x_label, &y_label)?;
+            LinePlot::new(x_label, y_label, data)?
+        };
+        self.modal = Some(Modal::LinePlot(plot));
+        Ok(())
+    }
+
+    fn show_bar_plot(&mut self, column: &str) -> AppResult<()> {
+        self.modal = Some(Modal::BarPlot(BarPlot::new(
+            self.full_data_frame()?.bar_plot_data(column)?,
         )));
+        Ok(())
+    }
+
+    fn show_bar_plot_wizard(&mut self) {
+        self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+            column_index_wizard::State::with_show(
+                self.tstack.last().data_frame(),
+                Message::PaneShowBarPlot,
+            ),
+        )))
+    }
+
+    fn show_box_plot(&mut self, column: &str, group_by: Option<&str>) -> AppResult<()> {
+        self.modal = Some(Modal::BoxPlot(BoxPlot::new(
+            &self.full_data_frame()?,
+            column,
+            group_by,
+        )?));
+        Ok(())
+    }
+
+    fn show_density_plot(&mut self, column: &str, bandwidth: Option<f64>) -> AppResult<()> {
+        let (bandwidth, data) = self
+            .full_data_frame()?
+            .density_plot_data(column, bandwidth)?;
+        self.modal = Some(Modal::DensityPlot(DensityPlot::new(
+            column.to_owned(),
+            bandwidth,
+            data,
+        )?));
+        Ok(())
+    }
+
+    fn show_density_plot_wizard(&mut self) {
+        self.modal = Some(Modal::DensityWizard(DensityWizard::new(
+            density_wizard::State::new(self.tstack.last().data_frame()),
+        )))
+    }
+
+    fn show_null_map(&mut self) -> AppResult<()> {
+        let df = self.full_data_frame()?;
+        if df.height() == 0 {
+            Err(anyhow!("Null map needs at least one row"))?
+        }
+        self.modal = Some(Modal::NullMap(NullMap::new(df)));
+        Ok(())
+    }
+
+    fn show_corr_matrix(&mut self) -> AppResult<()> {
+        self.modal = Some(Modal::CorrMatrix(CorrMatrix::new(
+            self.full_data_frame()?.corr_matrix_data()?,
+        )));
+        Ok(())
+    }
+
+    /// Opens the summary statistics of the numeric and string columns in a new tab.
+    fn describe(&self) -> AppResult<()> {
+        let df = self.full_data_frame()?.describe()?;
+        Message::TabsAddNamePane(df, format!("{} describe", self.title())).enqueue();
+        Ok(())
+    }
+
+    /// Opens the current view transposed in a new tab, values are cast to text on the way.
+    fn transpose(&self) -> AppResult<()> {
+        let df = self.full_data_frame()?.transpose_as_text()?;
+        Message::TabsAddNamePane(df, format!("{} transposed as text", self.title())).enqueue();
+        Ok(())
+    }
+
+    fn show_box_plot_wizard(&mut self) {
+        self.modal = Some(Modal::BoxPlotWizard(Wizard::new(
+            box_plot_wizard::State::new(self.tstack.last().data_frame().clone()),
+        )))
+    }
+
+    fn show_inline_query_picker(&mut self, query_type: QueryType) {
+        let picker =
+            InlineQueryPicker::new(self.id, self.tstack.last().data_frame().clone(), query_type)
+                .with_lazy_table(self.lazy_base().map(str::to_owned));
+        self.modal = Some(Modal::InlineQueryPicker(match query_type {
+            QueryType::Select => picker.with_value(select_list(
+                self.tstack
+                    .last()
+                    .visible_data_frame()
+                    .get_column_names_str(),
+            )),
+            // The query of the tab is the usual starting point of a view
+            QueryType::View => match self.description() {
+                TableDescription::Query(query) => picker.with_value(format!(
+                    "{} as {query}",
+                    sql().schema().available_name("view")
+                )),
+                _ => picker,
+            },
+            _ => picker,
+        }));
     }
 
     fn show_go_to_line_with_value(&mut self, value: usize) {
@@ -136,18 +425,66 @@ impl Pane {
         }
     }
 
-    fn show_export_wizard(&mut self) {
+    /// Name of the lazily scanned table the base of the pane is a preview of.
+    fn lazy_table(&self) -> Option<&str> {
+        match self.dstack.base() {
+            TableDescription::Table(name)
+                if sql().schema().get(name).is_some_and(TableInfo::is_lazy) =>
+            {
+                Some(name)
+            }
+            _ => None,
+        }
+    }
+
+    /// Name of the lazily scanned table if the pane still shows nothing but its preview.
+    fn lazy_base(&self) -> Option<&str> {
+        self.lazy_table().filter(|_| {
+            self.dstack.len_without_base() == 0
+                && self.tstack.len_without_base() == 0
+                && self.scope.is_none()
+        })
+    }
+
+    /// Frame `_` refers to in queries on the current view, the preview of a lazily scanned table
+    /// leaves `_` referring to the whole table instead.
+    fn query_table(&self) -> AppResult<Option<DataFrame>> {
+        match self.lazy_base() {
+            Some(name) => {
+                sql().set_default(name)?;
+                Ok(None)
+            }
+            None => Ok(Some(self.tstack.last().data_frame().clone())),
+        }
+    }
+
+    /// Data frame of the current view, collecting the whole table if the pane still shows the
+    /// preview of a lazily scanned table.
+    pub fn full_data_frame(&self) -> AppResult<DataFrame> {
+        match self.lazy_base() {
+            Some(name) => {
+                let name = name.replace('"', "\"\"");
+                Ok(sql().execute(&format!("SELECT * FROM \"{name}\""), None)?)
+            }
+            None => Ok(self.tstack.last().data_frame().clone()),
+        }
+    }
+
+    fn show_export_wizard(&mut self) -> AppResult<()> {
+        let df = self.full_data_frame()?;
+        let name = self.title().to_owned();
         self.modal = Some(Modal::ExportWizard(ExportWizard::new(
-            self.tstack.last().data_frame().clone().into(),
-        )))
+            match self.tstack.last().marked_data_frame() {
+                Some(marked) => export_wizard::State::with_marked(df, marked, name),
+                None => export_wizard::State::new(df, name),
+            },
+        )));
+        Ok(())
     }
 
     fn show_histogram(&mut self, col: &str, buckets: usize) -> AppResult<()> {
         self.modal = Some(Modal::HistogramPlot(HistogramPlot::new(
-            self.tstack
-                .last()
-                .data_frame()
-                .histogram_plot_data(col, buckets)?,
+            self.full_data_frame()?.histogram_plot_data(col, buckets)?,
         )));
         Ok(())
     }
@@ -164,42 +501,735 @@ impl Pane {
         )))
     }
 
+    fn show_line_plot_wizard(&mut self) {
+        self.modal = Some(Modal::ScatterPlotWizard(Wizard::new(
+            scatter_plot_wizard::State::with_show(
+                self.tstack.last().data_frame().clone(),
+                Message::PaneShowLinePlot,
+            ),
+        )))
+    }
+
     fn show_table_registerer(&mut self) {
         self.modal = Some(Modal::TableRegisterer(TableRegisterer::new(
             self.tstack.last().data_frame().clone(),
         )));
     }
 
-    fn show_column_caster_wizard(&mut self) {
-        self.modal = Some(Modal::ColumnCasterWizard(ColumnCastWizard::new(
-            self.tstack.last().data_frame().clone().into(),
-        )))
+    fn show_column_caster_wizard(&mut self) {
+        self.modal = Some(Modal::ColumnCasterWizard(ColumnCastWizard::new(
+            self.tstack.last().data_frame().clone().into(),
+        )))
+    }
+
+    fn show_precision_picker(&mut self) {
+        self.modal = Some(Modal::PrecisionPicker(PrecisionPicker::new(
+            self.format.precision(),
+        )));
+    }
+
+    fn show_time_format_picker(&mut self) {
+        self.modal = Some(Modal::TimeFormatPicker(TimeFormatPicker::default()));
+    }
+
+    fn set_precision(&mut self, precision: Option<usize>) {
+        self.format.set_precision(precision);
+    }
+
+    fn show_pin_picker(&mut self) {
+        self.modal = Some(Modal::PinPicker(PinPicker::new(self.pinned)));
+    }
+
+    fn set_pinned(&mut self, pinned: usize) {
+        self.pinned = pinned;
+    }
+
+    fn show_notation_wizard(&mut self) {
+        self.modal = Some(Modal::NotationWizard(NotationWizard::new(
+            notation_wizard::State::new(self.tstack.last().data_frame()),
+        )))
+    }
+
+    fn set_notation(&mut self, column: &str, notation: Notation) -> AppResult<()> {
+        let dtype = self.tstack.last().data_frame().column(column)?.dtype();
+        if dtype.is_primitive_numeric() {
+            self.format.set_notation(column, notation);
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "Notation can not be applied to column '{column}' of type {dtype}"
+            ))
+        }
+    }
+
+    fn reverse(&mut self) -> AppResult<()> {
+        let selected = self.tstack.last().selected();
+        let df = self.full_data_frame()?.reverse();
+        let height = df.height();
+        self.push_data_frame(df, TableDescription::Order("reverse".to_owned()));
+        if let Some(selected) = selected {
+            self.select(height.saturating_sub(1).saturating_sub(selected));
+        }
+        Ok(())
+    }
+
+    /// Reports how many rows have an exact duplicate and how many would be dropped by dedup.
+    fn show_duplicates(&self) -> AppResult<()> {
+        let df = self.full_data_frame()?;
+        let duplicated = df.is_duplicated()?.sum().unwrap_or_default();
+        let dropped = df.height()
+            - df.unique_stable(None, UniqueKeepStrategy::First, None)?
+                .height();
+        Message::AppShowToast(format!(
+            "{duplicated} rows have an exact duplicate, dedup would drop {dropped}"
+        ))
+        .enqueue();
+        Ok(())
+    }
+
+    /// Drops exact duplicate rows, keeping the first occurrence of each in order.
+    fn dedup(&mut self) -> AppResult<()> {
+        let df = self.full_data_frame()?;
+        let dedup = df.unique_stable(None, UniqueKeepStrategy::First, None)?;
+        let dropped = df.height() - dedup.height();
+        self.push_data_frame(dedup, TableDescription::Filter("dedup".to_owned()));
+        Message::AppShowToast(format!("Dropped {dropped} duplicate rows")).enqueue();
+        Ok(())
+    }
+
+    fn show_sample_picker(&mut self) {
+        self.modal = Some(Modal::SamplePicker(SamplePicker::default()));
+    }
+
+    /// Keeps a random subset of the rows, reproducible if a seed was passed on the command line.
+    fn sample(&mut self, spec: SampleSpec) -> AppResult<()> {
+        let df = self.full_data_frame()?.random_sample(spec, args().seed)?;
+        self.push_data_frame(df, TableDescription::Filter(format!("sample {spec}")));
+        Ok(())
+    }
+
+    fn show_head_tail_picker(&mut self, slice: Slice) {
+        self.modal = Some(Modal::HeadTailPicker(HeadTailPicker::new(slice)));
+    }
+
+    /// Keeps the first or last rows, all of them if the table is shorter.
+    fn head_tail(&mut self, slice: Slice, rows: usize) -> AppResult<()> {
+        let df = self.full_data_frame()?;
+        let (df, description) = match slice {
+            Slice::Head => (df.head(Some(rows)), format!("head {rows}")),
+            Slice::Tail => (df.tail(Some(rows)), format!("tail {rows}")),
+        };
+        self.push_data_frame(df, TableDescription::Filter(description));
+        Ok(())
+    }
+
+    /// Renames columns of the shown table, the source keeps its names and reset restores them.
+    fn rename_columns(&mut self, spec: &RenameSpec) -> AppResult<()> {
+        let df = self.full_data_frame()?.rename_columns(spec)?;
+        self.push_data_frame(df, TableDescription::Select(format!("rename {spec}")));
+        Ok(())
+    }
+
+    fn cast_column(
+        &mut self,
+        column: &str,
+        target_type: TargetType,
+        strict: bool,
+    ) -> AppResult<()> {
+        let mut df = self.full_data_frame()?;
+        cast_column(&mut df, column, target_type, strict)?;
+        self.push_data_frame(
+            df,
+            TableDescription::Cast(format!("'{column}' as {target_type}")),
+        );
+        Ok(())
+    }
+
+    fn show_column_index_wizard(&mut self) {
+        self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+            column_index_wizard::State::new(self.tstack.last().data_frame()),
+        )))
+    }
+
+    fn show_column_index(&mut self, column: &str) -> AppResult<()> {
+        self.modal = Some(Modal::ColumnIndex(ColumnIndex::new(
+            self.full_data_frame()?,
+            column,
+        )?));
+        Ok(())
+    }
+
+    fn show_column_stats_wizard(&mut self) {
+        self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+            column_index_wizard::State::with_show(
+                self.tstack.last().data_frame(),
+                Message::PaneShowColumnStats,
+            ),
+        )))
+    }
+
+    fn show_column_stats(&mut self, column: &str) -> AppResult<()> {
+        self.modal = Some(Modal::ColumnStats(ColumnStats::new(
+            &self.full_data_frame()?,
+            column,
+        )?));
+        Ok(())
+    }
+
+    fn show_value_counts_wizard(&mut self) {
+        self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+            column_index_wizard::State::with_show(
+                self.tstack.last().data_frame(),
+                Message::PaneShowValueCounts,
+            ),
+        )))
+    }
+
+    /// Opens the distinct values of the column with their number of occurrences in a new tab.
+    fn show_value_counts(&mut self, column: &str) -> AppResult<()> {
+        let column = format!("\"{}\"", column.replace('"', "\"\""));
+        let query = format!(
+            "SELECT {column}, COUNT(*) AS count FROM _ GROUP BY {column} ORDER BY count DESC"
+        );
+        let default_table = self.query_table()?;
+        let df = sql().execute_timed(&query, default_table)?;
+        Message::TabsAddQueryPane(df, query).enqueue();
+        Ok(())
+    }
+
+    fn show_pivot_wizard(&mut self) {
+        self.modal = Some(Modal::PivotWizard(Wizard::new(pivot_wizard::State::new(
+            self.tstack.last().data_frame().clone(),
+        ))))
+    }
+
+    /// Registers a wide table with a column for each distinct value of `columns` and opens it in
+    /// a new tab, SQL has no pivot the tab could be described with.
+    fn pivot(
+        &mut self,
+        index: &str,
+        columns: &str,
+        values: &str,
+        agg: Aggregation,
+    ) -> AppResult<()> {
+        let df = self.full_data_frame()?.pivot(index, columns, values, agg)?;
+        let base = match self.dstack.base() {
+            TableDescription::Table(name) => format!("{name}_pivot"),
+            _ => "pivot".to_owned(),
+        };
+        let name = sql().register(&base, df.clone(), Source::User);
+        Message::TabsAddNamePane(df, name).enqueue();
+        Ok(())
+    }
+
+    /// Sorts by the column, descending if the current view is already sorted ascending by it.
+    /// Cycles the column through ascending, descending and unsorted. The descending view
+    /// replaces the ascending one, so unsorting drops a single view.
+    fn order_by_column(&mut self, idx: usize) -> AppResult<()> {
+        let df = self.tstack.last().visible_data_frame();
+        let Some(column) = df.get_column_names().get(idx).map(|name| name.to_string()) else {
+            return Ok(());
+        };
+        match self.tstack.last().sort() {
+            Some((sorted, true)) if sorted == &column => self.pop_data_frame(),
+            Some((sorted, false)) if sorted == &column => {
+                self.pop_data_frame();
+                self.push_order(column, true)?;
+            }
+            _ => self.push_order(column, false)?,
+        }
+        Ok(())
+    }
+
+    fn push_order(&mut self, column: String, descending: bool) -> AppResult<()> {
+        let quoted = format!("\"{}\"", column.replace('"', "\"\""));
+        let order = if descending {
+            format!("{quoted} DESC")
+        } else {
+            quoted
+        };
+        // Hidden columns stay in the sorted view, the pane keeps hiding them
+        let default_table = self.query_table()?;
+        let df =
+            sql().execute_timed(&format!("SELECT * FROM _ ORDER BY {order}"), default_table)?;
+        self.push_data_frame(df, TableDescription::Order(order));
+        self.tstack.last_mut().set_sort(Some((column, descending)));
+        Ok(())
+    }
+
+    /// Copies the selected row to the clipboard as a tab separated line, after a line of the
+    /// column names if `header` is set.
+    fn copy_row(&self, header: bool) -> AppResult<()> {
+        let table = self.tstack.last();
+        if let Some(row) = table.selected() {
+            let df = table.data_frame();
+            let mut line = df
+                .get_columns()
+                .iter()
+                .map(|column| column.get(row).map(AnyValueExt::into_single_line))
+                .collect::<Result<Vec<_>, _>>()?
+                .join("\t");
+            if header {
+                line = format!("{}\n{line}", df.get_column_names_str().join("\t"));
+            }
+            line.copy_to_clipboard_via_osc52();
+            Message::AppShowToast("Row copied".to_owned()).enqueue();
+        }
+        Ok(())
+    }
+
+    /// Copies the whole table of the current view to the clipboard, tables larger than the
+    /// configured limit have to be exported to a file instead.
+    fn export_clipboard(&self, format: TextFormat) -> AppResult<()> {
+        let mut df = self.full_data_frame()?;
+        let bytes = text_writer(format).write_to_bytes(&mut df)?;
+        let max = config().clipboard_max_bytes();
+        if bytes.len() > max {
+            Err(anyhow!(
+                "The table takes {}, more than the clipboard_max_bytes limit of {}, export it to a file instead",
+                human_readable_size(bytes.len() as u64),
+                human_readable_size(max as u64)
+            ))?
+        }
+        bytes.copy_to_clipboard_via_osc52();
+        let note = if bytes.len() > OSC52_WARN_BYTES {
+            ", terminals may cut clipboard copies this large"
+        } else {
+            ""
+        };
+        Message::AppShowToast(format!(
+            "Copied {} rows ({}) to the clipboard{note}",
+            df.height(),
+            human_readable_size(bytes.len() as u64)
+        ))
+        .enqueue();
+        Ok(())
+    }
+
+    fn show_filter_selected_wizard(&mut self) {
+        if self.tstack.last().selected().is_some() {
+            self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+                column_index_wizard::State::with_show(
+                    self.tstack.last().data_frame(),
+                    Message::PaneFilterSelected,
+                ),
+            )))
+        }
+    }
+
+    /// Keeps the rows where the column has the value of the selected row.
+    fn filter_selected(&mut self, column: &str) -> AppResult<()> {
+        let Some(row) = self.tstack.last().selected() else {
+            return Ok(());
+        };
+        let value = self.tstack.last().data_frame().column(column)?.get(row)?;
+        let predicate = equals_predicate(column, value)?;
+        let default_table = self.query_table()?;
+        let filtered =
+            sql().execute_timed(&format!("SELECT * FROM _ WHERE {predicate}"), default_table)?;
+        self.push_data_frame(filtered, TableDescription::Filter(predicate));
+        Ok(())
+    }
+
+    /// Appends a column computed from the columns of the current view.
+    fn add_column(&mut self, spec: &str) -> AppResult<()> {
+        let df = self.full_data_frame()?;
+        let columns = df
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect_vec();
+        let (name, expr) = parse_computed_column(spec, &columns)?;
+        if columns.contains(&name) {
+            Err(anyhow!("Column '{name}' already exists"))?
+        }
+        let df = df.lazy().with_column(expr.alias(&name)).collect()?;
+        self.push_data_frame(
+            df,
+            TableDescription::Select(format!("addcol {}", spec.trim())),
+        );
+        Ok(())
+    }
+
+    /// Shows how many rows of the current view match the predicate, every row if it is empty,
+    /// without changing the view. The preview of a lazily scanned table is counted against the
+    /// table itself without collecting it.
+    fn count(&self, predicate: &str) -> AppResult<()> {
+        let (table, df, total) = match self.lazy_base() {
+            Some(name) => (
+                format!("\"{}\"", name.replace('"', "\"\"")),
+                None,
+                sql()
+                    .schema()
+                    .get(name)
+                    .map(TableInfo::height)
+                    .unwrap_or_default(),
+            ),
+            None => {
+                let df = self.tstack.last().data_frame().clone();
+                let total = df.height();
+                ("_".to_owned(), Some(df), total)
+            }
+        };
+        let predicate = predicate.trim();
+        let message = if predicate.is_empty() {
+            format!("{total} rows")
+        } else {
+            let count = sql()
+                .execute_timed(
+                    &format!("SELECT COUNT(*) AS count FROM {table} WHERE {predicate}"),
+                    df,
+                )?
+                .column("count")?
+                .get(0)?
+                .extract::<usize>()
+                .unwrap_or_default();
+            format!("{count} of {total} rows match {predicate}")
+        };
+        Message::AppShowToast(message).enqueue();
+        Ok(())
+    }
+
+    fn show_cell_edit_wizard(&mut self) {
+        if self.tstack.last().selected().is_some() {
+            self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+                column_index_wizard::State::with_show(
+                    self.tstack.last().data_frame(),
+                    Message::PaneEditCell,
+                ),
+            )))
+        }
+    }
+
+    /// Opens an input prefilled with the value of the column at the selected row.
+    fn show_cell_editor(&mut self, column: &str) -> AppResult<()> {
+        let Some(row) = self.tstack.last().selected() else {
+            return Ok(());
+        };
+        let value = self
+            .tstack
+            .last()
+            .data_frame()
+            .column(column)?
+            .get(row)?
+            .into_single_line();
+        self.modal = Some(Modal::CellEditor(CellEditor::new(
+            row,
+            column.to_owned(),
+            value,
+        )));
+        Ok(())
+    }
+
+    fn show_copy_column_wizard(&mut self) {
+        self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+            column_index_wizard::State::with_show(
+                self.tstack.last().data_frame(),
+                Message::PaneCopyColumn,
+            ),
+        )))
+    }
+
+    /// Copies the values of the column in the current view to the clipboard, one per line.
+    fn copy_column(&self, column: &str) -> AppResult<()> {
+        let values = self
+            .tstack
+            .last()
+            .data_frame()
+            .column(column)?
+            .as_materialized_series()
+            .iter()
+            .map(AnyValueExt::into_single_line)
+            .join("\n");
+        values.copy_to_clipboard_via_osc52();
+        Message::AppShowToast(format!("Column {column} copied")).enqueue();
+        Ok(())
+    }
+
+    fn show_hide_column_wizard(&mut self) {
+        self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+            column_index_wizard::State::with_show(
+                self.tstack.last().visible_data_frame(),
+                Message::PaneHideColumn,
+            ),
+        )))
+    }
+
+    /// Hides the column from the table of every view in this tab, queries still see it.
+    fn hide_column(&mut self, column: &str) -> AppResult<()> {
+        let visible = self.tstack.last().visible_data_frame();
+        if visible.width() <= 1 {
+            Err(anyhow!("At least one column has to stay visible"))?
+        }
+        visible.column(column)?;
+        self.hidden.insert(column.to_owned());
+        Ok(())
+    }
+
+    fn show_show_column_wizard(&mut self) -> AppResult<()> {
+        if self.hidden.is_empty() {
+            Err(anyhow!("No hidden columns"))?
+        }
+        self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+            column_index_wizard::State::with_columns(
+                self.hidden.iter().cloned().collect(),
+                Message::PaneShowColumn,
+            ),
+        )));
+        Ok(())
+    }
+
+    fn show_column(&mut self, column: &str) {
+        self.hidden.remove(column);
+        if self.hidden.is_empty() {
+            self.cancel_modal();
+        }
+    }
+
+    fn show_shade_column_wizard(&mut self) -> AppResult<()> {
+        let columns = self
+            .tstack
+            .last()
+            .visible_data_frame()
+            .get_columns()
+            .iter()
+            .filter(|column| column.dtype().is_primitive_numeric())
+            .map(|column| column.name().to_string())
+            .collect_vec();
+        if columns.is_empty() {
+            Err(anyhow!("No numeric columns to shade"))?
+        }
+        self.modal = Some(Modal::ColumnIndexWizard(ColumnIndexWizard::new(
+            column_index_wizard::State::with_columns(columns, Message::PaneShadeColumn),
+        )));
+        Ok(())
+    }
+
+    /// Shades the cells of the numeric column in every view of this tab that has it.
+    fn shade_column(&mut self, column: &str) -> AppResult<()> {
+        self.tstack.last_mut().shade(column)?;
+        self.shade = Some(column.to_owned());
+        Ok(())
+    }
+
+    fn zebra(&self) -> bool {
+        self.zebra.unwrap_or_else(|| config().zebra_stripes())
+    }
+
+    fn toggle_zebra(&mut self) {
+        self.zebra = Some(!self.zebra());
+    }
+
+    fn toggle_fit_columns(&mut self) {
+        self.fit_columns = !self.fit_columns;
+    }
+
+    fn toggle_wrap(&mut self) {
+        self.wrap = !self.wrap;
+    }
+
+    fn show_max_width_picker(&mut self) {
+        self.modal = Some(Modal::MaxWidthPicker(MaxWidthPicker::default()));
+    }
+
+    pub fn predicate(&self) -> Option<&str> {
+        self.scope.as_ref().map(|scope| scope.predicate.as_str())
+    }
+
+    fn show_where_picker(&mut self) {
+        self.modal = Some(Modal::WherePicker(WherePicker::new(self.predicate())));
+    }
+
+    /// Applies the predicate to the unscoped base of the pane, dropping the views above it.
+    fn set_where(&mut self, predicate: Option<String>) -> AppResult<()> {
+        let unscoped = self
+            .scope
+            .as_ref()
+            .map(|scope| scope.unscoped.clone())
+            .unwrap_or_else(|| self.tstack.base().data_frame().clone());
+        // The predicate of a lazily scanned table is applied to the table, not to its preview
+        let default_table = match self.lazy_table() {
+            Some(name) => {
+                sql().set_default(name)?;
+                None
+            }
+            None => Some(unscoped.clone()),
+        };
+        let df = match &predicate {
+            Some(predicate) => {
+                sql().execute_timed(&format!("SELECT * FROM _ WHERE {predicate}"), default_table)?
+            }
+            None => unscoped.clone(),
+        };
+        self.scope = predicate.map(|predicate| Scope {
+            predicate,
+            unscoped,
+        });
+        self.tstack.reset(table(df));
+        self.dstack.reset(self.dstack.base().clone());
+        Ok(())
+    }
+
+    fn push_data_frame(&mut self, df: DataFrame, description: TableDescription) {
+        self.tstack.push(table(df));
+        self.dstack.push(description);
+    }
+
+    fn pop_data_frame(&mut self) {
+        self.tstack.pop();
+        self.dstack.pop();
+    }
+
+    /// Drops the views on top of the table, asking first if the config says so.
+    fn reset(&mut self) {
+        match self.tstack.len_without_base() {
+            0 => (),
+            views if config().confirm_reset() => {
+                self.modal = Some(Modal::ConfirmPicker(ConfirmPicker::new(
+                    format!("Reset and drop {views} views?"),
+                    Message::PaneResetNow,
+                )))
+            }
+            _ => self.reset_now(),
+        }
+    }
+
+    fn reset_now(&mut self) {
+        while self.tstack.len_without_base() > 0 {
+            self.pop_data_frame();
+        }
+        self.tstack.last_mut().clear_bookmarks();
+    }
+
+    /// Registers the shown table under a new name and opens it in a tab of its own, later views
+    /// of this tab leave it untouched.
+    fn snapshot(&self) -> AppResult<()> {
+        let base = match self.dstack.base() {
+            TableDescription::Table(name) => format!("{name}_snapshot"),
+            _ => "snapshot".to_owned(),
+        };
+        let df = self.full_data_frame()?;
+        let name = sql().register(&base, df.clone(), Source::User);
+        Message::AppShowToast(format!("Snapshot registered as {name}")).enqueue();
+        Message::TabsAddNamePane(df, name).enqueue();
+        Ok(())
+    }
+
+    /// Keeps the bookmarked rows, reset brings back the full table.
+    fn filter_bookmarks(&mut self) -> AppResult<()> {
+        let df = self.tstack.last().bookmarked_data_frame().ok_or(anyhow!(
+            "No row is bookmarked, press ' to bookmark the selected row"
+        ))?;
+        self.push_data_frame(df, TableDescription::Filter("bookmarks".to_owned()));
+        Ok(())
+    }
+
+    fn select(&mut self, idx: usize) {
+        self.tstack.last_mut().select(idx);
+    }
+
+    fn select_random(&mut self) {
+        self.select(rand::rng().random_range(0..self.tstack.last().data_frame().height()));
+    }
+
+    fn cancel_modal(&mut self) {
+        self.modal.take();
+    }
+
+    fn show_rename_picker(&mut self) {
+        self.modal = Some(Modal::RenamePicker(RenamePicker::new(self.title())));
+    }
+
+    /// Starts reloading the base table whenever the file it was read from changes.
+    fn watch(&mut self) -> AppResult<()> {
+        let source = match self.dstack.base() {
+            TableDescription::Table(name) => {
+                sql().schema().get(name).map(|info| info.source().clone())
+            }
+            _ => None,
+        };
+        let Some(Source::File(path)) = source else {
+            Err(anyhow!("Only tables read from a file can be watched"))?
+        };
+        self.watcher = Some(FileWatcher::new(&path)?);
+        Message::AppShowToast(format!("Watching {}", path.display())).enqueue();
+        Ok(())
+    }
+
+    fn unwatch(&mut self) {
+        if let Some(watcher) = self.watcher.take() {
+            Message::AppShowToast(format!("Stopped watching {}", watcher.path().display()))
+                .enqueue();
+        }
     }
 
-    fn push_data_frame(&mut self, df: DataFrame, description: TableDescription) {
-        self.tstack.push(table(df));
-        self.dstack.push(description);
+    /// Returns true if the pane watches the file of the table.
+    fn watches(&self, name: &str) -> bool {
+        self.watcher.is_some()
+            && matches!(self.dstack.base(), TableDescription::Table(base) if base == name)
     }
 
-    fn pop_data_frame(&mut self) {
-        self.tstack.pop();
-        self.dstack.pop();
+    /// Re-reads the watched file on a worker thread and re-registers the table under the same
+    /// name, the pane shows it once it is read.
+    fn reload(&self) {
+        let TableDescription::Table(name) = self.dstack.base() else {
+            return;
+        };
+        let name = name.to_owned();
+        let (path, lazy, reader) = {
+            let sql = sql();
+            let Some(info) = sql.schema().get(&name) else {
+                return;
+            };
+            let Source::File(path) = info.source().clone() else {
+                return;
+            };
+            (path, info.is_lazy(), sql.reader(&name))
+        };
+        std::thread::spawn(move || {
+            reread(&name, path, lazy, reader)
+                .map(|df| Message::PaneReloaded(name, df).enqueue())
+                .unwrap_or_enqueue_error()
+        });
     }
 
-    fn select(&mut self, idx: usize) {
-        self.tstack.last_mut().select(idx);
+    /// Replaces the base of the pane with the re-read table, derived views are dropped, the
+    /// scope and the selected row are kept.
+    fn show_reloaded(&mut self, df: DataFrame) -> AppResult<()> {
+        let selected = self.tstack.last().selected();
+        let predicate = self.scope.take().map(|scope| scope.predicate);
+        self.tstack.reset(table(df));
+        self.dstack.reset(self.dstack.base().clone());
+        self.set_where(predicate)?;
+        if let Some(selected) = selected {
+            self.select(selected);
+        }
+        Ok(())
     }
 
-    fn select_random(&mut self) {
-        self.select(rand::rng().random_range(0..self.tstack.last().data_frame().height()));
+    /// Sets the display name of the pane, the name registered in the SQL context is unchanged.
+    fn rename(&mut self, name: Option<String>) {
+        self.name = name;
     }
 
-    fn cancel_modal(&mut self) {
-        self.modal.take();
+    pub fn theme(&self) -> Option<&LoadedTheme> {
+        self.theme.as_ref()
+    }
+
+    /// Overrides the theme of this tab, None goes back to the global theme.
+    fn set_theme(&mut self, theme: Option<Theme>) {
+        self.theme = theme.map(LoadedTheme::from);
+        Message::AppShowToast(match theme {
+            Some(theme) => format!("Tab theme set to {theme}"),
+            None => "Tab theme cleared".to_owned(),
+        })
+        .enqueue();
     }
 
     pub fn title(&self) -> &str {
-        self.dstack.base().title()
+        self.name
+            .as_deref()
+            .unwrap_or_else(|| self.dstack.base().title())
     }
 }
 
@@ -213,6 +1243,22 @@ impl Component for Pane {
         self.tstack
             .last_mut()
             .set_gutter_visibility(config().show_table_row_numbers());
+        self.tstack.last_mut().set_format(
+            &self
+                .format
+                .with_defaults(config().thousands_separator(), config().float_precision())
+                .with_time(config().time_format(), Utc::now().naive_utc()),
+        );
+        self.tstack.last_mut().set_pinned(self.pinned);
+        self.tstack.last_mut().set_hidden(&self.hidden);
+        let zebra = self.zebra();
+        self.tstack.last_mut().set_striped(zebra);
+        self.tstack.last_mut().set_fit_columns(self.fit_columns);
+        self.tstack.last_mut().set_wrap(self.wrap);
+        self.tstack.last_mut().set_shade(self.shade.as_deref());
+        self.tstack
+            .last_mut()
+            .set_max_width(config().max_column_width());
         match &mut self.modal {
             Some(Modal::Sheet(sheet_state)) => {
                 if let Some(row) = self.tstack.last().selected()
@@ -221,6 +1267,7 @@ impl Component for Pane {
                     let sections = self.tstack.last().data_frame().get_sheet_sections(row);
                     sheet_state.set(row, sections);
                 }
+                sheet_state.set_total(self.tstack.last().data_frame().height());
                 self.tstack.last_mut().render(area, buf, focus_state);
                 let area = area.inner(Margin::new(13, 3));
                 sheet_state.render(area, buf, focus_state);
@@ -231,6 +1278,12 @@ impl Component for Pane {
                 self.tstack.last_mut().render(table_area, buf, focus_state);
                 search_bar_state.render(search_area, buf, focus_state);
             }
+            Some(Modal::FilterRow(filter_row)) => {
+                let [filter_area, table_area] =
+                    Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(area);
+                self.tstack.last_mut().render(table_area, buf, focus_state);
+                filter_row.render(filter_area, buf, focus_state);
+            }
             Some(Modal::GoToLine(state)) => {
                 self.tstack.last_mut().render(area, buf, focus_state);
                 state.render(area, buf, focus_state);
@@ -247,12 +1300,54 @@ impl Component for Pane {
                     .render(area, buf, FocusState::NotFocused);
                 state.render(area, buf, focus_state);
             }
+            Some(Modal::LinePlot(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
             Some(Modal::HistogramPlot(state)) => {
                 self.tstack
                     .last_mut()
                     .render(area, buf, FocusState::NotFocused);
                 state.render(area, buf, focus_state);
             }
+            Some(Modal::BarPlot(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::DensityPlot(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::BoxPlot(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::CorrMatrix(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::NullMap(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::BoxPlotWizard(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
             Some(Modal::InlineQueryPicker(state)) => {
                 self.tstack
                     .last_mut()
@@ -271,6 +1366,12 @@ impl Component for Pane {
                     .render(area, buf, FocusState::NotFocused);
                 state.render(area, buf, focus_state);
             }
+            Some(Modal::DensityWizard(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
             Some(Modal::ScatterPlotWizard(state)) => {
                 self.tstack
                     .last_mut()
@@ -289,10 +1390,133 @@ impl Component for Pane {
                     .render(area, buf, FocusState::NotFocused);
                 state.render(area, buf, focus_state);
             }
+            Some(Modal::WherePicker(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::ColumnList(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::GoToColumn(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::CellEditor(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::RenamePicker(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::PrecisionPicker(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::MaxWidthPicker(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::SamplePicker(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::HeadTailPicker(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::ConfirmPicker(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::TimeFormatPicker(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::PivotWizard(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::PinPicker(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::NotationWizard(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::ColumnIndexWizard(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::ColumnStats(state)) => {
+                self.tstack
+                    .last_mut()
+                    .render(area, buf, FocusState::NotFocused);
+                state.render(area, buf, focus_state);
+            }
+            Some(Modal::ColumnIndex(state)) => {
+                let [table_area, index_area] = Layout::horizontal([
+                    Constraint::Fill(1),
+                    Constraint::Length(state.width().min(area.width / 3)),
+                ])
+                .areas(area);
+                self.tstack
+                    .last_mut()
+                    .render(table_area, buf, FocusState::NotFocused);
+                state.render(index_area, buf, focus_state);
+            }
             None => self.tstack.last_mut().render(area, buf, focus_state),
         }
     }
 
+    fn handle_mouse(&mut self, event: crossterm::event::MouseEvent) -> bool {
+        if self.modal.is_some() {
+            return false;
+        }
+        match (
+            event.kind,
+            self.tstack.last().header_column_at(event.column, event.row),
+        ) {
+            (MouseEventKind::Down(MouseButton::Left), Some(idx)) => {
+                self.order_by_column(idx).unwrap_or_enqueue_error();
+                true
+            }
+            _ => self.tstack.last_mut().handle_mouse(event),
+        }
+    }
+
     fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
         (match &mut self.modal {
             Some(Modal::SearchBar(search_bar)) => {
@@ -301,6 +1525,9 @@ impl Component for Pane {
             Some(Modal::Sheet(sheet)) => {
                 sheet.handle(event) || self.tstack.last_mut().handle(event)
             }
+            Some(Modal::FilterRow(filter_row)) => {
+                filter_row.handle(event) || self.tstack.last_mut().handle(event)
+            }
             Some(Modal::GoToLine(go_to_line)) => go_to_line.handle(event),
             Some(Modal::DataFrameInfo(data_frame_info)) => data_frame_info.handle(event),
             Some(Modal::ExportWizard(export_wizard)) => export_wizard.handle(event),
@@ -308,11 +1535,36 @@ impl Component for Pane {
             Some(Modal::HistogramWizard(histogram_wizard)) => histogram_wizard.handle(event),
             Some(Modal::InlineQueryPicker(query_picker)) => query_picker.handle(event),
             Some(Modal::ScatterPlot(scatter_plot)) => scatter_plot.handle(event),
+            Some(Modal::LinePlot(line_plot)) => line_plot.handle(event),
+            Some(Modal::BarPlot(bar_plot)) => bar_plot.handle(event),
+            Some(Modal::DensityPlot(density_plot)) => density_plot.handle(event),
+            Some(Modal::DensityWizard(density_wizard)) => density_wizard.handle(event),
+            Some(Modal::BoxPlot(box_plot)) => box_plot.handle(event),
+            Some(Modal::BoxPlotWizard(box_plot_wizard)) => box_plot_wizard.handle(event),
+            Some(Modal::CorrMatrix(corr_matrix)) => corr_matrix.handle(event),
+            Some(Modal::NullMap(null_map)) => null_map.handle(event),
             Some(Modal::TableRegisterer(table_registerer)) => table_registerer.handle(event),
             Some(Modal::ScatterPlotWizard(scatter_plot_wizard)) => {
                 scatter_plot_wizard.handle(event)
             }
             Some(Modal::ColumnCasterWizard(wizard)) => wizard.handle(event),
+            Some(Modal::PrecisionPicker(precision_picker)) => precision_picker.handle(event),
+            Some(Modal::MaxWidthPicker(picker)) => picker.handle(event),
+            Some(Modal::SamplePicker(picker)) => picker.handle(event),
+            Some(Modal::HeadTailPicker(picker)) => picker.handle(event),
+            Some(Modal::ConfirmPicker(picker)) => picker.handle(event),
+            Some(Modal::TimeFormatPicker(picker)) => picker.handle(event),
+            Some(Modal::PinPicker(pin_picker)) => pin_picker.handle(event),
+            Some(Modal::PivotWizard(pivot_wizard)) => pivot_wizard.handle(event),
+            Some(Modal::WherePicker(where_picker)) => where_picker.handle(event),
+            Some(Modal::RenamePicker(rename_picker)) => rename_picker.handle(event),
+            Some(Modal::CellEditor(cell_editor)) => cell_editor.handle(event),
+            Some(Modal::ColumnList(column_list)) => column_list.handle(event),
+            Some(Modal::GoToColumn(go_to_column)) => go_to_column.handle(event),
+            Some(Modal::NotationWizard(wizard)) => wizard.handle(event),
+            Some(Modal::ColumnIndexWizard(wizard)) => wizard.handle(event),
+            Some(Modal::ColumnIndex(column_index)) => column_index.handle(event),
+            Some(Modal::ColumnStats(column_stats)) => column_stats.handle(event),
 
             None => self.tstack.last_mut().handle(event),
         }) || (match (event.code, event.modifiers) {
@@ -364,8 +1616,21 @@ impl Component for Pane {
                 self.show_data_frame_info();
                 true
             }
+            (KeyCode::Char('s'), KeyModifiers::NONE) => {
+                self.order_by_column(self.tstack.last().current_column())
+                    .unwrap_or_enqueue_error();
+                true
+            }
+            (KeyCode::Char('E'), KeyModifiers::SHIFT) => {
+                self.show_cell_edit_wizard();
+                true
+            }
+            (KeyCode::Char('F'), KeyModifiers::SHIFT) => {
+                self.show_filter_selected_wizard();
+                true
+            }
             (KeyCode::Char('/'), KeyModifiers::NONE) => {
-                self.show_fuzzy_search();
+                self.show_fuzzy_search().unwrap_or_enqueue_error();
                 true
             }
             (KeyCode::Char('R'), KeyModifiers::SHIFT)
@@ -374,11 +1639,20 @@ impl Component for Pane {
                 self.select_random();
                 true
             }
+            (KeyCode::Char('\\'), KeyModifiers::NONE) => {
+                self.show_regex_search().unwrap_or_enqueue_error();
+                true
+            }
             (KeyCode::Char('?'), KeyModifiers::NONE)
             | (KeyCode::Char('?'), KeyModifiers::SHIFT) => {
-                self.show_exact_search();
+                self.show_exact_search().unwrap_or_enqueue_error();
+                true
+            }
+            (KeyCode::Char('r'), KeyModifiers::CONTROL) => {
+                self.reset();
                 true
             }
+            #[allow(clippy::collapsible_match)]
             (KeyCode::Char('q'), KeyModifiers::NONE) => {
                 if self.tstack.len_without_base() > 0 {
                     self.pop_data_frame();
@@ -406,7 +1680,46 @@ impl Component for Pane {
             Message::PaneShowInlineOrder if focus_state.is_focused() => {
                 self.show_inline_query_picker(QueryType::Order)
             }
-            Message::PaneShowExportWizard if focus_state.is_focused() => self.show_export_wizard(),
+            Message::PaneShowInlineGroupBy if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::GroupBy)
+            }
+            Message::PaneShowInlineJoin if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::Join)
+            }
+            Message::PaneShowInlineDiff if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::Diff)
+            }
+            Message::PaneShowInlineUnpivot if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::Unpivot)
+            }
+            Message::PaneShowInlineRename if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::Rename)
+            }
+            Message::PaneShowInlineView if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::View)
+            }
+            Message::PaneShowInlineAddColumn if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::AddColumn)
+            }
+            Message::PaneAddColumn(spec) if focus_state.is_focused() => {
+                self.add_column(spec).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowInlineCount if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::Count)
+            }
+            Message::PaneCount(predicate) if focus_state.is_focused() => {
+                self.count(predicate).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowInlineTabTheme if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::TabTheme)
+            }
+            Message::PaneSetTheme(theme) if focus_state.is_focused() => self.set_theme(*theme),
+            Message::PaneShowInlineCast if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::Cast)
+            }
+            Message::PaneShowExportWizard if focus_state.is_focused() => {
+                self.show_export_wizard().unwrap_or_enqueue_error()
+            }
             Message::PaneShowScatterPlotWizard if focus_state.is_focused() => {
                 self.show_scatter_plot_wizard()
             }
@@ -419,6 +1732,42 @@ impl Component for Pane {
             Message::PaneShowScatterPlot(x, y, grp) if focus_state.is_focused() => self
                 .show_scatter_plot(x.to_owned(), y.to_owned(), grp.as_deref())
                 .unwrap_or_enqueue_error(),
+            Message::PaneShowLinePlotWizard if focus_state.is_focused() => {
+                self.show_line_plot_wizard()
+            }
+            Message::PaneShowBarPlotWizard if focus_state.is_focused() => {
+                self.show_bar_plot_wizard()
+            }
+            Message::PaneShowBarPlot(column) if focus_state.is_focused() => {
+                self.show_bar_plot(column).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowDensityPlotWizard if focus_state.is_focused() => {
+                self.show_density_plot_wizard()
+            }
+            Message::PaneShowDensityPlot(column, bandwidth) if focus_state.is_focused() => self
+                .show_density_plot(column, *bandwidth)
+                .unwrap_or_enqueue_error(),
+            Message::PaneShowBoxPlotWizard if focus_state.is_focused() => {
+                self.show_box_plot_wizard()
+            }
+            Message::PaneShowBoxPlot(column, grp) if focus_state.is_focused() => self
+                .show_box_plot(column, grp.as_deref())
+                .unwrap_or_enqueue_error(),
+            Message::PaneShowNullMap if focus_state.is_focused() => {
+                self.show_null_map().unwrap_or_enqueue_error()
+            }
+            Message::PaneShowCorrMatrix if focus_state.is_focused() => {
+                self.show_corr_matrix().unwrap_or_enqueue_error()
+            }
+            Message::PaneDescribe if focus_state.is_focused() => {
+                self.describe().unwrap_or_enqueue_error()
+            }
+            Message::PaneTranspose if focus_state.is_focused() => {
+                self.transpose().unwrap_or_enqueue_error()
+            }
+            Message::PaneShowLinePlot(x, y, grp) if focus_state.is_focused() => self
+                .show_line_plot(x.to_owned(), y.to_owned(), grp.as_deref())
+                .unwrap_or_enqueue_error(),
             Message::PaneShowTableRegisterer if focus_state.is_focused() => {
                 self.show_table_registerer()
             }
@@ -426,26 +1775,219 @@ impl Component for Pane {
             Message::PanePushDataFrame(df, desc) if focus_state.is_focused() => {
                 self.push_data_frame(df.clone(), desc.clone())
             }
+            Message::PanePushQueryResult(id, df, desc) if *id == self.id => {
+                self.push_data_frame(df.clone(), desc.clone())
+            }
             Message::PanePopDataFrame if focus_state.is_focused() => self.pop_data_frame(),
             Message::PaneTableSelect(idx) if focus_state.is_focused() => self.select(*idx),
             Message::PaneShowTableInfo if focus_state.is_focused() => self.show_data_frame_info(),
+            Message::PaneShowColumnList if focus_state.is_focused() => {
+                self.modal = Some(Modal::ColumnList(ColumnList::new(
+                    self.tstack.last().visible_data_frame(),
+                )))
+            }
             Message::PaneShowColumnCasterWizard if focus_state.is_focused() => {
                 self.show_column_caster_wizard()
             }
+            Message::PaneShowPrecisionPicker if focus_state.is_focused() => {
+                self.show_precision_picker()
+            }
+            Message::PaneShowTimeFormatPicker if focus_state.is_focused() => {
+                self.show_time_format_picker()
+            }
+            Message::PaneSetPrecision(precision) if focus_state.is_focused() => {
+                self.set_precision(*precision)
+            }
+            Message::PaneShowPinPicker if focus_state.is_focused() => self.show_pin_picker(),
+            Message::PaneSetPinned(pinned) if focus_state.is_focused() => self.set_pinned(*pinned),
+            Message::PaneReverse if focus_state.is_focused() => {
+                self.reverse().unwrap_or_enqueue_error()
+            }
+            Message::PaneShowDuplicates if focus_state.is_focused() => {
+                self.show_duplicates().unwrap_or_enqueue_error()
+            }
+            Message::PaneDedup if focus_state.is_focused() => {
+                self.dedup().unwrap_or_enqueue_error()
+            }
+            Message::PaneShowSamplePicker if focus_state.is_focused() => self.show_sample_picker(),
+            Message::PaneSample(spec) if focus_state.is_focused() => {
+                self.sample(*spec).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowHeadPicker if focus_state.is_focused() => {
+                self.show_head_tail_picker(Slice::Head)
+            }
+            Message::PaneShowTailPicker if focus_state.is_focused() => {
+                self.show_head_tail_picker(Slice::Tail)
+            }
+            Message::PaneReset if focus_state.is_focused() => self.reset(),
+            Message::PaneSnapshot if focus_state.is_focused() => {
+                self.snapshot().unwrap_or_enqueue_error()
+            }
+            Message::PaneFilterBookmarks if focus_state.is_focused() => {
+                self.filter_bookmarks().unwrap_or_enqueue_error()
+            }
+            Message::PaneResetNow if focus_state.is_focused() => self.reset_now(),
+            Message::PaneHead(rows) if focus_state.is_focused() => {
+                self.head_tail(Slice::Head, *rows).unwrap_or_enqueue_error()
+            }
+            Message::PaneTail(rows) if focus_state.is_focused() => {
+                self.head_tail(Slice::Tail, *rows).unwrap_or_enqueue_error()
+            }
+            Message::PaneRenameColumns(spec) if focus_state.is_focused() => {
+                self.rename_columns(spec).unwrap_or_enqueue_error()
+            }
+            Message::PaneCastColumn(column, target_type, strict) if focus_state.is_focused() => {
+                self.cast_column(column, *target_type, *strict)
+                    .unwrap_or_enqueue_error()
+            }
+            Message::PaneShowNotationWizard if focus_state.is_focused() => {
+                self.show_notation_wizard()
+            }
+            Message::PaneSetNotation(column, notation) if focus_state.is_focused() => self
+                .set_notation(column, *notation)
+                .unwrap_or_enqueue_error(),
+            Message::PaneToggleZebra if focus_state.is_focused() => self.toggle_zebra(),
+            Message::PaneToggleFitColumns if focus_state.is_focused() => self.toggle_fit_columns(),
+            Message::PaneToggleWrap if focus_state.is_focused() => self.toggle_wrap(),
+            Message::PaneShowMaxWidthPicker if focus_state.is_focused() => {
+                self.show_max_width_picker()
+            }
+            Message::PaneShowWherePicker if focus_state.is_focused() => self.show_where_picker(),
+            Message::PaneSetWhere(predicate) if focus_state.is_focused() => {
+                self.set_where(predicate.clone()).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowRegexSearch if focus_state.is_focused() => {
+                self.show_regex_search().unwrap_or_enqueue_error()
+            }
+            Message::PaneToggleFilterRow if focus_state.is_focused() => {
+                self.toggle_filter_row().unwrap_or_enqueue_error()
+            }
+            Message::PaneShowRenamePicker if focus_state.is_focused() => self.show_rename_picker(),
+            Message::PaneRename(name) if focus_state.is_focused() => self.rename(name.clone()),
+            Message::PaneShowColumnIndexWizard if focus_state.is_focused() => {
+                self.show_column_index_wizard()
+            }
+            Message::PaneShowColumnIndex(column) if focus_state.is_focused() => {
+                self.show_column_index(column).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowColumnStatsWizard if focus_state.is_focused() => {
+                self.show_column_stats_wizard()
+            }
+            Message::PaneShowColumnStats(column) if focus_state.is_focused() => {
+                self.show_column_stats(column).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowValueCountsWizard if focus_state.is_focused() => {
+                self.show_value_counts_wizard()
+            }
+            Message::PaneShowValueCounts(column) if focus_state.is_focused() => {
+                self.show_value_counts(column).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowPivotWizard if focus_state.is_focused() => self.show_pivot_wizard(),
+            Message::PaneWatch if focus_state.is_focused() => {
+                self.watch().unwrap_or_enqueue_error()
+            }
+            Message::PaneUnwatch if focus_state.is_focused() => self.unwatch(),
+            Message::PaneReloaded(name, df) if self.watches(name) => {
+                self.show_reloaded(df.clone()).unwrap_or_enqueue_error()
+            }
+            Message::PanePivot(index, columns, values, agg) if focus_state.is_focused() => self
+                .pivot(index, columns, values, *agg)
+                .unwrap_or_enqueue_error(),
+            Message::PaneSheetNextRow if focus_state.is_focused() => self.move_sheet_row(true),
+            Message::PaneSheetGoTo(row) if focus_state.is_focused() => self.set_sheet_row(*row),
+            Message::PaneSheetPrevRow if focus_state.is_focused() => self.move_sheet_row(false),
+            Message::PaneCopyRow(header) if focus_state.is_focused() => {
+                self.copy_row(*header).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowInlineExportClipboard if focus_state.is_focused() => {
+                self.show_inline_query_picker(QueryType::ExportClipboard)
+            }
+            Message::PaneExportClipboard(format) if focus_state.is_focused() => {
+                self.export_clipboard(*format).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowCopyColumnWizard if focus_state.is_focused() => {
+                self.show_copy_column_wizard()
+            }
+            Message::PaneCopyColumn(column) if focus_state.is_focused() => {
+                self.copy_column(column).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowHideColumnWizard if focus_state.is_focused() => {
+                self.show_hide_column_wizard()
+            }
+            Message::PaneHideColumn(column) if focus_state.is_focused() => {
+                self.hide_column(column).unwrap_or_enqueue_error()
+            }
+            Message::PaneShowShowColumnWizard if focus_state.is_focused() => {
+                self.show_show_column_wizard().unwrap_or_enqueue_error()
+            }
+            Message::PaneShowColumn(column) if focus_state.is_focused() => self.show_column(column),
+            Message::PaneShowAllColumns if focus_state.is_focused() => self.hidden.clear(),
+            Message::PaneShowShadeColumnWizard if focus_state.is_focused() => {
+                self.show_shade_column_wizard().unwrap_or_enqueue_error()
+            }
+            Message::PaneShadeColumn(column) if focus_state.is_focused() => {
+                self.shade_column(column).unwrap_or_enqueue_error()
+            }
+            Message::PaneUnshade if focus_state.is_focused() => self.shade = None,
+            Message::PaneShowGoToColumn if focus_state.is_focused() => {
+                self.modal = Some(Modal::GoToColumn(GoToColumn::default()))
+            }
+            Message::PaneGoToColumn(column) if focus_state.is_focused() => self
+                .tstack
+                .last_mut()
+                .go_to_column(column)
+                .unwrap_or_enqueue_error(),
+            Message::PaneShowCellEditWizard if focus_state.is_focused() => {
+                self.show_cell_edit_wizard()
+            }
+            Message::PaneShowFilterSelectedWizard if focus_state.is_focused() => {
+                self.show_filter_selected_wizard()
+            }
+            Message::PaneFilterSelected(column) if focus_state.is_focused() => {
+                self.filter_selected(column).unwrap_or_enqueue_error()
+            }
+            Message::PaneEditCell(column) if focus_state.is_focused() => {
+                self.show_cell_editor(column).unwrap_or_enqueue_error()
+            }
+            Message::PaneCommitCellEdit(row, column, value) if focus_state.is_focused() => self
+                .tstack
+                .last_mut()
+                .set_cell(*row, column, value)
+                .unwrap_or_enqueue_error(),
             _ => (),
         }
     }
 
     fn tick(&mut self) {
+        if self
+            .watcher
+            .as_mut()
+            .is_some_and(|watcher| watcher.poll(Instant::now()))
+        {
+            self.reload();
+        }
         match &mut self.modal {
             Some(Modal::SearchBar(search_bar)) => {
                 if let Some(df) = search_bar.searcher().latest() {
                     self.tstack.last_mut().set_data_frame(df);
                 }
             }
+            Some(Modal::FilterRow(filter_row)) => {
+                if let Some(df) = filter_row.poll(Instant::now()) {
+                    self.apply_filter_row(df);
+                }
+            }
             Some(Modal::Sheet(_)) => (),
             Some(Modal::DataFrameInfo(_)) => (),
             Some(Modal::ScatterPlot(_)) => (),
+            Some(Modal::LinePlot(_)) => (),
+            Some(Modal::BarPlot(_)) => (),
+            Some(Modal::DensityPlot(_)) => (),
+            Some(Modal::DensityWizard(_)) => (),
+            Some(Modal::BoxPlot(_)) => (),
+            Some(Modal::BoxPlotWizard(_)) => (),
+            Some(Modal::CorrMatrix(_)) => (),
+            Some(Modal::NullMap(_)) => (),
             Some(Modal::HistogramPlot(_)) => (),
             Some(Modal::InlineQueryPicker(_)) => (),
             Some(Modal::GoToLine(_)) => (),
@@ -454,6 +1996,23 @@ impl Component for Pane {
             Some(Modal::ScatterPlotWizard(_)) => (),
             Some(Modal::TableRegisterer(_)) => (),
             Some(Modal::ColumnCasterWizard(_)) => (),
+            Some(Modal::PrecisionPicker(_)) => (),
+            Some(Modal::MaxWidthPicker(_)) => (),
+            Some(Modal::SamplePicker(_)) => (),
+            Some(Modal::HeadTailPicker(_)) => (),
+            Some(Modal::ConfirmPicker(_)) => (),
+            Some(Modal::TimeFormatPicker(_)) => (),
+            Some(Modal::PinPicker(_)) => (),
+            Some(Modal::PivotWizard(_)) => (),
+            Some(Modal::WherePicker(_)) => (),
+            Some(Modal::RenamePicker(_)) => (),
+            Some(Modal::CellEditor(_)) => (),
+            Some(Modal::ColumnList(_)) => (),
+            Some(Modal::GoToColumn(_)) => (),
+            Some(Modal::NotationWizard(_)) => (),
+            Some(Modal::ColumnIndexWizard(_)) => (),
+            Some(Modal::ColumnIndex(_)) => (),
+            Some(Modal::ColumnStats(_)) => (),
             None => (),
         }
     }
@@ -463,8 +2022,17 @@ impl Component for Pane {
 pub enum Modal {
     Sheet(Sheet),
     SearchBar(SearchBar),
+    FilterRow(FilterRow),
     DataFrameInfo(DataFrameInfo),
     ScatterPlot(ScatterPlot),
+    LinePlot(LinePlot),
+    BarPlot(BarPlot),
+    DensityPlot(DensityPlot),
+    DensityWizard(DensityWizard),
+    BoxPlot(BoxPlot),
+    BoxPlotWizard(BoxPlotWizard),
+    CorrMatrix(CorrMatrix),
+    NullMap(NullMap),
     HistogramPlot(HistogramPlot),
     InlineQueryPicker(InlineQueryPicker),
     GoToLine(GoToLine),
@@ -473,6 +2041,23 @@ pub enum Modal {
     ScatterPlotWizard(ScatterPlotWizard),
     TableRegisterer(TableRegisterer),
     ColumnCasterWizard(ColumnCastWizard),
+    PrecisionPicker(PrecisionPicker),
+    MaxWidthPicker(MaxWidthPicker),
+    SamplePicker(SamplePicker),
+    HeadTailPicker(HeadTailPicker),
+    ConfirmPicker(ConfirmPicker),
+    TimeFormatPicker(TimeFormatPicker),
+    PinPicker(PinPicker),
+    PivotWizard(PivotWizard),
+    WherePicker(WherePicker),
+    RenamePicker(RenamePicker),
+    CellEditor(CellEditor),
+    ColumnList(ColumnList),
+    GoToColumn(GoToColumn),
+    NotationWizard(NotationWizard),
+    ColumnIndexWizard(ColumnIndexWizard),
+    ColumnIndex(ColumnIndex),
+    ColumnStats(ColumnStats),
 }
 
 impl Modal {
@@ -480,8 +2065,17 @@ impl Modal {
         match self {
             Modal::Sheet(sheet) => sheet,
             Modal::SearchBar(search_bar) => search_bar,
+            Modal::FilterRow(filter_row) => filter_row,
             Modal::DataFrameInfo(data_frame_info) => data_frame_info,
             Modal::ScatterPlot(scatter_plot_state) => scatter_plot_state,
+            Modal::LinePlot(line_plot) => line_plot,
+            Modal::BarPlot(bar_plot) => bar_plot,
+            Modal::DensityPlot(density_plot) => density_plot,
+            Modal::DensityWizard(wizard) => wizard,
+            Modal::BoxPlot(box_plot) => box_plot,
+            Modal::BoxPlotWizard(wizard) => wizard,
+            Modal::CorrMatrix(corr_matrix) => corr_matrix,
+            Modal::NullMap(null_map) => null_map,
             Modal::HistogramPlot(histogram_plot_state) => histogram_plot_state,
             Modal::InlineQueryPicker(query_picker) => query_picker,
             Modal::GoToLine(go_to_line) => go_to_line,
@@ -490,6 +2084,23 @@ impl Modal {
             Modal::ScatterPlotWizard(wizard) => wizard,
             Modal::TableRegisterer(table_registerer) => table_registerer,
             Modal::ColumnCasterWizard(wizard) => wizard,
+            Modal::PrecisionPicker(precision_picker) => precision_picker,
+            Modal::MaxWidthPicker(picker) => picker,
+            Modal::SamplePicker(picker) => picker,
+            Modal::HeadTailPicker(picker) => picker,
+            Modal::ConfirmPicker(picker) => picker,
+            Modal::TimeFormatPicker(picker) => picker,
+            Modal::PinPicker(pin_picker) => pin_picker,
+            Modal::PivotWizard(wizard) => wizard,
+            Modal::WherePicker(where_picker) => where_picker,
+            Modal::RenamePicker(rename_picker) => rename_picker,
+            Modal::CellEditor(cell_editor) => cell_editor,
+            Modal::ColumnList(column_list) => column_list,
+            Modal::GoToColumn(go_to_column) => go_to_column,
+            Modal::NotationWizard(wizard) => wizard,
+            Modal::ColumnIndexWizard(wizard) => wizard,
+            Modal::ColumnIndex(column_index) => column_index,
+            Modal::ColumnStats(column_stats) => column_stats,
         }
     }
 }
@@ -524,3 +2135,53 @@ fn table(df: DataFrame) -> Table {
         .with_col_space(2)
         .with_extended_column()
 }
+
+/// Reads a watched file again with the reader the table was imported with, or the command line
+/// options for a table read at startup, and re-registers it under the same name. A lazily
+/// scanned table is scanned again and only its preview is collected.
+fn reread(
+    name: &str,
+    path: PathBuf,
+    lazy: bool,
+    reader: Option<Arc<dyn ReadToDataFrames + Send + Sync>>,
+) -> AppResult<DataFrame> {
+    if lazy {
+        let lazy_frame = reader::scan_path(args(), &path)
+            .ok_or(anyhow!("{} can not be scanned", path.display()))??;
+        let df = lazy_frame
+            .clone()
+            .limit(LAZY_PREVIEW_ROWS)
+            .collect_with_engine(Engine::Streaming)?;
+        sql().replace_lazy(name, lazy_frame, Source::File(path))?;
+        return Ok(df);
+    }
+    let frames = match &reader {
+        Some(reader) => reader.named_frames(reader::Source::File(path.clone()))?,
+        None => args()
+            .build_reader(&path)?
+            .named_frames(reader::Source::File(path.clone()))?,
+    };
+    let mut frames = frames.into_vec();
+    let idx = match frames.len() {
+        1 => Some(0),
+        _ => frames.iter().position(|(frame_name, _)| frame_name == name),
+    }
+    .ok_or(anyhow!("Table {name} not found in {}", path.display()))?;
+    let (_, mut df) = frames.swap_remove(idx);
+    let mut sql = sql();
+    match reader {
+        Some(reader) => {
+            sql.replace(name, df.clone(), Source::File(path));
+            sql.set_reader(name, reader);
+        }
+        None => {
+            TypeInferer::from_args(args()).update(&mut df);
+            let preview = Preview::from_args(args(), &path, df.height());
+            sql.replace(name, df.clone(), Source::File(path));
+            if let Some(preview) = preview {
+                sql.set_preview(name, preview);
+            }
+        }
+    }
+    Ok(df)
+}
@@ -120,6 +120,17 @@ impl<T> SearchPicker<T> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Filters the items by the current text right away instead of on the next render, so the
+    /// selection maps to the filtered and reordered items while handling the same key press.
+    fn refresh_filter(&mut self) {
+        self.cached_filter.query(self.input.value(), &self.strings);
+        match self.list.selected() {
+            Some(selected) if selected < self.len() => (),
+            Some(_) => self.list.select(self.len().checked_sub(1)),
+            None => self.list.select((!self.is_empty()).then_some(0)),
+        }
+    }
 }
 
 impl<T> Component for SearchPicker<T> {
@@ -197,27 +208,29 @@ impl<T> Component for SearchPicker<T> {
     }
 
     fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
-        self.input.handle(event)
-            || match (event.code, event.modifiers) {
-                (KeyCode::Up, KeyModifiers::NONE) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
-                    if self.list.selected() != Some(0) {
-                        self.list.select_previous();
-                    } else {
-                        self.list.select(Some(self.len().saturating_sub(1)));
-                    }
-                    true
+        if self.input.handle(event) {
+            self.refresh_filter();
+            return true;
+        }
+        match (event.code, event.modifiers) {
+            (KeyCode::Up, KeyModifiers::NONE) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                if self.list.selected() != Some(0) {
+                    self.list.select_previous();
+                } else {
+                    self.list.select(Some(self.len().saturating_sub(1)));
                 }
-                (KeyCode::Down, KeyModifiers::NONE)
-                | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
-                    if self.list.selected() != Some(self.len().saturating_sub(1)) {
-                        self.list.select_next();
-                    } else {
-                        self.list.select_first();
-                    }
-                    true
+                true
+            }
+            (KeyCode::Down, KeyModifiers::NONE) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                if self.list.selected() != Some(self.len().saturating_sub(1)) {
+                    self.list.select_next();
+                } else {
+                    self.list.select_first();
                 }
-                _ => false,
+                true
             }
+            _ => false,
+        }
     }
 }
 
@@ -274,3 +287,24 @@ fn subsequence_pos(larger: &str, other: &str) -> Option<Vec<usize>> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyEvent;
+
+    use super::*;
+
+    #[test]
+    fn test_selected_after_filter() {
+        let mut picker = SearchPicker::new(vec!["alphabet", "beta", "alpha"]);
+        picker.select(Some(2));
+        for c in "al".chars() {
+            picker.handle(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        // Matches are ordered by length and the selection is clamped to them
+        assert_eq!(picker.len(), 2);
+        assert_eq!(picker.selected_item(), Some(&"alphabet"));
+        picker.handle(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(picker.selected_item(), Some(&"alpha"));
+    }
+}
@@ -64,6 +64,10 @@ impl TextPicker {
     pub fn value(&self) -> &str {
         self.input.value()
     }
+
+    pub fn set_value(&mut self, value: String) {
+        self.input.set_value(value);
+    }
 }
 
 impl Component for TextPicker {
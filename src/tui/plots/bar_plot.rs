@@ -0,0 +1,114 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Margin},
+    widgets::{Clear, Padding, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    handler::message::Message,
+    misc::{config::theme, type_ext::FitToWidth},
+    tui::{component::Component, widgets::block::Block},
+};
+
+/// Horizontal bars of category frequencies, scaled to the largest count.
+#[derive(Debug)]
+pub struct BarPlot {
+    offset: usize,
+    data: Vec<(String, u64)>,
+    max_value: u64,
+}
+
+impl BarPlot {
+    pub fn new(data: Vec<(String, u64)>) -> Self {
+        Self {
+            offset: 0,
+            max_value: data.iter().map(|(_, v)| *v).max().unwrap_or_default(),
+            data,
+        }
+    }
+
+    fn scroll_up(&mut self) {
+        self.offset = self.offset.saturating_sub(1);
+    }
+
+    fn scroll_down(&mut self) {
+        self.offset = self.offset.saturating_add(1);
+    }
+}
+
+impl Component for BarPlot {
+    fn render(
+        &mut self,
+        _area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        _focus_state: crate::tui::component::FocusState,
+    ) {
+        let area = buf.area.inner(Margin::new(7, 3));
+        Widget::render(Clear, area, buf);
+        let area = {
+            let blk = Block::default()
+                .title("Bar Plot")
+                .title_alignment(Alignment::Center)
+                .padding(Padding::horizontal(1));
+            let new_area = blk.inner(area);
+            blk.render(area, buf);
+            new_area
+        };
+
+        self.offset = self
+            .offset
+            .min(self.data.len().saturating_sub(area.height as usize));
+
+        let value_len = self.max_value.to_string().len();
+        let label_len = self
+            .data
+            .iter()
+            .map(|(label, _)| label.width())
+            .max()
+            .unwrap_or_default()
+            .min(area.width as usize / 3);
+        let bar_len = (area.width as usize).saturating_sub(label_len + value_len + 2);
+
+        for (idx, (y, (label, value))) in (area.top()..area.bottom())
+            .zip(self.data.iter().skip(self.offset))
+            .enumerate()
+        {
+            let style = theme().graph(self.offset + idx);
+            let label = label.fit_to_width(label_len);
+            let len = if self.max_value > 0 {
+                (*value as f64 / self.max_value as f64 * bar_len as f64).round() as usize
+            } else {
+                0
+            };
+            let line = format!(
+                "{}{label} {} {value}",
+                " ".repeat(label_len.saturating_sub(label.width())),
+                "█".repeat(len)
+            );
+            buf.set_stringn(area.x, y, line, area.width as usize, style);
+        }
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        match (event.code, event.modifiers) {
+            (KeyCode::Up, KeyModifiers::NONE)
+            | (KeyCode::Char('k'), KeyModifiers::NONE)
+            | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                self.scroll_up();
+                true
+            }
+            (KeyCode::Down, KeyModifiers::NONE)
+            | (KeyCode::Char('j'), KeyModifiers::NONE)
+            | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                self.scroll_down();
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                Message::PaneDismissModal.enqueue();
+                true
+            }
+            _ => false,
+        }
+    }
+}
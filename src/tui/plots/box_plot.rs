@@ -0,0 +1,309 @@
+use anyhow::anyhow;
+use crossterm::event::{KeyCode, KeyModifiers};
+use itertools::Itertools;
+use polars::{
+    frame::DataFrame,
+    prelude::{ChunkQuantile, DataType, QuantileMethod},
+    series::Series,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Margin, Rect},
+    text::Line,
+    widgets::{Clear, Padding, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    AppResult,
+    handler::message::Message,
+    misc::{config::theme, polars_ext::AnyValueExt},
+    tui::{component::Component, widgets::block::Block},
+};
+
+/// Summary of a distribution, whiskers reach the furthest values within 1.5 IQR of the box.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxStats {
+    lower: f64,
+    q1: f64,
+    median: f64,
+    q3: f64,
+    upper: f64,
+    outliers: Vec<f64>,
+}
+
+impl BoxStats {
+    /// Returns None if the series has no non-null values.
+    pub fn from_series(series: &Series) -> AppResult<Option<Self>> {
+        let series = series.cast(&DataType::Float64)?;
+        let values = series.f64()?;
+        let quantile = |q| values.quantile(q, QuantileMethod::Linear);
+        let (Some(q1), Some(median), Some(q3)) = (quantile(0.25)?, quantile(0.5)?, quantile(0.75)?)
+        else {
+            return Ok(None);
+        };
+        let iqr = q3 - q1;
+        let (low_fence, high_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        let (inside, outliers): (Vec<_>, Vec<_>) = values
+            .into_iter()
+            .flatten()
+            .filter(|v| !v.is_nan())
+            .partition(|v| (low_fence..=high_fence).contains(v));
+        let (lower, upper) = inside
+            .iter()
+            .copied()
+            .minmax_by(f64::total_cmp)
+            .into_option()
+            .unwrap_or((q1, q3));
+        Ok(Some(Self {
+            lower,
+            q1,
+            median,
+            q3,
+            upper,
+            outliers,
+        }))
+    }
+
+    fn bounds(&self) -> [f64; 2] {
+        self.outliers
+            .iter()
+            .fold([self.lower, self.upper], |[min, max], v| {
+                [min.min(*v), max.max(*v)]
+            })
+    }
+}
+
+#[derive(Debug)]
+pub struct BoxPlot {
+    column: String,
+    boxes: Vec<(String, BoxStats)>,
+    skipped: Vec<String>,
+    bounds: [f64; 2],
+    offset: usize,
+}
+
+impl BoxPlot {
+    pub fn new(df: &DataFrame, column: &str, group_by: Option<&str>) -> AppResult<Self> {
+        let groups = if let Some(group_by) = group_by {
+            df.partition_by(vec![group_by], true)?
+                .into_iter()
+                .map(|df| {
+                    let name = df
+                        .column(group_by)
+                        .and_then(|column| column.get(0))
+                        .map(AnyValueExt::into_single_line)
+                        .unwrap_or("null".to_owned());
+                    df.column(column)
+                        .map(|col| (name, col.as_materialized_series().clone()))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .sorted_by(|(a, _), (b, _)| a.cmp(b))
+                .collect_vec()
+        } else {
+            vec![(
+                column.to_owned(),
+                df.column(column)?.as_materialized_series().clone(),
+            )]
+        };
+
+        let mut boxes = Vec::new();
+        let mut skipped = Vec::new();
+        for (name, series) in groups {
+            match BoxStats::from_series(&series)? {
+                Some(stats) => boxes.push((name, stats)),
+                None => skipped.push(name),
+            }
+        }
+        let bounds = boxes
+            .iter()
+            .map(|(_, stats)| stats.bounds())
+            .reduce(|[min1, max1], [min2, max2]| [min1.min(min2), max1.max(max2)])
+            .ok_or(anyhow!("No values to plot in column '{column}'"))?;
+
+        Ok(Self {
+            column: column.to_owned(),
+            boxes,
+            skipped,
+            bounds,
+            offset: 0,
+        })
+    }
+
+    fn scroll_up(&mut self) {
+        self.offset = self.offset.saturating_sub(1);
+    }
+
+    fn scroll_down(&mut self) {
+        self.offset = self.offset.saturating_add(1);
+    }
+
+    fn footer(&self) -> Option<Line<'static>> {
+        (!self.skipped.is_empty()).then(|| {
+            Line::styled(
+                format!(" Skipped empty groups: {} ", self.skipped.join(", ")),
+                theme().text(),
+            )
+        })
+    }
+}
+
+impl Component for BoxPlot {
+    fn render(
+        &mut self,
+        _area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        _focus_state: crate::tui::component::FocusState,
+    ) {
+        let area = buf.area.inner(Margin::new(7, 3));
+        Widget::render(Clear, area, buf);
+        let area = {
+            let mut blk = Block::default()
+                .title("Box Plot")
+                .title_alignment(Alignment::Center)
+                .padding(Padding::new(1, 2, 0, 0));
+            if let Some(footer) = self.footer() {
+                blk = blk.bottom(footer);
+            }
+            let new_area = blk.inner(area);
+            blk.render(area, buf);
+            new_area
+        };
+        if area.height < 2 {
+            return;
+        }
+
+        let label_len = self
+            .boxes
+            .iter()
+            .map(|(name, _)| name.width())
+            .max()
+            .unwrap_or_default()
+            .min(24) as u16;
+        let plot_area = Rect {
+            x: area.x + label_len + 1,
+            width: area.width.saturating_sub(label_len + 1),
+            height: area.height - 1,
+            ..area
+        };
+
+        // Each box takes one line and is followed by a blank line to keep them apart.
+        let visible = (plot_area.height as usize).div_ceil(2);
+        self.offset = self.offset.min(self.boxes.len().saturating_sub(visible));
+        for (idx, (name, stats)) in self
+            .boxes
+            .iter()
+            .enumerate()
+            .skip(self.offset)
+            .take(visible)
+        {
+            let y = plot_area.y + 2 * (idx - self.offset) as u16;
+            let label = name.chars().take(label_len as usize).collect::<String>();
+            buf.set_stringn(
+                area.x,
+                y,
+                format!("{label:>width$}", width = label_len as usize),
+                label_len as usize,
+                theme().graph(idx),
+            );
+            render_box(
+                stats,
+                self.bounds,
+                plot_area.x,
+                plot_area.width,
+                y,
+                idx,
+                buf,
+            );
+        }
+
+        let axis_y = plot_area.bottom();
+        let [min, max] = self.bounds.map(|f| format!("{f:.2}"));
+        buf.set_stringn(
+            plot_area.x,
+            axis_y,
+            &min,
+            plot_area.width as usize,
+            theme().text(),
+        );
+        let max_x = plot_area.right().saturating_sub(max.width() as u16);
+        if max_x > plot_area.x + min.width() as u16 {
+            buf.set_string(max_x, axis_y, &max, theme().text());
+        }
+        let title_x =
+            plot_area.x + (plot_area.width.saturating_sub(self.column.width() as u16)) / 2;
+        if title_x > plot_area.x + min.width() as u16
+            && title_x + (self.column.width() as u16) < max_x
+        {
+            buf.set_string(title_x, axis_y, &self.column, theme().text());
+        }
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        match (event.code, event.modifiers) {
+            (KeyCode::Up, KeyModifiers::NONE)
+            | (KeyCode::Char('k'), KeyModifiers::NONE)
+            | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                self.scroll_up();
+                true
+            }
+            (KeyCode::Down, KeyModifiers::NONE)
+            | (KeyCode::Char('j'), KeyModifiers::NONE)
+            | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                self.scroll_down();
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                Message::PaneDismissModal.enqueue();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+fn render_box(
+    stats: &BoxStats,
+    [min, max]: [f64; 2],
+    x: u16,
+    width: u16,
+    y: u16,
+    idx: usize,
+    buf: &mut Buffer,
+) {
+    if width == 0 {
+        return;
+    }
+    let span = max - min;
+    let column = |value: f64| {
+        let ratio = if span > 0.0 {
+            (value - min) / span
+        } else {
+            0.5
+        };
+        x + (ratio * (width - 1) as f64).round() as u16
+    };
+    let style = theme().graph(idx);
+    let (lower, q1, median, q3, upper) = (
+        column(stats.lower),
+        column(stats.q1),
+        column(stats.median),
+        column(stats.q3),
+        column(stats.upper),
+    );
+    for cx in lower..=upper {
+        buf[(cx, y)].set_symbol("─").set_style(style);
+    }
+    for cx in q1..=q3 {
+        buf[(cx, y)].set_symbol("█").set_style(style);
+    }
+    buf[(lower, y)].set_symbol("├").set_style(style);
+    buf[(upper, y)].set_symbol("┤").set_style(style);
+    buf[(median, y)]
+        .set_symbol("┃")
+        .set_style(theme().text_highlighted());
+    for outlier in stats.outliers.iter() {
+        buf[(column(*outlier), y)].set_symbol("•").set_style(style);
+    }
+}
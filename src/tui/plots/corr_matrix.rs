@@ -0,0 +1,189 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Margin},
+    style::{Color, Style},
+    widgets::{Clear, Padding, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    handler::message::Message,
+    misc::{config::theme, type_ext::FitToWidth},
+    tui::{component::Component, widgets::block::Block},
+};
+
+const CELL_WIDTH: usize = 7;
+
+/// Grid of pairwise Pearson coefficients, each cell shaded from the theme's negative (-1) to
+/// positive (+1) color through the background at zero.
+#[derive(Debug)]
+pub struct CorrMatrix {
+    names: Vec<String>,
+    matrix: Vec<Vec<f64>>,
+    row_offset: usize,
+    col_offset: usize,
+}
+
+impl CorrMatrix {
+    pub fn new((names, matrix): (Vec<String>, Vec<Vec<f64>>)) -> Self {
+        Self {
+            names,
+            matrix,
+            row_offset: 0,
+            col_offset: 0,
+        }
+    }
+
+    fn scroll_up(&mut self) {
+        self.row_offset = self.row_offset.saturating_sub(1);
+    }
+
+    fn scroll_down(&mut self) {
+        self.row_offset = self.row_offset.saturating_add(1);
+    }
+
+    fn scroll_left(&mut self) {
+        self.col_offset = self.col_offset.saturating_sub(1);
+    }
+
+    fn scroll_right(&mut self) {
+        self.col_offset = self.col_offset.saturating_add(1);
+    }
+}
+
+impl Component for CorrMatrix {
+    fn render(
+        &mut self,
+        _area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        _focus_state: crate::tui::component::FocusState,
+    ) {
+        let area = buf.area.inner(Margin::new(7, 3));
+        Widget::render(Clear, area, buf);
+        let area = {
+            let blk = Block::default()
+                .title("Correlation Matrix")
+                .title_alignment(Alignment::Center)
+                .padding(Padding::horizontal(1));
+            let new_area = blk.inner(area);
+            blk.render(area, buf);
+            new_area
+        };
+        if area.height < 2 {
+            return;
+        }
+
+        let label_len = self
+            .names
+            .iter()
+            .map(|name| name.width())
+            .max()
+            .unwrap_or_default()
+            .min(16);
+        let rows = area.height as usize - 1;
+        let cols = (area.width as usize).saturating_sub(label_len + 1) / CELL_WIDTH;
+        self.row_offset = self.row_offset.min(self.names.len().saturating_sub(rows));
+        self.col_offset = self.col_offset.min(self.names.len().saturating_sub(cols));
+        let grid_x = area.x + label_len as u16 + 1;
+
+        for (idx, name) in self
+            .names
+            .iter()
+            .enumerate()
+            .skip(self.col_offset)
+            .take(cols)
+        {
+            let x = grid_x + ((idx - self.col_offset) * CELL_WIDTH) as u16;
+            let name = name.fit_to_width(CELL_WIDTH - 1);
+            buf.set_string(
+                x,
+                area.y,
+                format!("{name:>width$} ", width = CELL_WIDTH - 1),
+                theme().header(idx),
+            );
+        }
+
+        let [negative, positive] = [0, 3].map(|idx| theme().graph(idx).fg.unwrap_or_default());
+        let (background, foreground) = {
+            let text = theme().text();
+            (text.bg.unwrap_or_default(), text.fg.unwrap_or_default())
+        };
+        for (idx, (name, values)) in self
+            .names
+            .iter()
+            .zip(self.matrix.iter())
+            .enumerate()
+            .skip(self.row_offset)
+            .take(rows)
+        {
+            let y = area.y + 1 + (idx - self.row_offset) as u16;
+            let name = name.fit_to_width(label_len);
+            buf.set_string(
+                area.x,
+                y,
+                format!("{name:>width$}", width = label_len),
+                theme().header(idx),
+            );
+            for (col, value) in values.iter().enumerate().skip(self.col_offset).take(cols) {
+                let x = grid_x + ((col - self.col_offset) * CELL_WIDTH) as u16;
+                let strength = if value.is_nan() { 0.0 } else { value.abs() };
+                let target = if *value < 0.0 { negative } else { positive };
+                let style = Style::default().bg(blend(background, target, strength)).fg(
+                    if strength > 0.5 {
+                        background
+                    } else {
+                        foreground
+                    },
+                );
+                buf.set_string(
+                    x,
+                    y,
+                    format!("{value:>width$.2} ", width = CELL_WIDTH - 1),
+                    style,
+                );
+            }
+        }
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        match (event.code, event.modifiers) {
+            (KeyCode::Up, KeyModifiers::NONE)
+            | (KeyCode::Char('k'), KeyModifiers::NONE)
+            | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                self.scroll_up();
+                true
+            }
+            (KeyCode::Down, KeyModifiers::NONE)
+            | (KeyCode::Char('j'), KeyModifiers::NONE)
+            | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                self.scroll_down();
+                true
+            }
+            (KeyCode::Left, KeyModifiers::NONE) | (KeyCode::Char('h'), KeyModifiers::NONE) => {
+                self.scroll_left();
+                true
+            }
+            (KeyCode::Right, KeyModifiers::NONE) | (KeyCode::Char('l'), KeyModifiers::NONE) => {
+                self.scroll_right();
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                Message::PaneDismissModal.enqueue();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Mixes two colors by the ratio, colors without RGB values switch over halfway instead.
+pub fn blend(from: Color, to: Color, ratio: f64) -> Color {
+    match (from, to) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+            let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * ratio).round() as u8;
+            Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+        }
+        _ if ratio > 0.5 => to,
+        _ => from,
+    }
+}
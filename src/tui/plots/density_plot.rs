@@ -0,0 +1,38 @@
+use crate::{
+    AppResult,
+    misc::jagged_vec::JaggedVec,
+    tui::{component::Component, plots::scatter_plot::ScatterPlot},
+};
+
+/// Kernel density estimate of a column, drawn as a single curve.
+#[derive(Debug)]
+pub struct DensityPlot {
+    plot: ScatterPlot,
+}
+
+impl DensityPlot {
+    pub fn new(column: String, bandwidth: f64, data: Vec<(f64, f64)>) -> AppResult<Self> {
+        let mut curve = JaggedVec::new();
+        curve.push(data);
+        Ok(Self {
+            plot: ScatterPlot::new(column, format!("density (bandwidth {bandwidth:.3})"), curve)?
+                .with_lines()
+                .with_title("Density Plot"),
+        })
+    }
+}
+
+impl Component for DensityPlot {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.plot.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.plot.handle(event)
+    }
+}
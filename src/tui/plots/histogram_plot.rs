@@ -16,19 +16,30 @@ use crate::{
 #[derive(Debug)]
 pub struct HistogramPlot {
     offset: usize,
+    data: Vec<(String, u64)>,
     bars: Vec<Bar<'static>>,
     max_value: u64,
+    log: bool,
 }
 
 impl HistogramPlot {
     pub fn new(data: Vec<(String, u64)>) -> Self {
         Self {
             offset: 0,
-            max_value: data.first().map(|(_, v)| *v).unwrap_or_default(),
-            bars: bars_from_data(data),
+            max_value: data.iter().map(|(_, v)| *v).max().unwrap_or_default(),
+            bars: bars_from_data(&data, false),
+            data,
+            log: false,
         }
     }
 
+    /// Toggles the log scale of the counts, bars are drawn by log10(1 + count) so empty buckets
+    /// keep an empty bar.
+    fn toggle_log(&mut self) {
+        self.log = !self.log;
+        self.bars = bars_from_data(&self.data, self.log);
+    }
+
     fn scroll_up(&mut self) {
         self.offset = self.offset.saturating_sub(1);
     }
@@ -48,9 +59,15 @@ impl Component for HistogramPlot {
         let area = buf.area.inner(Margin::new(7, 3));
         Widget::render(Clear, area, buf);
         let area = {
-            let blk = Block::default()
+            let mut blk = Block::default()
                 .title("Histogram Plot")
                 .title_alignment(Alignment::Center);
+            if self.log {
+                blk = blk.bottom(Line::styled(
+                    " log scale, bar length is log10(1 + count) ",
+                    theme().text(),
+                ));
+            }
             let new_area = blk.inner(area);
             blk.render(area, buf);
             new_area
@@ -68,7 +85,7 @@ impl Component for HistogramPlot {
         let chart = BarChart::default()
             .style(theme().text())
             .bar_width(1)
-            .max(self.max_value)
+            .max(bar_length(self.max_value, self.log))
             .direction(Direction::Horizontal)
             .bar_gap(0)
             .data(BarGroup::default().bars(&self.bars[self.offset..end]));
@@ -89,6 +106,10 @@ impl Component for HistogramPlot {
                 self.scroll_down();
                 true
             }
+            (KeyCode::Char('y'), KeyModifiers::NONE) => {
+                self.toggle_log();
+                true
+            }
             (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
                 Message::PaneDismissModal.enqueue();
                 true
@@ -98,7 +119,16 @@ impl Component for HistogramPlot {
     }
 }
 
-fn bars_from_data(data: Vec<(String, u64)>) -> Vec<Bar<'static>> {
+/// Log scaled lengths are kept in thousandths since bars only take integers.
+fn bar_length(value: u64, log: bool) -> u64 {
+    if log {
+        ((value as f64).ln_1p() / std::f64::consts::LN_10 * 1000.0).round() as u64
+    } else {
+        value
+    }
+}
+
+fn bars_from_data(data: &[(String, u64)], log: bool) -> Vec<Bar<'static>> {
     let label_len = data
         .iter()
         .map(|(l, _)| l.trim().width())
@@ -115,7 +145,7 @@ fn bars_from_data(data: Vec<(String, u64)>) -> Vec<Bar<'static>> {
         .map(|(idx, (label, value))| {
             let label = label.trim().chars().take(label_len).collect::<String>();
             Bar::default()
-                .value(*value)
+                .value(bar_length(*value, log))
                 .text_value(format!("{value:>value_len$} "))
                 .label(Line::styled(
                     format!("{label:>label_len$}"),
@@ -0,0 +1,40 @@
+use crate::{
+    AppResult,
+    misc::jagged_vec::JaggedVec,
+    tui::{component::Component, plots::scatter_plot::ScatterPlot},
+};
+
+/// Scatter plot with the points of each group connected in the order of the x axis.
+#[derive(Debug)]
+pub struct LinePlot {
+    plot: ScatterPlot,
+}
+
+impl LinePlot {
+    pub fn new(x_label: String, y_label: String, data: JaggedVec<(f64, f64)>) -> AppResult<Self> {
+        Ok(Self {
+            plot: ScatterPlot::new(x_label, y_label, data)?.with_lines(),
+        })
+    }
+
+    pub fn with_groups(self, groups: impl Into<Option<Vec<String>>>) -> Self {
+        Self {
+            plot: self.plot.with_groups(groups),
+        }
+    }
+}
+
+impl Component for LinePlot {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.plot.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.plot.handle(event)
+    }
+}
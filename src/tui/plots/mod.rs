@@ -1,2 +1,8 @@
+pub mod bar_plot;
+pub mod box_plot;
+pub mod corr_matrix;
+pub mod density_plot;
 pub mod histogram_plot;
+pub mod line_plot;
+pub mod null_map;
 pub mod scatter_plot;
@@ -0,0 +1,183 @@
+use std::ops::Range;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use polars::frame::DataFrame;
+use ratatui::{
+    layout::{Alignment, Margin},
+    style::Style,
+    text::Line,
+    widgets::{Clear, Padding, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    handler::message::Message,
+    misc::{config::theme, polars_ext::DataFrameExt, type_ext::FitToWidth},
+    tui::{component::Component, plots::corr_matrix::blend, widgets::block::Block},
+};
+
+const CELL_WIDTH: usize = 7;
+
+/// Grid of null shares with one column per table column and one line per bucket of rows, the
+/// rows are split into as many buckets as there are lines available.
+#[derive(Debug)]
+pub struct NullMap {
+    df: DataFrame,
+    buckets: Vec<(Range<usize>, Vec<f64>)>,
+    col_offset: usize,
+}
+
+impl NullMap {
+    pub fn new(df: DataFrame) -> Self {
+        Self {
+            df,
+            buckets: Vec::new(),
+            col_offset: 0,
+        }
+    }
+
+    fn scroll_left(&mut self) {
+        self.col_offset = self.col_offset.saturating_sub(1);
+    }
+
+    fn scroll_right(&mut self) {
+        self.col_offset = self.col_offset.saturating_add(1);
+    }
+
+    /// Recomputes the buckets only when the number of lines changes.
+    fn update_buckets(&mut self, lines: usize) {
+        let expected = lines.min(self.df.height());
+        if self.buckets.len() != expected {
+            self.buckets = self.df.null_map_data(lines);
+        }
+    }
+
+    fn footer(&self) -> Line<'static> {
+        let nulls = self
+            .df
+            .get_columns()
+            .iter()
+            .map(|column| column.null_count())
+            .sum::<usize>();
+        let rows = self
+            .buckets
+            .iter()
+            .map(|(range, _)| range.len())
+            .max()
+            .unwrap_or_default();
+        Line::styled(
+            format!(" {nulls} nulls, each line covers up to {rows} rows "),
+            theme().text(),
+        )
+    }
+}
+
+impl Component for NullMap {
+    fn render(
+        &mut self,
+        _area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        _focus_state: crate::tui::component::FocusState,
+    ) {
+        let area = buf.area.inner(Margin::new(7, 3));
+        Widget::render(Clear, area, buf);
+        let area = {
+            let blk = Block::default()
+                .title("Null Map")
+                .title_alignment(Alignment::Center)
+                .padding(Padding::horizontal(1));
+            let new_area = blk.inner(area);
+            // The header takes the first line, every other line shows one bucket
+            self.update_buckets(new_area.height.saturating_sub(1) as usize);
+            blk.bottom(self.footer()).render(area, buf);
+            new_area
+        };
+        if area.height < 2 {
+            return;
+        }
+
+        let label = |range: &Range<usize>| format!("{}-{}", range.start + 1, range.end);
+        let label_len = self
+            .buckets
+            .iter()
+            .map(|(range, _)| label(range).width())
+            .max()
+            .unwrap_or_default();
+        let names = self.df.get_column_names();
+        let cols = (area.width as usize).saturating_sub(label_len + 1) / CELL_WIDTH;
+        self.col_offset = self.col_offset.min(names.len().saturating_sub(cols));
+        let grid_x = area.x + label_len as u16 + 1;
+
+        for (idx, name) in names.iter().enumerate().skip(self.col_offset).take(cols) {
+            let x = grid_x + ((idx - self.col_offset) * CELL_WIDTH) as u16;
+            let name = name.as_str().fit_to_width(CELL_WIDTH - 1);
+            buf.set_string(
+                x,
+                area.y,
+                format!("{name:>width$} ", width = CELL_WIDTH - 1),
+                theme().header(idx),
+            );
+        }
+
+        let target = theme().graph(0).fg.unwrap_or_default();
+        let (background, foreground) = {
+            let text = theme().text();
+            (text.bg.unwrap_or_default(), text.fg.unwrap_or_default())
+        };
+        for (line, (range, fractions)) in self.buckets.iter().enumerate() {
+            let y = area.y + 1 + line as u16;
+            buf.set_string(
+                area.x,
+                y,
+                format!("{:>width$}", label(range), width = label_len),
+                theme().text(),
+            );
+            for (col, fraction) in fractions
+                .iter()
+                .enumerate()
+                .skip(self.col_offset)
+                .take(cols)
+            {
+                let x = grid_x + ((col - self.col_offset) * CELL_WIDTH) as u16;
+                let style = Style::default()
+                    .bg(blend(background, target, *fraction))
+                    .fg(if *fraction > 0.5 {
+                        background
+                    } else {
+                        foreground
+                    });
+                let text = if *fraction == 0.0 {
+                    String::new()
+                } else if *fraction < 0.01 {
+                    "<1%".to_owned()
+                } else {
+                    format!("{:.0}%", fraction * 100.0)
+                };
+                buf.set_string(
+                    x,
+                    y,
+                    format!("{text:>width$} ", width = CELL_WIDTH - 1),
+                    style,
+                );
+            }
+        }
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        match (event.code, event.modifiers) {
+            (KeyCode::Left, KeyModifiers::NONE) | (KeyCode::Char('h'), KeyModifiers::NONE) => {
+                self.scroll_left();
+                true
+            }
+            (KeyCode::Right, KeyModifiers::NONE) | (KeyCode::Char('l'), KeyModifiers::NONE) => {
+                self.scroll_right();
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                Message::PaneDismissModal.enqueue();
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -4,7 +4,7 @@ use itertools::Itertools;
 use ratatui::{
     layout::{Alignment, Constraint, Margin},
     symbols::Marker,
-    text::Span,
+    text::{Line, Span},
     widgets::{Axis, Chart, Clear, Dataset, GraphType, LegendPosition, Padding, Widget},
 };
 
@@ -23,21 +23,91 @@ pub struct ScatterPlot {
     x_label: String,
     y_label: String,
     groups: Option<Vec<String>>,
+    order: Vec<Vec<usize>>,
+    cursor: Option<(usize, usize)>,
+    graph_type: GraphType,
+    title: &'static str,
+    log_x: bool,
+    log_y: bool,
+    /// Data with log scaled axes applied, None if both axes are linear.
+    scaled: Option<JaggedVec<(f64, f64)>>,
+    hidden: usize,
+    /// Least squares fit of each group, None for groups without one.
+    fits: Vec<Option<LinearFit>>,
+    show_fits: bool,
+}
+
+/// Ordinary least squares line through a set of points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LinearFit {
+    slope: f64,
+    intercept: f64,
+    r_squared: f64,
+    x_range: [f64; 2],
+}
+
+impl LinearFit {
+    /// Returns None for less than two points or if all of them share the same x.
+    fn new(points: &[(f64, f64)]) -> Option<Self> {
+        if points.len() < 2 {
+            return None;
+        }
+        let n = points.len() as f64;
+        let (mean_x, mean_y) = points
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x / n, sy + y / n));
+        let (sxx, sxy, syy) = points
+            .iter()
+            .fold((0.0, 0.0, 0.0), |(sxx, sxy, syy), (x, y)| {
+                let (dx, dy) = (x - mean_x, y - mean_y);
+                (sxx + dx * dx, sxy + dx * dy, syy + dy * dy)
+            });
+        if sxx <= 0.0 {
+            return None;
+        }
+        let slope = sxy / sxx;
+        let r_squared = if syy > 0.0 {
+            sxy * sxy / (sxx * syy)
+        } else {
+            1.0
+        };
+        let x_range = points
+            .iter()
+            .map(|(x, _)| *x)
+            .minmax_by(f64::total_cmp)
+            .into_option()
+            .map(|(min, max)| [min, max])?;
+        Some(Self {
+            slope,
+            intercept: mean_y - slope * mean_x,
+            r_squared,
+            x_range,
+        })
+    }
+
+    /// Evenly spaced points of the line across the x range of the fitted points.
+    fn points(&self, count: usize) -> impl Iterator<Item = (f64, f64)> {
+        let [min, max] = self.x_range;
+        let step = (max - min) / count.saturating_sub(1).max(1) as f64;
+        (0..count).map(move |idx| {
+            let x = min + step * idx as f64;
+            (x, self.slope * x + self.intercept)
+        })
+    }
 }
 
 impl ScatterPlot {
     pub fn new(x_label: String, y_label: String, data: JaggedVec<(f64, f64)>) -> AppResult<Self> {
-        let [x_bounds, y_bounds] = data
+        let [x_bounds, y_bounds] = bounds(&data).ok_or(anyhow!("Empty dimension"))?;
+        let fits = data.iter().map(LinearFit::new).collect();
+        let order = data
             .iter()
-            .flat_map(|v| v.iter())
-            .fold(None, |bounds, p| {
-                let bounds = bounds.unwrap_or([[p.0, p.0], [p.1, p.1]]);
-                Some([
-                    [bounds[0][0].min(p.0), bounds[0][1].max(p.0)],
-                    [bounds[1][0].min(p.1), bounds[1][1].max(p.1)],
-                ])
+            .map(|points| {
+                (0..points.len())
+                    .sorted_by(|a, b| points[*a].0.total_cmp(&points[*b].0))
+                    .collect_vec()
             })
-            .ok_or(anyhow!("Empty dimension"))?;
+            .collect_vec();
         Ok(Self {
             data,
             x_bounds,
@@ -45,15 +115,159 @@ impl ScatterPlot {
             x_label,
             y_label,
             groups: None,
+            order,
+            cursor: None,
+            graph_type: GraphType::Scatter,
+            title: "Scatter Plot",
+            log_x: false,
+            log_y: false,
+            scaled: None,
+            hidden: 0,
+            fits,
+            show_fits: false,
         })
     }
 
+    /// Position of a point on the plot, None if it can not be shown on a log scaled axis.
+    fn scale(&self, (x, y): (f64, f64)) -> Option<(f64, f64)> {
+        let scale = |value: f64, log: bool| match log {
+            true if value > 0.0 => Some(value.log10()),
+            true => None,
+            false => Some(value),
+        };
+        Some((scale(x, self.log_x)?, scale(y, self.log_y)?))
+    }
+
+    /// Toggles the log scale of the x or y axis, points that are not positive on a log scaled
+    /// axis are hidden and counted in the footer.
+    fn toggle_log(&mut self, x_axis: bool) {
+        if x_axis {
+            self.log_x = !self.log_x;
+        } else {
+            self.log_y = !self.log_y;
+        }
+        self.scaled = (self.log_x || self.log_y).then(|| {
+            self.data
+                .iter()
+                .map(|points| points.iter().filter_map(|p| self.scale(*p)).collect_vec())
+                .fold(JaggedVec::new(), |mut data, points| {
+                    data.push(points);
+                    data
+                })
+        });
+        let data = self.scaled.as_ref().unwrap_or(&self.data);
+        self.hidden = self.data.iter().map(<[_]>::len).sum::<usize>()
+            - data.iter().map(<[_]>::len).sum::<usize>();
+        [self.x_bounds, self.y_bounds] = bounds(data).unwrap_or([[0.0, 1.0], [0.0, 1.0]]);
+    }
+
+    fn hovered(&self) -> Option<(usize, (f64, f64))> {
+        let (group, pos) = self.cursor?;
+        let idx = *self.order.get(group)?.get(pos)?;
+        Some((group, *self.data.get(group)?.get(idx)?))
+    }
+
+    fn move_cursor(&mut self, forward: bool) {
+        self.cursor = match self.cursor {
+            Some((group, pos)) => {
+                let len = self.order[group].len();
+                Some((
+                    group,
+                    if forward {
+                        (pos + 1).min(len.saturating_sub(1))
+                    } else {
+                        pos.saturating_sub(1)
+                    },
+                ))
+            }
+            None => self
+                .order
+                .iter()
+                .position(|o| !o.is_empty())
+                .map(|g| (g, 0)),
+        }
+    }
+
+    fn cycle_group(&mut self, forward: bool) {
+        let count = self.order.len();
+        if let Some((group, pos)) = self.cursor
+            && count > 0
+        {
+            let mut next = group;
+            for _ in 0..count {
+                next = if forward {
+                    (next + 1) % count
+                } else {
+                    (next + count - 1) % count
+                };
+                if !self.order[next].is_empty() {
+                    break;
+                }
+            }
+            self.cursor = Some((next, pos.min(self.order[next].len().saturating_sub(1))));
+        } else {
+            self.move_cursor(forward);
+        }
+    }
+
+    fn footer(&self) -> Option<Line<'static>> {
+        let mut footer = String::new();
+        if let Some((group, (x, y))) = self.hovered() {
+            footer.push_str(&format!(" {}: {x}, {}: {y} ", self.x_label, self.y_label));
+            if let Some(name) = self.groups.as_ref().and_then(|g| g.get(group)) {
+                footer.push_str(&format!("group: {name} "));
+            }
+        }
+        if self.scaled.is_some() {
+            let axes = [(self.log_x, "x"), (self.log_y, "y")]
+                .into_iter()
+                .filter_map(|(log, axis)| log.then_some(axis))
+                .join(", ");
+            if !footer.is_empty() {
+                footer.push('|');
+            }
+            footer.push_str(&format!(" log {axes} "));
+            if self.hidden > 0 {
+                footer.push_str(&format!("({} non-positive points hidden) ", self.hidden));
+            }
+        }
+        (!footer.is_empty()).then(|| Line::styled(footer, theme().text()))
+    }
+
+    /// Sorts the points of each group by x and connects consecutive points with lines.
+    pub fn with_lines(self) -> Self {
+        let data = self
+            .order
+            .iter()
+            .zip(self.data.iter())
+            .map(|(order, points)| order.iter().map(|idx| points[*idx]).collect_vec())
+            .fold(JaggedVec::new(), |mut data, points| {
+                data.push(points);
+                data
+            });
+        let order = data
+            .iter()
+            .map(|points| (0..points.len()).collect())
+            .collect();
+        Self {
+            data,
+            order,
+            graph_type: GraphType::Line,
+            title: "Line Plot",
+            ..self
+        }
+    }
+
     pub fn with_groups(self, groups: impl Into<Option<Vec<String>>>) -> Self {
         Self {
             groups: groups.into(),
             ..self
         }
     }
+
+    pub fn with_title(self, title: &'static str) -> Self {
+        Self { title, ..self }
+    }
 }
 
 impl Component for ScatterPlot {
@@ -66,13 +280,18 @@ impl Component for ScatterPlot {
         let area = buf.area.inner(Margin::new(7, 3));
         Widget::render(Clear, area, buf);
         let ds = self
-            .data
+            .scaled
+            .as_ref()
+            .unwrap_or(&self.data)
             .iter()
             .enumerate()
             .map(|(i, v)| {
                 let ds = Dataset::default()
-                    .marker(Marker::Dot)
-                    .graph_type(GraphType::Scatter)
+                    .marker(match self.graph_type {
+                        GraphType::Line => Marker::Braille,
+                        _ => Marker::Dot,
+                    })
+                    .graph_type(self.graph_type)
                     .style(theme().graph(i))
                     .data(v);
                 if let Some(g) = &self.groups {
@@ -83,35 +302,94 @@ impl Component for ScatterPlot {
             })
             .collect_vec();
 
+        let hovered = self
+            .hovered()
+            .and_then(|(_, point)| self.scale(point))
+            .map(|point| [point]);
+        let ds = if let Some(hovered) = hovered.as_ref() {
+            ds.into_iter()
+                .chain(std::iter::once(
+                    Dataset::default()
+                        .marker(Marker::Block)
+                        .graph_type(GraphType::Scatter)
+                        .style(theme().text_highlighted())
+                        .data(hovered),
+                ))
+                .collect_vec()
+        } else {
+            ds
+        };
+
+        // Sampled instead of two end points so lines stay true on log scaled axes.
+        let fits = self
+            .fits
+            .iter()
+            .enumerate()
+            .filter(|_| self.show_fits)
+            .filter_map(|(idx, fit)| Some((idx, fit.as_ref()?)))
+            .map(|(idx, fit)| {
+                let points = fit
+                    .points(FIT_POINTS)
+                    .filter_map(|p| self.scale(p))
+                    .collect_vec();
+                let name = match self.groups.as_ref().and_then(|g| g.get(idx)) {
+                    Some(group) => {
+                        format!("{group} slope {:.3}, R² {:.3}", fit.slope, fit.r_squared)
+                    }
+                    None => format!("slope {:.3}, R² {:.3}", fit.slope, fit.r_squared),
+                };
+                (idx, name, points)
+            })
+            .collect_vec();
+        let ds = ds
+            .into_iter()
+            .chain(fits.iter().map(|(idx, name, points)| {
+                Dataset::default()
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(theme().graph(*idx))
+                    .name(name.as_str())
+                    .data(points)
+            }))
+            .collect_vec();
+
+        let mut block = Block::default()
+            .title(self.title)
+            .title_alignment(Alignment::Center)
+            .padding(Padding::new(1, 2, 0, 0));
+        if let Some(footer) = self.footer() {
+            block = block.bottom(footer);
+        }
+
         let chart = Chart::new(ds)
             .x_axis(
                 Axis::default()
-                    .title(Span::styled(&self.x_label, theme().text()))
+                    .title(Span::styled(
+                        axis_title(&self.x_label, self.log_x),
+                        theme().text(),
+                    ))
                     .bounds(self.x_bounds)
                     .style(theme().text())
                     .labels(
                         self.x_bounds
-                            .map(|f| Span::styled(format!("{f:.2}"), theme().text())),
+                            .map(|f| Span::styled(axis_label(f, self.log_x), theme().text())),
                     ),
             )
             .y_axis(
                 Axis::default()
-                    .title(Span::styled(&self.y_label, theme().text()))
+                    .title(Span::styled(
+                        axis_title(&self.y_label, self.log_y),
+                        theme().text(),
+                    ))
                     .bounds(self.y_bounds)
                     .style(theme().text())
                     .labels(
                         self.y_bounds
-                            .map(|f| Span::styled(format!("{f:.2}"), theme().text())),
+                            .map(|f| Span::styled(axis_label(f, self.log_y), theme().text())),
                     ),
             )
             .style(theme().text())
-            .block(
-                Block::default()
-                    .title("Scatter Plot")
-                    .title_alignment(Alignment::Center)
-                    .padding(Padding::new(1, 2, 0, 0))
-                    .into_widget(),
-            )
+            .block(block.into_widget())
             .legend_position(Some(LegendPosition::TopRight))
             .hidden_legend_constraints((Constraint::Min(0), Constraint::Min(0)));
         // .hidden_legend_constraints((Constraint::Min(0), Constraint::Ratio(1, 2)));
@@ -125,7 +403,93 @@ impl Component for ScatterPlot {
                 Message::PaneDismissModal.enqueue();
                 true
             }
+            (KeyCode::Right, KeyModifiers::NONE) | (KeyCode::Char('l'), KeyModifiers::NONE) => {
+                self.move_cursor(true);
+                true
+            }
+            (KeyCode::Left, KeyModifiers::NONE) | (KeyCode::Char('h'), KeyModifiers::NONE) => {
+                self.move_cursor(false);
+                true
+            }
+            (KeyCode::Char('f'), KeyModifiers::NONE) => {
+                self.show_fits = !self.show_fits;
+                true
+            }
+            (KeyCode::Char('x'), KeyModifiers::NONE) => {
+                self.toggle_log(true);
+                true
+            }
+            (KeyCode::Char('y'), KeyModifiers::NONE) => {
+                self.toggle_log(false);
+                true
+            }
+            (KeyCode::Tab, KeyModifiers::NONE) => {
+                self.cycle_group(true);
+                true
+            }
+            (KeyCode::BackTab, _) => {
+                self.cycle_group(false);
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE) if self.cursor.is_some() => {
+                self.cursor = None;
+                true
+            }
             _ => false,
         }
     }
 }
+
+const FIT_POINTS: usize = 64;
+
+fn bounds(data: &JaggedVec<(f64, f64)>) -> Option<[[f64; 2]; 2]> {
+    data.iter().flat_map(|v| v.iter()).fold(None, |bounds, p| {
+        let bounds = bounds.unwrap_or([[p.0, p.0], [p.1, p.1]]);
+        Some([
+            [bounds[0][0].min(p.0), bounds[0][1].max(p.0)],
+            [bounds[1][0].min(p.1), bounds[1][1].max(p.1)],
+        ])
+    })
+}
+
+fn axis_title(label: &str, log: bool) -> String {
+    if log {
+        format!("{label} (log)")
+    } else {
+        label.to_owned()
+    }
+}
+
+/// Two decimals, switching to scientific notation for values too small to show that way. Bounds
+/// of log scaled axes are labeled with the value they stand for.
+fn axis_label(value: f64, log: bool) -> String {
+    let value = if log { 10_f64.powf(value) } else { value };
+    if value != 0.0 && value.abs() < 0.01 {
+        format!("{value:.2e}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_fit() {
+        let fit = LinearFit::new(&[(1.0, 3.0), (2.0, 5.0), (3.0, 7.0)]).unwrap();
+        assert_eq!((fit.slope, fit.intercept, fit.r_squared), (2.0, 1.0, 1.0));
+        assert_eq!(
+            fit.points(3).collect_vec(),
+            [(1.0, 3.0), (2.0, 5.0), (3.0, 7.0)]
+        );
+
+        let fit = LinearFit::new(&[(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (3.0, 3.0)]).unwrap();
+        assert!((fit.slope - 0.8).abs() < 1e-9);
+        assert!((fit.intercept - 0.3).abs() < 1e-9);
+        assert!((fit.r_squared - 0.64).abs() < 1e-9);
+
+        assert_eq!(LinearFit::new(&[(1.0, 1.0)]), None);
+        assert_eq!(LinearFit::new(&[(1.0, 1.0), (1.0, 2.0)]), None);
+    }
+}
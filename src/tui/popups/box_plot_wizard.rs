@@ -0,0 +1,86 @@
+use polars::frame::DataFrame;
+
+use crate::{
+    handler::message::Message,
+    tui::{
+        pickers::search_picker::SearchPicker,
+        popups::wizard::{Wizard, WizardState},
+    },
+};
+
+pub type BoxPlotWizard = Wizard<State>;
+
+#[derive(Debug)]
+pub enum State {
+    PickColumn {
+        df: DataFrame,
+        picker: SearchPicker<String>,
+    },
+    PickGroupBy {
+        column: String,
+        picker: SearchPicker<String>,
+    },
+}
+
+impl State {
+    pub fn new(df: DataFrame) -> Self {
+        let items = df
+            .column_iter()
+            .filter(|col| col.dtype().is_primitive_numeric())
+            .map(|col| col.name().to_string())
+            .collect();
+        Self::PickColumn {
+            df,
+            picker: SearchPicker::new(items).with_title("Column"),
+        }
+    }
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickColumn { df, picker } => {
+                if let Some(column) = picker.selected_item().cloned() {
+                    State::PickGroupBy {
+                        picker: SearchPicker::new(
+                            std::iter::once("None".to_owned())
+                                .chain(
+                                    df.column_iter()
+                                        .filter(|col| {
+                                            let dtype = col.dtype();
+                                            col.name() != column.as_str()
+                                                && (dtype.is_string()
+                                                    || dtype.is_bool()
+                                                    || dtype.is_integer())
+                                        })
+                                        .map(|col| col.name().to_string()),
+                                )
+                                .collect(),
+                        )
+                        .with_title("Group By"),
+                        column,
+                    }
+                } else {
+                    State::PickColumn { df, picker }
+                }
+            }
+            State::PickGroupBy { column, picker } => {
+                if let Some(grp) = picker.selected_item().cloned() {
+                    if picker.selected() == Some(0) {
+                        Message::PaneShowBoxPlot(column.clone(), None).enqueue();
+                    } else {
+                        Message::PaneShowBoxPlot(column.clone(), Some(grp)).enqueue();
+                    }
+                }
+                State::PickGroupBy { column, picker }
+            }
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
+        match self {
+            State::PickColumn { df: _, picker } => picker,
+            State::PickGroupBy { column: _, picker } => picker,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    tui::{component::Component, pickers::text_picker::TextPicker},
+};
+
+#[derive(Debug)]
+pub struct CellEditor {
+    row: usize,
+    column: String,
+    picker: TextPicker,
+}
+
+impl CellEditor {
+    pub fn new(row: usize, column: String, value: String) -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title(format!("Edit {column}"))
+                .with_hint("Parsed as the type of the column, empty sets null")
+                .with_value(value),
+            row,
+            column,
+        }
+    }
+}
+
+impl Component for CellEditor {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    Message::PaneCommitCellEdit(
+                        self.row,
+                        self.column.clone(),
+                        self.picker.value().to_owned(),
+                    )
+                    .enqueue();
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
@@ -3,13 +3,14 @@ use polars::{
     prelude::{DataType, TimeUnit},
 };
 use strum::IntoEnumIterator;
-use strum_macros::{Display, EnumIter, IntoStaticStr};
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
 
 use crate::{
     AppResult,
     handler::message::Message,
     misc::type_inferer::{
-        cast_boolean, cast_date, cast_datetime, cast_float, cast_int, cast_string,
+        cast_boolean, cast_boolean_lossy, cast_date, cast_date_lossy, cast_datetime,
+        cast_datetime_lossy,
     },
     tui::{
         pane::TableDescription,
@@ -54,7 +55,7 @@ impl WizardState for State {
             } => {
                 if let Some(target_type) = picker.selected_item() {
                     Message::PaneDismissModal.enqueue();
-                    match cast_column(&mut df, &col_name, *target_type) {
+                    match cast_column(&mut df, &col_name, *target_type, true) {
                         Ok(_) => Message::PanePushDataFrame(
                             df.clone(),
                             TableDescription::Cast(format!("'{col_name}' as {target_type}")),
@@ -99,13 +100,16 @@ impl From<DataFrame> for State {
     }
 }
 
-#[derive(Debug, Clone, Copy, IntoStaticStr, EnumIter, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr, EnumIter, EnumString, Display)]
+#[strum(ascii_case_insensitive)]
 pub enum TargetType {
+    #[strum(to_string = "Boolean", serialize = "bool")]
     Boolean,
     Date,
     Datetime,
     Float,
     Int,
+    #[strum(to_string = "String", serialize = "str")]
     String,
 }
 
@@ -122,16 +126,81 @@ impl From<TargetType> for DataType {
     }
 }
 
-fn cast_column(df: &mut DataFrame, name: &str, target_type: TargetType) -> AppResult<()> {
+/// Casts the column in place. A strict cast fails if any value can not be converted, otherwise
+/// those values become null. Text is parsed the same way either way.
+pub fn cast_column(
+    df: &mut DataFrame,
+    name: &str,
+    target_type: TargetType,
+    strict: bool,
+) -> AppResult<()> {
     let series = df.column(name)?.as_materialized_series();
-    let casted = match target_type {
-        TargetType::Boolean => cast_boolean(series),
-        TargetType::Date => cast_date(series),
-        TargetType::Datetime => cast_datetime(series),
-        TargetType::Float => cast_float(series),
-        TargetType::Int => cast_int(series),
-        TargetType::String => cast_string(series),
+    let text = series.dtype().is_string();
+    let casted = match (target_type, strict) {
+        (TargetType::Boolean, false) if text => cast_boolean_lossy(series),
+        (TargetType::Date, false) if text => cast_date_lossy(series),
+        (TargetType::Datetime, false) if text => cast_datetime_lossy(series),
+        (_, false) => Ok(series.cast(&target_type.into())?),
+        (TargetType::Boolean, true) => cast_boolean(series),
+        (TargetType::Date, true) => cast_date(series),
+        (TargetType::Datetime, true) => cast_datetime(series),
+        // Polars names the values that fail to convert
+        (TargetType::Float | TargetType::Int | TargetType::String, true) => {
+            Ok(series.strict_cast(&target_type.into())?)
+        }
     }?;
     df.replace(name, casted)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use polars::df;
+
+    use super::*;
+
+    #[test]
+    fn test_cast_column() {
+        let mut df = df!("a" => ["1", "x", "3"], "b" => ["true", "false", "true"]).unwrap();
+        assert_eq!("bool".parse::<TargetType>().unwrap(), TargetType::Boolean);
+        assert_eq!("INT".parse::<TargetType>().unwrap(), TargetType::Int);
+        assert_eq!(TargetType::String.to_string(), "String");
+
+        let err = cast_column(&mut df.clone(), "a", TargetType::Int, true).unwrap_err();
+        assert!(err.to_string().contains("\"x\""), "{err}");
+        cast_column(&mut df, "a", TargetType::Int, false).unwrap();
+        assert_eq!(df.column("a").unwrap().null_count(), 1);
+        cast_column(&mut df, "b", TargetType::Boolean, true).unwrap();
+        assert_eq!(df.column("b").unwrap().dtype(), &DataType::Boolean);
+
+        let mut df = df!(
+            "flag" => [Some("true"), Some("maybe"), None],
+            "day" => ["31/12/2024", "01/02/2025", "someday"],
+            "none" => ["x", "y", "z"]
+        )
+        .unwrap();
+        assert!(cast_column(&mut df.clone(), "flag", TargetType::Boolean, true).is_err());
+        assert!(cast_column(&mut df.clone(), "day", TargetType::Date, true).is_err());
+
+        cast_column(&mut df, "flag", TargetType::Boolean, false).unwrap();
+        let flag = df.column("flag").unwrap();
+        assert_eq!(flag.dtype(), &DataType::Boolean);
+        assert_eq!(flag.bool().unwrap().get(0), Some(true));
+        assert_eq!(flag.null_count(), 2);
+
+        // The day first format parses both dates, not only ISO ones
+        cast_column(&mut df, "day", TargetType::Date, false).unwrap();
+        let day = df.column("day").unwrap();
+        assert_eq!(day.dtype(), &DataType::Date);
+        assert_eq!(day.null_count(), 1);
+        assert_eq!(day.get(0).unwrap().to_string(), "2024-12-31");
+
+        cast_column(&mut df, "none", TargetType::Datetime, false).unwrap();
+        let none = df.column("none").unwrap();
+        assert_eq!(
+            none.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
+        assert_eq!(none.null_count(), 3);
+    }
+}
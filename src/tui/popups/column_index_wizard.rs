@@ -0,0 +1,63 @@
+use polars::frame::DataFrame;
+
+use crate::{
+    handler::message::Message,
+    tui::{
+        pickers::search_picker::SearchPicker,
+        popups::wizard::{Wizard, WizardState},
+    },
+};
+
+pub type ColumnIndexWizard = Wizard<State>;
+
+#[derive(Debug)]
+pub enum State {
+    PickColumn {
+        show: fn(String) -> Message,
+        picker: SearchPicker<String>,
+    },
+}
+
+impl State {
+    pub fn new(df: &DataFrame) -> Self {
+        Self::with_show(df, Message::PaneShowColumnIndex)
+    }
+
+    /// Picks a column and enqueues the message built by `show` from its name.
+    pub fn with_show(df: &DataFrame, show: fn(String) -> Message) -> Self {
+        Self::with_columns(
+            df.get_column_names()
+                .into_iter()
+                .map(|name| name.to_string())
+                .collect(),
+            show,
+        )
+    }
+
+    /// Picks one of the given columns and enqueues the message built by `show` from its name.
+    pub fn with_columns(columns: Vec<String>, show: fn(String) -> Message) -> Self {
+        State::PickColumn {
+            show,
+            picker: SearchPicker::new(columns).with_title("Column"),
+        }
+    }
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickColumn { show, picker } => {
+                if let Some(column) = picker.selected_item() {
+                    show(column.to_owned()).enqueue();
+                }
+                State::PickColumn { show, picker }
+            }
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
+        match self {
+            State::PickColumn { show: _, picker } => picker,
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use itertools::Itertools;
+use polars::frame::DataFrame;
+use ratatui::{
+    layout::{Alignment, Constraint, Margin},
+    text::Text,
+    widgets::{Clear, Row, StatefulWidget, Table, TableState, Widget},
+};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
+
+use crate::{
+    handler::message::Message,
+    misc::{
+        config::theme,
+        sql::{FieldInfo, TableSchema},
+        type_ext::human_readable_size,
+    },
+    tui::{
+        component::Component,
+        status_bar::{StatusBar, Tag},
+        widgets::block::Block,
+    },
+};
+
+/// Order of the listed columns, counts and sizes list the largest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, Display)]
+pub enum ColumnOrder {
+    Position,
+    Name,
+    Type,
+    Nulls,
+    Size,
+}
+
+/// Name, type, null count and estimated size of every column of a tab.
+#[derive(Debug)]
+pub struct ColumnList {
+    columns: Vec<(String, FieldInfo)>,
+    order: ColumnOrder,
+    table_state: TableState,
+}
+
+impl ColumnList {
+    pub fn new(df: &DataFrame) -> Self {
+        Self {
+            columns: TableSchema::new(df)
+                .iter()
+                .map(|(name, info)| (name.to_owned(), info.to_owned()))
+                .collect(),
+            order: ColumnOrder::Position,
+            table_state: TableState::default().with_selected(0),
+        }
+    }
+
+    /// Indices into the columns in the current order.
+    fn ordered(&self) -> Vec<usize> {
+        let indices = 0..self.columns.len();
+        match self.order {
+            ColumnOrder::Position => indices.collect(),
+            ColumnOrder::Name => indices
+                .sorted_by_key(|idx| self.columns[*idx].0.to_lowercase())
+                .collect(),
+            ColumnOrder::Type => indices
+                .sorted_by_key(|idx| self.columns[*idx].1.dtype().to_string())
+                .collect(),
+            ColumnOrder::Nulls => indices
+                .sorted_by_key(|idx| std::cmp::Reverse(self.columns[*idx].1.null_count()))
+                .collect(),
+            ColumnOrder::Size => indices
+                .sorted_by_key(|idx| std::cmp::Reverse(self.columns[*idx].1.estimated_size()))
+                .collect(),
+        }
+    }
+
+    fn cycle_order(&mut self) {
+        self.order = ColumnOrder::iter()
+            .cycle()
+            .skip_while(|order| *order != self.order)
+            .nth(1)
+            .unwrap_or(ColumnOrder::Position);
+    }
+
+    fn selected_name(&self) -> Option<&str> {
+        let idx = *self.ordered().get(self.table_state.selected()?)?;
+        Some(self.columns[idx].0.as_str())
+    }
+}
+
+impl Component for ColumnList {
+    fn render(
+        &mut self,
+        _area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        _focus_state: crate::tui::component::FocusState,
+    ) {
+        let area = buf.area.inner(Margin::new(7, 3));
+        Widget::render(Clear, area, buf);
+        let rows = self
+            .ordered()
+            .into_iter()
+            .enumerate()
+            .map(|(line, idx)| {
+                let (name, info) = &self.columns[idx];
+                Row::new([
+                    name.to_owned(),
+                    info.dtype().to_string(),
+                    info.null_count().to_string(),
+                    human_readable_size(info.estimated_size() as u64),
+                ])
+                .style(theme().row(line))
+            })
+            .collect_vec();
+        let order = format!(" {} ", self.order);
+        StatefulWidget::render(
+            Table::default()
+                .header(
+                    Row::new(
+                        ["Name", "Type", "Null Count", "Estimated Size"]
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, s)| Text::styled(s, theme().header(i))),
+                    )
+                    .style(theme().table_header()),
+                )
+                .rows(rows)
+                .widths([
+                    Constraint::Fill(2),
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                ])
+                .style(theme().text())
+                .row_highlight_style(theme().row_highlighted())
+                .block(
+                    Block::default()
+                        .title("Columns")
+                        .title_alignment(Alignment::Center)
+                        .bottom(
+                            StatusBar::new()
+                                .mono_color()
+                                .centered()
+                                .tag(Tag::new(" Sort (s) ", order))
+                                .tag(Tag::new(" Go to Column ", " Enter ")),
+                        )
+                        .into_widget(),
+                ),
+            area,
+            buf,
+            &mut self.table_state,
+        );
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        match (event.code, event.modifiers) {
+            (KeyCode::Up, KeyModifiers::NONE) | (KeyCode::Char('k'), KeyModifiers::NONE) => {
+                self.table_state.select_previous();
+                true
+            }
+            (KeyCode::Down, KeyModifiers::NONE) | (KeyCode::Char('j'), KeyModifiers::NONE) => {
+                if self.table_state.selected() < Some(self.columns.len().saturating_sub(1)) {
+                    self.table_state.select_next();
+                }
+                true
+            }
+            (KeyCode::Char('s'), KeyModifiers::NONE) => {
+                self.cycle_order();
+                true
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                if let Some(name) = self.selected_name() {
+                    Message::PaneDismissModal.enqueue();
+                    Message::PaneGoToColumn(name.to_owned()).enqueue();
+                }
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                Message::PaneDismissModal.enqueue();
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use polars::frame::DataFrame;
+use ratatui::{
+    layout::{Alignment, Constraint, Flex, Layout},
+    text::Span,
+    widgets::{Clear, Row, Table, Widget},
+};
+
+use crate::{
+    AppResult,
+    handler::message::Message,
+    misc::{config::theme, polars_ext::DataFrameExt},
+    tui::{component::Component, widgets::block::Block},
+};
+
+/// Descriptive statistics of a single column, distinct values stand in for non-numeric columns.
+#[derive(Debug)]
+pub struct ColumnStats {
+    column: String,
+    stats: Vec<(&'static str, String)>,
+}
+
+impl ColumnStats {
+    pub fn new(df: &DataFrame, column: &str) -> AppResult<Self> {
+        Ok(Self {
+            column: column.to_owned(),
+            stats: df.column_stats(column)?,
+        })
+    }
+}
+
+impl Component for ColumnStats {
+    fn render(
+        &mut self,
+        _area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        _focus_state: crate::tui::component::FocusState,
+    ) {
+        let [area] = Layout::horizontal([Constraint::Length(60)])
+            .flex(Flex::Center)
+            .areas(buf.area);
+        let [area] = Layout::vertical([Constraint::Length(self.stats.len() as u16 + 2)])
+            .flex(Flex::Center)
+            .areas(area);
+        Widget::render(Clear, area, buf);
+        Table::default()
+            .rows(self.stats.iter().enumerate().map(|(idx, (name, value))| {
+                Row::new([
+                    Span::styled(*name, theme().header(idx)),
+                    Span::styled(value.as_str(), theme().text()),
+                ])
+            }))
+            .widths([Constraint::Max(16), Constraint::Fill(1)])
+            .block(
+                Block::default()
+                    .title_alignment(Alignment::Center)
+                    .title(self.column.as_str())
+                    .into_widget(),
+            )
+            .render(area, buf);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        match (event.code, event.modifiers) {
+            (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                Message::PaneDismissModal.enqueue();
+                true
+            }
+            _ => false,
+        }
+    }
+}
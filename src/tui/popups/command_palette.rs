@@ -61,25 +61,99 @@ impl Component for CommandPalette {
                     Message::AppDismissOverlay.enqueue();
                     if let Some(item) = self.picker.selected_item() {
                         match item {
+                            Command::AddCol => Message::PaneShowInlineAddColumn.enqueue(),
+                            Command::BarPlot => Message::PaneShowBarPlotWizard.enqueue(),
+                            Command::BoxPlot => Message::PaneShowBoxPlotWizard.enqueue(),
+                            Command::Bookmarks => Message::PaneFilterBookmarks.enqueue(),
                             Command::Cast => Message::PaneShowColumnCasterWizard.enqueue(),
+                            Command::CastColumn => Message::PaneShowInlineCast.enqueue(),
+                            Command::Columns => Message::PaneShowColumnList.enqueue(),
+                            Command::Corr => Message::PaneShowCorrMatrix.enqueue(),
+                            Command::Count => Message::PaneShowInlineCount.enqueue(),
+                            Command::Density => Message::PaneShowDensityPlotWizard.enqueue(),
+                            Command::Dedup => Message::PaneDedup.enqueue(),
+                            Command::Describe => Message::PaneDescribe.enqueue(),
+                            Command::Diff => Message::PaneShowInlineDiff.enqueue(),
+                            Command::Duplicates => Message::PaneShowDuplicates.enqueue(),
+                            Command::Edit => Message::PaneShowCellEditWizard.enqueue(),
+                            Command::GotoColumn => Message::PaneShowGoToColumn.enqueue(),
+                            Command::Index => Message::PaneShowColumnIndexWizard.enqueue(),
                             Command::Info => Message::PaneShowTableInfo.enqueue(),
+                            Command::CopyColumn => Message::PaneShowCopyColumnWizard.enqueue(),
+                            Command::CopyRow => Message::PaneCopyRow(false).enqueue(),
+                            Command::CopyRowWithHeader => Message::PaneCopyRow(true).enqueue(),
+                            Command::ExportClipboard => {
+                                Message::PaneShowInlineExportClipboard.enqueue()
+                            }
+                            Command::LoadSession => Message::AppShowLoadSession.enqueue(),
+                            Command::SaveSession => Message::AppShowSaveSession.enqueue(),
+                            Command::Explain => Message::AppShowExplainQuery.enqueue(),
                             Command::Export => Message::PaneShowExportWizard.enqueue(),
                             Command::Filter => Message::PaneShowInlineFilter.enqueue(),
+                            Command::FilterRow => Message::PaneToggleFilterRow.enqueue(),
+                            Command::FilterSelected => {
+                                Message::PaneShowFilterSelectedWizard.enqueue()
+                            }
+                            Command::GroupBy => Message::PaneShowInlineGroupBy.enqueue(),
+                            Command::Join => Message::PaneShowInlineJoin.enqueue(),
+                            Command::Head => Message::PaneShowHeadPicker.enqueue(),
+                            Command::Hide => Message::PaneShowHideColumnWizard.enqueue(),
                             Command::Histogram => Message::PaneShowHistogramWizard.enqueue(),
                             Command::Import => Message::AppShowImportWizard.enqueue(),
+                            Command::LinePlot => Message::PaneShowLinePlotWizard.enqueue(),
+                            Command::FitColumns => Message::PaneToggleFitColumns.enqueue(),
+                            Command::MaxWidth => Message::PaneShowMaxWidthPicker.enqueue(),
+                            Command::Notation => Message::PaneShowNotationWizard.enqueue(),
+                            Command::Open => Message::AppShowOpenWizard.enqueue(),
+                            Command::NullMap => Message::PaneShowNullMap.enqueue(),
+                            Command::Messages => Message::AppShowMessageLog.enqueue(),
                             Command::Order => Message::PaneShowInlineOrder.enqueue(),
+                            Command::Pin => Message::PaneShowPinPicker.enqueue(),
+                            Command::Pivot => Message::PaneShowPivotWizard.enqueue(),
+                            Command::Precision => Message::PaneShowPrecisionPicker.enqueue(),
                             Command::Query => Message::AppShowSqlQuery.enqueue(),
                             Command::Quit => Message::Quit.enqueue(),
+                            Command::RegexSearch => Message::PaneShowRegexSearch.enqueue(),
                             Command::Register => Message::PaneShowTableRegisterer.enqueue(),
+                            Command::Rename => Message::PaneShowRenamePicker.enqueue(),
+                            Command::RenameColumn => Message::PaneShowInlineRename.enqueue(),
+                            Command::Reset => Message::PaneReset.enqueue(),
+                            Command::ResetNow => Message::PaneResetNow.enqueue(),
+                            Command::Reverse => Message::PaneReverse.enqueue(),
+                            Command::RunSql => Message::AppShowSqlFileRunner.enqueue(),
+                            Command::Sample => Message::PaneShowSamplePicker.enqueue(),
                             Command::ScatterPlot => Message::PaneShowScatterPlotWizard.enqueue(),
+                            Command::Stats => Message::PaneShowColumnStatsWizard.enqueue(),
                             Command::Schema => Message::AppShowSchema.enqueue(),
                             Command::Select => Message::PaneShowInlineSelect.enqueue(),
+                            Command::Shade => Message::PaneShowShadeColumnWizard.enqueue(),
+                            Command::Show => Message::PaneShowShowColumnWizard.enqueue(),
+                            Command::ShowAll => Message::PaneShowAllColumns.enqueue(),
+                            Command::Snapshot => Message::PaneSnapshot.enqueue(),
                             Command::Sort => Message::PaneShowInlineOrder.enqueue(),
+                            Command::TabTheme => Message::PaneShowInlineTabTheme.enqueue(),
+                            Command::Tail => Message::PaneShowTailPicker.enqueue(),
                             Command::ThemeSelector => Message::AppShowThemeSelector.enqueue(),
+                            Command::TimeFmt => Message::PaneShowTimeFormatPicker.enqueue(),
                             Command::ToggleBorders => {
                                 config().toggle_show_table_borders();
                                 config().store().unwrap_or_enqueue_error();
                             }
+                            Command::Unpin => Message::PaneSetPinned(0).enqueue(),
+                            Command::Unpivot => Message::PaneShowInlineUnpivot.enqueue(),
+                            Command::Unshade => Message::PaneUnshade.enqueue(),
+                            Command::ToggleZebra => Message::PaneToggleZebra.enqueue(),
+                            Command::Transpose => Message::PaneTranspose.enqueue(),
+                            Command::ToggleNumberFormat => {
+                                config().toggle_format_numbers();
+                                config().store().unwrap_or_enqueue_error();
+                            }
+                            Command::ValueCounts => Message::PaneShowValueCountsWizard.enqueue(),
+                            Command::View => Message::PaneShowInlineView.enqueue(),
+                            Command::Unwatch => Message::PaneUnwatch.enqueue(),
+                            Command::Wrap => Message::PaneToggleWrap.enqueue(),
+                            Command::Watch => Message::PaneWatch.enqueue(),
+                            Command::Where => Message::PaneShowWherePicker.enqueue(),
                             Command::ToggleRowNumbers => {
                                 config().toggle_show_table_row_numbers();
                                 config().store().unwrap_or_enqueue_error();
@@ -100,23 +174,91 @@ impl Component for CommandPalette {
 
 #[derive(Debug, Clone, Copy, EnumIter, IntoStaticStr)]
 enum Command {
+    AddCol,
+    BarPlot,
+    Bookmarks,
+    BoxPlot,
     Cast,
+    CastColumn,
+    Columns,
+    CopyColumn,
+    CopyRow,
+    CopyRowWithHeader,
+    Corr,
+    Count,
+    Dedup,
+    Density,
+    Describe,
+    Diff,
+    Duplicates,
+    Edit,
+    Explain,
     Export,
+    ExportClipboard,
     Filter,
+    FilterRow,
+    FilterSelected,
+    FitColumns,
+    GotoColumn,
+    GroupBy,
+    Head,
+    Hide,
     Histogram,
     Import,
+    Index,
     Info,
+    Join,
+    LinePlot,
+    LoadSession,
+    MaxWidth,
+    Messages,
+    Notation,
+    NullMap,
+    Open,
     Order,
+    Pin,
+    Pivot,
+    Precision,
     Query,
     Quit,
+    RegexSearch,
     Register,
+    Rename,
+    RenameColumn,
+    Reset,
+    #[strum(serialize = "Reset!")]
+    ResetNow,
+    Reverse,
+    RunSql,
+    Sample,
+    SaveSession,
     ScatterPlot,
     Schema,
     Select,
+    Shade,
+    Show,
+    ShowAll,
+    Snapshot,
     Sort,
+    Stats,
+    TabTheme,
+    Tail,
     ThemeSelector,
+    TimeFmt,
     ToggleBorders,
+    ToggleNumberFormat,
     ToggleRowNumbers,
+    ToggleZebra,
+    Transpose,
+    Unpin,
+    Unpivot,
+    Unshade,
+    Unwatch,
+    ValueCounts,
+    View,
+    Watch,
+    Where,
+    Wrap,
 }
 
 impl Command {
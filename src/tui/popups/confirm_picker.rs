@@ -0,0 +1,58 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    tui::{component::Component, popups::yes_no_picker::YesNoPicker},
+};
+
+/// Asks before sending a message that throws work away, `y` or Enter on yes sends it.
+#[derive(Debug)]
+pub struct ConfirmPicker {
+    picker: YesNoPicker,
+    message: Option<Message>,
+}
+
+impl ConfirmPicker {
+    pub fn new(title: impl Into<String>, message: Message) -> Self {
+        Self {
+            picker: YesNoPicker::default().with_title(title),
+            message: Some(message),
+        }
+    }
+
+    fn answer(&mut self, yes: bool) {
+        Message::PaneDismissModal.enqueue();
+        if let Some(message) = self.message.take().filter(|_| yes) {
+            message.enqueue();
+        }
+    }
+}
+
+impl Component for ConfirmPicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        match (event.code, event.modifiers) {
+            (KeyCode::Char('y'), KeyModifiers::NONE) => {
+                self.answer(true);
+                true
+            }
+            (KeyCode::Char('n'), KeyModifiers::NONE) | (KeyCode::Esc, KeyModifiers::NONE) => {
+                self.answer(false);
+                true
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                self.answer(self.picker.value().unwrap_or_default());
+                true
+            }
+            _ => self.picker.handle(event),
+        }
+    }
+}
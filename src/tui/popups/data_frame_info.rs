@@ -16,7 +16,8 @@ pub struct DataFrameInfo {
 impl DataFrameInfo {
     pub fn new(df: &DataFrame, input: crate::misc::sql::Source) -> Self {
         Self {
-            data_frame_info: data_frame_info::DataFrameInfo::new(TableInfo::new(input, df)),
+            data_frame_info: data_frame_info::DataFrameInfo::new(TableInfo::new(input, df))
+                .with_data_frame(df.clone()),
         }
     }
 }
@@ -0,0 +1,68 @@
+use polars::frame::DataFrame;
+
+use crate::{
+    handler::message::Message,
+    tui::{
+        pickers::{search_picker::SearchPicker, text_picker::TextPicker},
+        popups::wizard::{Wizard, WizardState},
+    },
+};
+
+pub type DensityWizard = Wizard<State>;
+
+#[derive(Debug)]
+pub enum State {
+    PickColumn { picker: SearchPicker<String> },
+    PickBandwidth { column: String, picker: TextPicker },
+}
+
+impl State {
+    pub fn new(df: &DataFrame) -> Self {
+        let items = df
+            .column_iter()
+            .filter(|col| col.dtype().is_numeric())
+            .map(|col| col.name().to_string())
+            .collect();
+
+        State::PickColumn {
+            picker: SearchPicker::new(items).with_title("Column"),
+        }
+    }
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickColumn { picker } => {
+                if let Some(column) = picker.selected_item() {
+                    State::PickBandwidth {
+                        column: column.to_owned(),
+                        picker: TextPicker::default()
+                            .with_title("Bandwidth")
+                            .with_hint("Empty for Silverman's rule"),
+                    }
+                } else {
+                    State::PickColumn { picker }
+                }
+            }
+            State::PickBandwidth { column, picker } => {
+                let value = picker.value().trim();
+                if value.is_empty() {
+                    Message::PaneShowDensityPlot(column.clone(), None).enqueue();
+                } else if let Ok(bandwidth) = value.parse() {
+                    Message::PaneShowDensityPlot(column.clone(), Some(bandwidth)).enqueue();
+                } else {
+                    Message::AppShowError(format!("Invalid bandwidth '{value}'")).enqueue();
+                }
+                State::PickBandwidth { column, picker }
+            }
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
+        match self {
+            State::PickColumn { picker } => picker,
+            State::PickBandwidth { column: _, picker } => picker,
+        }
+    }
+}
@@ -9,8 +9,8 @@ use crate::tui::{
     pickers::search_picker::SearchPicker,
     popups::{
         exporters::{
-            arrow_exporter, csv_exporter, json_exporter, jsonl_exporter, parquet_exporter,
-            tsv_exporter,
+            arrow_exporter, csv_exporter, ddl_exporter, html_exporter, json_exporter,
+            jsonl_exporter, markdown_exporter, parquet_exporter, tsv_exporter,
         },
         wizard::{Wizard, WizardState},
     },
@@ -20,8 +20,15 @@ pub type ExportWizard = Wizard<State>;
 
 #[derive(Debug)]
 pub enum State {
+    PickRows {
+        all: DataFrame,
+        marked: DataFrame,
+        name: String,
+        picker: SearchPicker<String>,
+    },
     PickFormat {
         df: DataFrame,
+        name: String,
         picker: SearchPicker<Format>,
     },
     Arrow {
@@ -30,12 +37,21 @@ pub enum State {
     Csv {
         state: csv_exporter::State,
     },
+    Ddl {
+        state: ddl_exporter::State,
+    },
+    Html {
+        state: html_exporter::State,
+    },
     Json {
         state: json_exporter::State,
     },
     JsonL {
         state: jsonl_exporter::State,
     },
+    Markdown {
+        state: markdown_exporter::State,
+    },
     Parquet {
         state: parquet_exporter::State,
     },
@@ -44,26 +60,64 @@ pub enum State {
     },
 }
 
-impl From<DataFrame> for State {
-    fn from(value: DataFrame) -> Self {
+impl State {
+    /// Starts with picking the format, `name` is used where the format supports a title.
+    pub fn new(df: DataFrame, name: String) -> Self {
         State::PickFormat {
-            df: value,
+            df,
+            name,
             picker: SearchPicker::new(Format::iter().collect()).with_title("Format"),
         }
     }
+
+    /// Asks whether to export all rows or only the marked ones before picking the format.
+    pub fn with_marked(all: DataFrame, marked: DataFrame, name: String) -> Self {
+        State::PickRows {
+            picker: SearchPicker::new(vec![
+                format!("All ({} rows)", all.height()),
+                format!("Marked ({} rows)", marked.height()),
+            ])
+            .with_title("Rows"),
+            all,
+            marked,
+            name,
+        }
+    }
 }
 
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
-            State::PickFormat { df, picker } => match picker.selected_item() {
+            State::PickRows {
+                all,
+                marked,
+                name,
+                picker,
+            } => match picker.selected() {
+                Some(0) => State::new(all, name),
+                Some(_) => State::new(marked, name),
+                None => State::PickRows {
+                    all,
+                    marked,
+                    name,
+                    picker,
+                },
+            },
+            State::PickFormat { df, name, picker } => match picker.selected_item() {
                 Some(Format::Arrow) => State::Arrow { state: df.into() },
                 Some(Format::Csv) => State::Csv { state: df.into() },
+                Some(Format::Ddl) => State::Ddl {
+                    state: ddl_exporter::State::new(df, name),
+                },
+                Some(Format::Html) => State::Html {
+                    state: html_exporter::State::new(df, name),
+                },
                 Some(Format::Json) => State::Json { state: df.into() },
                 Some(Format::JsonL) => State::JsonL { state: df.into() },
+                Some(Format::Markdown) => State::Markdown { state: df.into() },
                 Some(Format::Parquet) => State::Parquet { state: df.into() },
                 Some(Format::Tsv) => State::Tsv { state: df.into() },
-                None => State::PickFormat { df, picker },
+                None => State::PickFormat { df, name, picker },
             },
             State::Arrow { state } => State::Arrow {
                 state: state.next(),
@@ -71,12 +125,21 @@ impl WizardState for State {
             State::Csv { state } => State::Csv {
                 state: state.next(),
             },
+            State::Ddl { state } => State::Ddl {
+                state: state.next(),
+            },
+            State::Html { state } => State::Html {
+                state: state.next(),
+            },
             State::Json { state } => State::Json {
                 state: state.next(),
             },
             State::JsonL { state } => State::JsonL {
                 state: state.next(),
             },
+            State::Markdown { state } => State::Markdown {
+                state: state.next(),
+            },
             State::Parquet { state } => State::Parquet {
                 state: state.next(),
             },
@@ -88,11 +151,24 @@ impl WizardState for State {
 
     fn responder(&mut self) -> &mut dyn Component {
         match self {
-            State::PickFormat { df: _, picker } => picker,
+            State::PickRows {
+                all: _,
+                marked: _,
+                name: _,
+                picker,
+            } => picker,
+            State::PickFormat {
+                df: _,
+                name: _,
+                picker,
+            } => picker,
             State::Arrow { state } => state.responder(),
             State::Csv { state } => state.responder(),
+            State::Ddl { state } => state.responder(),
+            State::Html { state } => state.responder(),
             State::Json { state } => state.responder(),
             State::JsonL { state } => state.responder(),
+            State::Markdown { state } => state.responder(),
             State::Parquet { state } => state.responder(),
             State::Tsv { state } => state.responder(),
         }
@@ -107,6 +183,9 @@ pub enum Format {
     Json,
     JsonL,
     Arrow,
+    Markdown,
+    Html,
+    Ddl,
 }
 
 impl Display for Format {
@@ -2,7 +2,6 @@ use polars::frame::DataFrame;
 
 use crate::{
     handler::message::Message,
-    misc::type_ext::UnwrapOrEnqueueError,
     tui::{
         component::Component,
         popups::{path_picker::PathPicker, wizard::WizardState},
@@ -27,10 +26,8 @@ impl From<DataFrame> for State {
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
-            State::PickOutputPath { mut df, picker } => {
-                WriteToArrow
-                    .write_to_file(Destination::File(picker.path()), &mut df)
-                    .unwrap_or_enqueue_error();
+            State::PickOutputPath { df, picker } => {
+                WriteToArrow.write_in_background(Destination::File(picker.path()), df.clone());
                 Message::PaneDismissModal.enqueue();
                 Self::PickOutputPath { df, picker }
             }
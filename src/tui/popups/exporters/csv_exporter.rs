@@ -1,7 +1,4 @@
-use crate::{
-    misc::type_ext::UnwrapOrEnqueueError,
-    writer::{Destination, WriteToFile},
-};
+use crate::writer::{Destination, WriteToFile};
 use polars::frame::DataFrame;
 
 use crate::{
@@ -93,7 +90,7 @@ impl WizardState for State {
                 }
             }
             State::PickOutputTarget {
-                mut df,
+                df,
                 separator,
                 quote,
                 picker,
@@ -103,8 +100,7 @@ impl WizardState for State {
                         .with_separator_char(separator)
                         .with_quote_char(quote)
                         .with_header(true)
-                        .write_to_file(Destination::Clipboard, &mut df)
-                        .unwrap_or_enqueue_error();
+                        .write_in_background(Destination::Clipboard, df.clone());
                     Message::PaneDismissModal.enqueue();
                     State::PickOutputTarget {
                         df,
@@ -127,7 +123,7 @@ impl WizardState for State {
                 },
             },
             State::PickOutputPath {
-                mut df,
+                df,
                 separator,
                 quote,
                 picker,
@@ -136,8 +132,7 @@ impl WizardState for State {
                     .with_separator_char(separator)
                     .with_quote_char(quote)
                     .with_header(true)
-                    .write_to_file(Destination::File(picker.path()), &mut df)
-                    .unwrap_or_enqueue_error();
+                    .write_in_background(Destination::File(picker.path()), df.clone());
                 Message::PaneDismissModal.enqueue();
                 State::PickOutputPath {
                     df,
@@ -0,0 +1,132 @@
+use crate::writer::{Destination, WriteToFile};
+use polars::frame::DataFrame;
+use strum::IntoEnumIterator;
+
+use crate::{
+    handler::message::Message,
+    tui::{
+        component::Component,
+        pickers::{search_picker::SearchPicker, text_picker::TextPicker},
+        popups::{
+            export_target_picker::{ExportTargetPicker, Target},
+            path_picker::PathPicker,
+            wizard::WizardState,
+        },
+    },
+    writer::{DdlDialect, WriteToDdl},
+};
+
+#[derive(Debug)]
+pub enum State {
+    PickDialect {
+        df: DataFrame,
+        name: String,
+        picker: SearchPicker<DdlDialect>,
+    },
+    PickTableName {
+        df: DataFrame,
+        dialect: DdlDialect,
+        picker: TextPicker,
+    },
+    PickOutputTarget {
+        df: DataFrame,
+        writer: WriteToDdl,
+        picker: ExportTargetPicker,
+    },
+    PickOutputPath {
+        df: DataFrame,
+        writer: WriteToDdl,
+        picker: PathPicker,
+    },
+}
+
+impl State {
+    /// The table name is prefilled with `name`, the title of the exported tab.
+    pub fn new(df: DataFrame, name: String) -> Self {
+        State::PickDialect {
+            df,
+            name,
+            picker: SearchPicker::new(DdlDialect::iter().collect()).with_title("Dialect"),
+        }
+    }
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickDialect { df, name, picker } => match picker.selected_item() {
+                Some(dialect) => State::PickTableName {
+                    df,
+                    dialect: *dialect,
+                    picker: TextPicker::default()
+                        .with_title("Table Name")
+                        .with_value(name),
+                },
+                None => State::PickDialect { df, name, picker },
+            },
+            State::PickTableName {
+                df,
+                dialect,
+                picker,
+            } => match picker.value().trim() {
+                "" => State::PickTableName {
+                    df,
+                    dialect,
+                    picker,
+                },
+                name => State::PickOutputTarget {
+                    writer: WriteToDdl::new(name, dialect),
+                    df,
+                    picker: Default::default(),
+                },
+            },
+            State::PickOutputTarget { df, writer, picker } => match picker.selected() {
+                Some(Target::File) => State::PickOutputPath {
+                    df,
+                    writer,
+                    picker: Default::default(),
+                },
+                Some(Target::Clipboard) => {
+                    writer
+                        .clone()
+                        .write_in_background(Destination::Clipboard, df.clone());
+                    Message::PaneDismissModal.enqueue();
+                    State::PickOutputTarget { df, writer, picker }
+                }
+                None => State::PickOutputTarget { df, writer, picker },
+            },
+            State::PickOutputPath { df, writer, picker } => {
+                writer
+                    .clone()
+                    .write_in_background(Destination::File(picker.path()), df.clone());
+                Message::PaneDismissModal.enqueue();
+                State::PickOutputPath { df, writer, picker }
+            }
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn Component {
+        match self {
+            State::PickDialect {
+                df: _,
+                name: _,
+                picker,
+            } => picker,
+            State::PickTableName {
+                df: _,
+                dialect: _,
+                picker,
+            } => picker,
+            State::PickOutputTarget {
+                df: _,
+                writer: _,
+                picker,
+            } => picker,
+            State::PickOutputPath {
+                df: _,
+                writer: _,
+                picker,
+            } => picker,
+        }
+    }
+}
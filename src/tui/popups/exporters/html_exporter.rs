@@ -0,0 +1,139 @@
+use crate::writer::{Destination, WriteToFile};
+use polars::frame::DataFrame;
+
+use crate::{
+    handler::message::Message,
+    tui::{
+        component::Component,
+        popups::{
+            export_target_picker::{ExportTargetPicker, Target},
+            path_picker::PathPicker,
+            wizard::WizardState,
+            yes_no_picker::YesNoPicker,
+        },
+    },
+    writer::WriteToHtml,
+};
+
+#[derive(Debug)]
+pub enum State {
+    PickStyle {
+        df: DataFrame,
+        name: String,
+        picker: YesNoPicker,
+    },
+    PickOutputTarget {
+        df: DataFrame,
+        name: String,
+        styled: bool,
+        picker: ExportTargetPicker,
+    },
+    PickOutputPath {
+        df: DataFrame,
+        name: String,
+        styled: bool,
+        picker: PathPicker,
+    },
+}
+
+impl State {
+    pub fn new(df: DataFrame, name: String) -> Self {
+        State::PickStyle {
+            df,
+            name,
+            picker: YesNoPicker::default().with_title("Theme Style"),
+        }
+    }
+}
+
+fn writer(name: &str, styled: bool) -> WriteToHtml {
+    let writer = WriteToHtml::default().with_caption(name);
+    if styled {
+        writer.with_theme_style()
+    } else {
+        writer
+    }
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickStyle { df, name, picker } => match picker.value() {
+                Some(styled) => State::PickOutputTarget {
+                    df,
+                    name,
+                    styled,
+                    picker: Default::default(),
+                },
+                None => State::PickStyle { df, name, picker },
+            },
+            State::PickOutputTarget {
+                df,
+                name,
+                styled,
+                picker,
+            } => match picker.selected() {
+                Some(Target::File) => State::PickOutputPath {
+                    df,
+                    name,
+                    styled,
+                    picker: Default::default(),
+                },
+                Some(Target::Clipboard) => {
+                    writer(&name, styled).write_in_background(Destination::Clipboard, df.clone());
+                    Message::PaneDismissModal.enqueue();
+                    State::PickOutputTarget {
+                        df,
+                        name,
+                        styled,
+                        picker,
+                    }
+                }
+                None => State::PickOutputTarget {
+                    df,
+                    name,
+                    styled,
+                    picker,
+                },
+            },
+            State::PickOutputPath {
+                df,
+                name,
+                styled,
+                picker,
+            } => {
+                writer(&name, styled)
+                    .write_in_background(Destination::File(picker.path()), df.clone());
+                Message::PaneDismissModal.enqueue();
+                State::PickOutputPath {
+                    df,
+                    name,
+                    styled,
+                    picker,
+                }
+            }
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn Component {
+        match self {
+            State::PickStyle {
+                df: _,
+                name: _,
+                picker,
+            } => picker,
+            State::PickOutputTarget {
+                df: _,
+                name: _,
+                styled: _,
+                picker,
+            } => picker,
+            State::PickOutputPath {
+                df: _,
+                name: _,
+                styled: _,
+                picker,
+            } => picker,
+        }
+    }
+}
@@ -1,39 +1,47 @@
-use crate::{
-    misc::type_ext::UnwrapOrEnqueueError,
-    writer::{Destination, WriteToFile},
-};
+use crate::writer::{Destination, WriteToFile};
 use polars::frame::DataFrame;
 
 use crate::{
     handler::message::Message,
     tui::{
         component::Component,
+        pickers::text_picker::TextPicker,
         popups::{
             export_target_picker::{ExportTargetPicker, Target},
             path_picker::PathPicker,
             wizard::WizardState,
         },
+        widgets::input::InputType,
     },
     writer::{JsonFormat, WriteToJson},
 };
 
 #[derive(Debug)]
 pub enum State {
+    PickIndent {
+        df: DataFrame,
+        picker: TextPicker,
+    },
     PickOutputTarget {
         df: DataFrame,
+        indent: Option<usize>,
         picker: ExportTargetPicker,
     },
     PickOutputPath {
         df: DataFrame,
+        indent: Option<usize>,
         picker: PathPicker,
     },
 }
 
 impl From<DataFrame> for State {
     fn from(value: DataFrame) -> Self {
-        Self::PickOutputTarget {
+        Self::PickIndent {
             df: value,
-            picker: Default::default(),
+            picker: TextPicker::default()
+                .with_title("Indent")
+                .with_hint("Spaces per level, empty writes compact JSON")
+                .with_input_type(InputType::Numeric),
         }
     }
 }
@@ -41,36 +49,51 @@ impl From<DataFrame> for State {
 impl WizardState for State {
     fn next(self) -> State {
         match self {
-            State::PickOutputTarget { mut df, picker } => match picker.selected() {
+            State::PickIndent { df, picker } => State::PickOutputTarget {
+                df,
+                indent: picker.value().parse().ok(),
+                picker: Default::default(),
+            },
+            State::PickOutputTarget { df, indent, picker } => match picker.selected() {
                 Some(Target::File) => State::PickOutputPath {
                     df,
+                    indent,
                     picker: Default::default(),
                 },
                 Some(Target::Clipboard) => {
                     WriteToJson::default()
                         .with_format(JsonFormat::Json)
-                        .write_to_file(Destination::Clipboard, &mut df)
-                        .unwrap_or_enqueue_error();
+                        .with_indent(indent)
+                        .write_in_background(Destination::Clipboard, df.clone());
                     Message::PaneDismissModal.enqueue();
-                    State::PickOutputTarget { df, picker }
+                    State::PickOutputTarget { df, indent, picker }
                 }
-                None => State::PickOutputTarget { picker, df },
+                None => State::PickOutputTarget { picker, indent, df },
             },
-            State::PickOutputPath { mut df, picker } => {
+            State::PickOutputPath { df, indent, picker } => {
                 WriteToJson::default()
                     .with_format(JsonFormat::Json)
-                    .write_to_file(Destination::File(picker.path()), &mut df)
-                    .unwrap_or_enqueue_error();
+                    .with_indent(indent)
+                    .write_in_background(Destination::File(picker.path()), df.clone());
                 Message::PaneDismissModal.enqueue();
-                State::PickOutputPath { df, picker }
+                State::PickOutputPath { df, indent, picker }
             }
         }
     }
 
     fn responder(&mut self) -> &mut dyn Component {
         match self {
-            State::PickOutputTarget { picker, df: _ } => picker,
-            State::PickOutputPath { picker, df: _ } => picker,
+            State::PickIndent { picker, df: _ } => picker,
+            State::PickOutputTarget {
+                picker,
+                indent: _,
+                df: _,
+            } => picker,
+            State::PickOutputPath {
+                picker,
+                indent: _,
+                df: _,
+            } => picker,
         }
     }
 }
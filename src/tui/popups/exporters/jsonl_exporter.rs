@@ -1,7 +1,4 @@
-use crate::{
-    misc::type_ext::UnwrapOrEnqueueError,
-    writer::{Destination, WriteToFile},
-};
+use crate::writer::{Destination, WriteToFile};
 use polars::frame::DataFrame;
 
 use crate::{
@@ -41,7 +38,7 @@ impl From<DataFrame> for State {
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
-            State::PickOutputTarget { mut df, picker } => match picker.selected() {
+            State::PickOutputTarget { df, picker } => match picker.selected() {
                 Some(Target::File) => State::PickOutputPath {
                     df,
                     picker: Default::default(),
@@ -49,18 +46,16 @@ impl WizardState for State {
                 Some(Target::Clipboard) => {
                     WriteToJson::default()
                         .with_format(JsonFormat::JsonLine)
-                        .write_to_file(Destination::Clipboard, &mut df)
-                        .unwrap_or_enqueue_error();
+                        .write_in_background(Destination::Clipboard, df.clone());
                     Message::PaneDismissModal.enqueue();
                     State::PickOutputTarget { df, picker }
                 }
                 None => State::PickOutputTarget { df, picker },
             },
-            State::PickOutputPath { mut df, picker } => {
+            State::PickOutputPath { df, picker } => {
                 WriteToJson::default()
                     .with_format(JsonFormat::JsonLine)
-                    .write_to_file(Destination::File(picker.path()), &mut df)
-                    .unwrap_or_enqueue_error();
+                    .write_in_background(Destination::File(picker.path()), df.clone());
                 Message::PaneDismissModal.enqueue();
                 State::PickOutputPath { df, picker }
             }
@@ -0,0 +1,67 @@
+use crate::writer::{Destination, WriteToFile};
+use polars::frame::DataFrame;
+
+use crate::{
+    handler::message::Message,
+    tui::{
+        component::Component,
+        popups::{
+            export_target_picker::{ExportTargetPicker, Target},
+            path_picker::PathPicker,
+            wizard::WizardState,
+        },
+    },
+    writer::WriteToMarkdown,
+};
+
+#[derive(Debug)]
+pub enum State {
+    PickOutputTarget {
+        df: DataFrame,
+        picker: ExportTargetPicker,
+    },
+    PickOutputPath {
+        df: DataFrame,
+        picker: PathPicker,
+    },
+}
+
+impl From<DataFrame> for State {
+    fn from(value: DataFrame) -> Self {
+        State::PickOutputTarget {
+            df: value,
+            picker: Default::default(),
+        }
+    }
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickOutputTarget { df, picker } => match picker.selected() {
+                Some(Target::File) => State::PickOutputPath {
+                    df,
+                    picker: Default::default(),
+                },
+                Some(Target::Clipboard) => {
+                    WriteToMarkdown.write_in_background(Destination::Clipboard, df.clone());
+                    Message::PaneDismissModal.enqueue();
+                    State::PickOutputTarget { df, picker }
+                }
+                None => State::PickOutputTarget { df, picker },
+            },
+            State::PickOutputPath { df, picker } => {
+                WriteToMarkdown.write_in_background(Destination::File(picker.path()), df.clone());
+                Message::PaneDismissModal.enqueue();
+                State::PickOutputPath { df, picker }
+            }
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn Component {
+        match self {
+            State::PickOutputTarget { df: _, picker } => picker,
+            State::PickOutputPath { df: _, picker } => picker,
+        }
+    }
+}
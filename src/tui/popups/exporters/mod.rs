@@ -1,6 +1,9 @@
 pub mod arrow_exporter;
 pub mod csv_exporter;
+pub mod ddl_exporter;
+pub mod html_exporter;
 pub mod json_exporter;
 pub mod jsonl_exporter;
+pub mod markdown_exporter;
 pub mod parquet_exporter;
 pub mod tsv_exporter;
@@ -1,25 +1,40 @@
 use polars::frame::DataFrame;
+use strum::IntoEnumIterator;
 
 use crate::{
     handler::message::Message,
-    misc::type_ext::UnwrapOrEnqueueError,
     tui::{
         component::Component,
+        pickers::{search_picker::SearchPicker, text_picker::TextPicker},
         popups::{path_picker::PathPicker, wizard::WizardState},
+        widgets::input::InputType,
     },
-    writer::{Destination, WriteToFile, WriteToParquet},
+    writer::{Destination, ParquetCodec, WriteToFile, WriteToParquet},
 };
 
 #[derive(Debug)]
 pub enum State {
-    PickOutputPath { df: DataFrame, picker: PathPicker },
+    PickCompression {
+        df: DataFrame,
+        picker: SearchPicker<ParquetCodec>,
+    },
+    PickRowGroupSize {
+        df: DataFrame,
+        compression: ParquetCodec,
+        picker: TextPicker,
+    },
+    PickOutputPath {
+        df: DataFrame,
+        writer: WriteToParquet,
+        picker: PathPicker,
+    },
 }
 
 impl From<DataFrame> for State {
     fn from(value: DataFrame) -> Self {
-        Self::PickOutputPath {
+        Self::PickCompression {
             df: value,
-            picker: Default::default(),
+            picker: SearchPicker::new(ParquetCodec::iter().collect()).with_title("Compression"),
         }
     }
 }
@@ -27,19 +42,51 @@ impl From<DataFrame> for State {
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
-            State::PickOutputPath { mut df, picker } => {
-                WriteToParquet
-                    .write_to_file(Destination::File(picker.path()), &mut df)
-                    .unwrap_or_enqueue_error();
+            State::PickCompression { df, picker } => match picker.selected_item() {
+                Some(compression) => State::PickRowGroupSize {
+                    df,
+                    compression: *compression,
+                    picker: TextPicker::default()
+                        .with_title("Row Group Size")
+                        .with_hint("Rows per row group, empty lets polars decide")
+                        .with_input_type(InputType::Numeric),
+                },
+                None => State::PickCompression { df, picker },
+            },
+            State::PickRowGroupSize {
+                df,
+                compression,
+                picker,
+            } => State::PickOutputPath {
+                df,
+                writer: WriteToParquet::default()
+                    .with_compression(compression)
+                    .with_row_group_size(picker.value().parse().ok().filter(|size| *size > 0)),
+                picker: Default::default(),
+            },
+            State::PickOutputPath { df, writer, picker } => {
+                writer
+                    .clone()
+                    .write_in_background(Destination::File(picker.path()), df.clone());
                 Message::PaneDismissModal.enqueue();
-                State::PickOutputPath { df, picker }
+                State::PickOutputPath { df, writer, picker }
             }
         }
     }
 
     fn responder(&mut self) -> &mut dyn Component {
         match self {
-            State::PickOutputPath { df: _, picker } => picker,
+            State::PickCompression { df: _, picker } => picker,
+            State::PickRowGroupSize {
+                df: _,
+                compression: _,
+                picker,
+            } => picker,
+            State::PickOutputPath {
+                df: _,
+                writer: _,
+                picker,
+            } => picker,
         }
     }
 }
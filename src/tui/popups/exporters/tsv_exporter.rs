@@ -2,7 +2,6 @@ use polars::frame::DataFrame;
 
 use crate::{
     handler::message::Message,
-    misc::type_ext::UnwrapOrEnqueueError,
     tui::{
         component::Component,
         popups::{
@@ -38,7 +37,7 @@ impl From<DataFrame> for State {
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
-            State::PickOutputTarget { mut df, picker } => match picker.selected() {
+            State::PickOutputTarget { df, picker } => match picker.selected() {
                 Some(Target::File) => State::PickOutputPath {
                     df,
                     picker: PathPicker::default(),
@@ -48,20 +47,18 @@ impl WizardState for State {
                         .with_separator_char('\t')
                         .with_quote_char('"')
                         .with_header(false)
-                        .write_to_file(Destination::Clipboard, &mut df)
-                        .unwrap_or_enqueue_error();
+                        .write_in_background(Destination::Clipboard, df.clone());
                     Message::PaneDismissModal.enqueue();
                     State::PickOutputTarget { df, picker }
                 }
                 None => State::PickOutputTarget { df, picker },
             },
-            State::PickOutputPath { mut df, picker } => {
+            State::PickOutputPath { df, picker } => {
                 WriteToCsv::default()
                     .with_separator_char('\t')
                     .with_quote_char('"')
                     .with_header(false)
-                    .write_to_file(Destination::File(picker.path()), &mut df)
-                    .unwrap_or_enqueue_error();
+                    .write_in_background(Destination::File(picker.path()), df.clone());
                 Message::PaneDismissModal.enqueue();
                 State::PickOutputPath { df, picker }
             }
@@ -0,0 +1,48 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    tui::{component::Component, pickers::text_picker::TextPicker},
+};
+
+#[derive(Debug)]
+pub struct GoToColumn {
+    picker: TextPicker,
+}
+
+impl Default for GoToColumn {
+    fn default() -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title("Go to Column")
+                .with_hint("Name or number of the column"),
+        }
+    }
+}
+
+impl Component for GoToColumn {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    Message::PaneGoToColumn(self.picker.value().trim().to_owned()).enqueue();
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
@@ -0,0 +1,78 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    tui::{component::Component, pickers::text_picker::TextPicker},
+};
+
+/// End of the table the rows are kept from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slice {
+    Head,
+    Tail,
+}
+
+#[derive(Debug)]
+pub struct HeadTailPicker {
+    picker: TextPicker,
+    slice: Slice,
+}
+
+impl HeadTailPicker {
+    pub fn new(slice: Slice) -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title(match slice {
+                    Slice::Head => "Head",
+                    Slice::Tail => "Tail",
+                })
+                .with_hint("Number of rows to keep like 100"),
+            slice,
+        }
+    }
+
+    fn apply(&self) {
+        let value = self.picker.value().trim();
+        match value.parse::<usize>() {
+            Ok(rows) => match self.slice {
+                Slice::Head => Message::PaneHead(rows).enqueue(),
+                Slice::Tail => Message::PaneTail(rows).enqueue(),
+            },
+            Err(_) => Message::AppShowError(format!(
+                "'{value}' is not a row count, usage: {} <rows>",
+                match self.slice {
+                    Slice::Head => "head",
+                    Slice::Tail => "tail",
+                }
+            ))
+            .enqueue(),
+        }
+    }
+}
+
+impl Component for HeadTailPicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    self.apply();
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
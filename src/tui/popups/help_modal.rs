@@ -99,10 +99,18 @@ impl Component for Help {
             Span::styled("  Enter", theme().text().bold()),
             Span::raw("                  Open sheet (cell detail view)"),
         ]));
+        lines.push(Line::from(vec![
+            Span::styled("  j / k (in sheet)", theme().text().bold()),
+            Span::raw("       Next / previous record"),
+        ]));
         lines.push(Line::from(vec![
             Span::styled("  e", theme().text().bold()),
             Span::raw("                       Toggle Auto-Fit"),
         ]));
+        lines.push(Line::from(vec![
+            Span::styled("  s", theme().text().bold()),
+            Span::raw("                       Sort current column ▲ / ▼ / off"),
+        ]));
         lines.push(Line::from(vec![
             Span::styled("  f", theme().text().bold()),
             Span::raw("                       Toggle Borders"),
@@ -123,6 +131,10 @@ impl Component for Help {
             Span::styled("  ?", theme().text().bold()),
             Span::raw("                       Exact Search"),
         ]));
+        lines.push(Line::from(vec![
+            Span::styled("  \\", theme().text().bold()),
+            Span::raw("                       Regex Search"),
+        ]));
         lines.push(Line::raw(""));
 
         // Commands
@@ -7,8 +7,9 @@ use crate::tui::{
     pickers::search_picker::SearchPicker,
     popups::{
         importers::{
-            arrow_importer, csv_importer, excel_importer, fwf_importer, json_importer,
-            jsonl_importer, logfmt_importer, parquet_importer, sqlite_importer, tsv_importer,
+            arrow_importer, auto_importer, avro_importer, csv_importer, excel_importer,
+            fwf_importer, json_importer, jsonl_importer, logfmt_importer, ods_importer,
+            parquet_importer, sqlite_importer, tsv_importer,
         },
         wizard::{Wizard, WizardState},
     },
@@ -19,9 +20,12 @@ pub type ImportWizard = Wizard<State>;
 #[derive(Debug)]
 pub enum State {
     PickFormat { picker: SearchPicker<Formats> },
+    Auto { auto: auto_importer::State },
     Arrow { arrow: arrow_importer::State },
+    Avro { avro: avro_importer::State },
     Csv { csv: csv_importer::State },
     Excel { excel: excel_importer::State },
+    Ods { ods: ods_importer::State },
     Fwf { fwf: fwf_importer::State },
     Json { json: json_importer::State },
     JsonL { jsonl: jsonl_importer::State },
@@ -35,15 +39,24 @@ impl WizardState for State {
     fn next(self) -> Self {
         match self {
             State::PickFormat { picker } => match picker.selected_item() {
+                Some(Formats::Auto) => State::Auto {
+                    auto: Default::default(),
+                },
                 Some(Formats::Arrow) => State::Arrow {
                     arrow: Default::default(),
                 },
+                Some(Formats::Avro) => Self::Avro {
+                    avro: Default::default(),
+                },
                 Some(Formats::Csv) => Self::Csv {
                     csv: Default::default(),
                 },
                 Some(Formats::Excel) => Self::Excel {
                     excel: Default::default(),
                 },
+                Some(Formats::Ods) => Self::Ods {
+                    ods: Default::default(),
+                },
                 Some(Formats::Fwf) => Self::Fwf {
                     fwf: Default::default(),
                 },
@@ -67,13 +80,16 @@ impl WizardState for State {
                 },
                 None => State::PickFormat { picker },
             },
+            State::Auto { auto } => State::Auto { auto: auto.next() },
             State::Arrow { arrow } => State::Arrow {
                 arrow: arrow.next(),
             },
+            State::Avro { avro } => State::Avro { avro: avro.next() },
             State::Csv { csv } => State::Csv { csv: csv.next() },
             State::Excel { excel } => State::Excel {
                 excel: excel.next(),
             },
+            State::Ods { ods } => State::Ods { ods: ods.next() },
             State::Fwf { fwf } => State::Fwf { fwf: fwf.next() },
             State::Json { json } => State::Json { json: json.next() },
             State::JsonL { jsonl } => State::JsonL {
@@ -95,9 +111,12 @@ impl WizardState for State {
     fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
         match self {
             State::PickFormat { picker } => picker,
+            State::Auto { auto } => auto.responder(),
             State::Arrow { arrow } => arrow.responder(),
+            State::Avro { avro } => avro.responder(),
             State::Csv { csv } => csv.responder(),
             State::Excel { excel } => excel.responder(),
+            State::Ods { ods } => ods.responder(),
             State::Fwf { fwf } => fwf.responder(),
             State::Json { json } => json.responder(),
             State::JsonL { jsonl } => jsonl.responder(),
@@ -109,6 +128,15 @@ impl WizardState for State {
     }
 }
 
+impl State {
+    /// Skips the format picker, the format is detected from the picked file.
+    pub fn auto() -> Self {
+        State::Auto {
+            auto: Default::default(),
+        }
+    }
+}
+
 impl Default for State {
     fn default() -> Self {
         Self::PickFormat {
@@ -119,15 +147,18 @@ impl Default for State {
 
 #[derive(Debug, Clone, Copy, IntoStaticStr, EnumIter)]
 pub enum Formats {
+    Auto,
     Csv,
     Tsv,
     Parquet,
     Jsonl,
     Json,
     Arrow,
+    Avro,
     Fwf,
     Sqlite,
     Excel,
+    Ods,
     Logfmt,
 }
 
@@ -1,26 +1,44 @@
 use crate::{
     reader::{ArrowIpcToDataFrame, Source},
-    tui::popups::{importers::final_step, path_picker::PathPicker, wizard::WizardState},
+    tui::popups::{
+        importers::{final_step, row_window::RowWindow},
+        path_picker::PathPicker,
+        wizard::WizardState,
+    },
 };
 
 #[derive(Debug)]
 pub enum State {
     PickImportPath { picker: PathPicker },
+    PickRows { source: Source, rows: RowWindow },
 }
 
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
-            State::PickImportPath { picker } => {
-                final_step(Source::File(picker.path()), ArrowIpcToDataFrame);
-                Default::default()
-            }
+            State::PickImportPath { picker } => State::PickRows {
+                source: Source::File(picker.path()),
+                rows: Default::default(),
+            },
+            State::PickRows { source, rows } => match rows.next() {
+                Ok((limit, offset)) => {
+                    final_step(
+                        source,
+                        ArrowIpcToDataFrame::default()
+                            .with_limit(limit)
+                            .with_offset(offset),
+                    );
+                    Default::default()
+                }
+                Err(rows) => State::PickRows { source, rows },
+            },
         }
     }
 
     fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
         match self {
             State::PickImportPath { picker } => picker,
+            State::PickRows { source: _, rows } => rows.responder(),
         }
     }
 }
@@ -0,0 +1,53 @@
+use clap::ValueEnum;
+
+use crate::{
+    args::Format,
+    handler::message::Message,
+    reader::{DetectedToDataFrames, Source, sniff_format},
+    tui::popups::{importers::final_step, path_picker::PathPicker, wizard::WizardState},
+};
+
+#[derive(Debug)]
+pub enum State {
+    PickImportPath { picker: PathPicker },
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickImportPath { picker } => {
+                let path = picker.path();
+                let name = |format: Format| {
+                    format
+                        .to_possible_value()
+                        .map(|value| value.get_name().to_owned())
+                        .unwrap_or_default()
+                };
+                let toast = match (Format::from_extension(&path), sniff_format(&path)) {
+                    (Some(format), _) => format!("Importing as {}", name(format)),
+                    (None, Some(format)) => {
+                        format!("Detected {} from the content, importing", name(format))
+                    }
+                    (None, None) => "Unknown format, importing as csv".to_owned(),
+                };
+                final_step(Source::File(path), DetectedToDataFrames);
+                Message::AppShowToast(toast).enqueue();
+                Default::default()
+            }
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
+        match self {
+            State::PickImportPath { picker } => picker,
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::PickImportPath {
+            picker: Default::default(),
+        }
+    }
+}
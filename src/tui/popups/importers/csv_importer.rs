@@ -1,11 +1,15 @@
+use clap::ValueEnum;
+use strum::IntoEnumIterator;
+
 use crate::{
+    args::Encoding,
     handler::message::Message,
-    reader::{CsvToDataFrame, Source},
+    reader::{CsvToDataFrame, GlobMode, GlobToDataFrames, Source, is_glob},
     tui::{
-        pickers::text_picker::TextPicker,
+        pickers::{list_picker::ListPicker, text_picker::TextPicker},
         popups::{
             import_source_picker::{self, ImportSourcePicker},
-            importers::final_step,
+            importers::{final_step, row_window::RowWindow},
             path_picker::PathPicker,
             wizard::WizardState,
         },
@@ -20,28 +24,45 @@ pub enum State {
     PickPath {
         picker: PathPicker,
     },
+    PickGlobMode {
+        source: Source,
+        picker: ListPicker<GlobMode>,
+    },
     PickSeparator {
         source: Source,
+        mode: GlobMode,
         picker: TextPicker,
     },
     PickQuote {
         separator: char,
         source: Source,
+        mode: GlobMode,
         picker: TextPicker,
     },
+    PickEncoding {
+        separator: char,
+        quote: char,
+        source: Source,
+        mode: GlobMode,
+        picker: ListPicker<Encoding>,
+    },
+    PickRows {
+        separator: char,
+        quote: char,
+        encoding: Encoding,
+        source: Source,
+        mode: GlobMode,
+        rows: RowWindow,
+    },
 }
 
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
             State::PickSource { picker } => match picker.value() {
-                Some(import_source_picker::Source::Stdin) => State::PickSeparator {
-                    source: Source::Stdin,
-                    picker: TextPicker::default()
-                        .with_title("Separator")
-                        .with_max_len(1)
-                        .with_value(",".to_owned()),
-                },
+                Some(import_source_picker::Source::Stdin) => {
+                    pick_separator(Source::Stdin, GlobMode::Separate)
+                }
                 Some(import_source_picker::Source::File) => State::PickPath {
                     picker: Default::default(),
                 },
@@ -49,44 +70,112 @@ impl WizardState for State {
                     picker: Default::default(),
                 },
             },
-            State::PickPath { picker } => State::PickSeparator {
+            State::PickPath { picker } if is_glob(&picker.path()) => State::PickGlobMode {
                 source: Source::File(picker.path()),
-                picker: TextPicker::default()
-                    .with_title("Separator")
-                    .with_max_len(1)
-                    .with_value(",".to_owned()),
+                picker: ListPicker::new(GlobMode::iter().collect()).with_title("Matching Files"),
+            },
+            State::PickPath { picker } => {
+                pick_separator(Source::File(picker.path()), GlobMode::Separate)
+            }
+            State::PickGlobMode { source, picker } => match picker.selected_item() {
+                Some(mode) => pick_separator(source, *mode),
+                None => State::PickGlobMode { source, picker },
             },
-            State::PickSeparator { source, picker } => {
+            State::PickSeparator {
+                source,
+                mode,
+                picker,
+            } => {
                 if let Some(separator) = picker.value().chars().next() {
                     State::PickQuote {
                         separator,
                         source,
+                        mode,
                         picker: TextPicker::default()
                             .with_title("Quote")
                             .with_max_len(1)
                             .with_value("\"".to_owned()),
                     }
                 } else {
-                    State::PickSeparator { source, picker }
+                    State::PickSeparator {
+                        source,
+                        mode,
+                        picker,
+                    }
                 }
             }
             State::PickQuote {
                 separator,
                 source,
+                mode,
                 picker,
             } => {
-                Message::AppDismissOverlay.enqueue();
                 if let Some(quote) = picker.value().chars().next() {
+                    State::PickEncoding {
+                        separator,
+                        quote,
+                        source,
+                        mode,
+                        picker: ListPicker::new(Encoding::value_variants().to_vec())
+                            .with_title("Encoding"),
+                    }
+                } else {
+                    State::PickQuote {
+                        separator,
+                        source,
+                        mode,
+                        picker,
+                    }
+                }
+            }
+            State::PickEncoding {
+                separator,
+                quote,
+                source,
+                mode,
+                picker,
+            } => State::PickRows {
+                separator,
+                quote,
+                encoding: picker.selected_item().copied().unwrap_or(Encoding::Utf8),
+                source,
+                mode,
+                rows: Default::default(),
+            },
+            State::PickRows {
+                separator,
+                quote,
+                encoding,
+                source,
+                mode,
+                rows,
+            } => match rows.next() {
+                Ok((limit, offset)) => {
+                    Message::AppDismissOverlay.enqueue();
                     final_step(
                         source,
-                        CsvToDataFrame::default()
-                            .with_no_header(false)
-                            .with_separator(separator)
-                            .with_quote_char(quote),
+                        GlobToDataFrames::new(
+                            CsvToDataFrame::default()
+                                .with_no_header(false)
+                                .with_separator(separator)
+                                .with_quote_char(quote)
+                                .with_encoding(encoding)
+                                .with_limit(limit)
+                                .with_offset(offset),
+                            mode,
+                        ),
                     );
+                    Default::default()
                 }
-                Default::default()
-            }
+                Err(rows) => State::PickRows {
+                    separator,
+                    quote,
+                    encoding,
+                    source,
+                    mode,
+                    rows,
+                },
+            },
         }
     }
 
@@ -94,12 +183,33 @@ impl WizardState for State {
         match self {
             State::PickSource { picker } => picker,
             State::PickPath { picker } => picker,
-            State::PickSeparator { source: _, picker } => picker,
+            State::PickGlobMode { source: _, picker } => picker,
+            State::PickSeparator {
+                source: _,
+                mode: _,
+                picker,
+            } => picker,
             State::PickQuote {
                 separator: _,
                 source: _,
+                mode: _,
+                picker,
+            } => picker,
+            State::PickEncoding {
+                separator: _,
+                quote: _,
+                source: _,
+                mode: _,
                 picker,
             } => picker,
+            State::PickRows {
+                separator: _,
+                quote: _,
+                encoding: _,
+                source: _,
+                mode: _,
+                rows,
+            } => rows.responder(),
         }
     }
 }
@@ -111,3 +221,14 @@ impl Default for State {
         }
     }
 }
+
+fn pick_separator(source: Source, mode: GlobMode) -> State {
+    State::PickSeparator {
+        source,
+        mode,
+        picker: TextPicker::default()
+            .with_title("Separator")
+            .with_max_len(1)
+            .with_value(",".to_owned()),
+    }
+}
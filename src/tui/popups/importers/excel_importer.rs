@@ -1,30 +1,79 @@
+use std::fmt::Display;
+
+use strum::IntoEnumIterator;
+use strum_macros::{EnumIter, IntoStaticStr};
+
 use crate::{
     reader::{ExcelToDataFarmes, Source},
-    tui::popups::{importers::final_step, path_picker::PathPicker, wizard::WizardState},
+    tui::{
+        pickers::list_picker::ListPicker,
+        popups::{importers::final_step, path_picker::PathPicker, wizard::WizardState},
+    },
 };
 
 #[derive(Debug)]
 pub enum State {
-    PickPath { picker: PathPicker },
+    PickPath {
+        picker: PathPicker,
+    },
+    PickHeader {
+        source: Source,
+        picker: ListPicker<Header>,
+    },
 }
 
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
-            State::PickPath { picker } => {
-                final_step(Source::File(picker.path()), ExcelToDataFarmes);
-                State::PickPath { picker }
-            }
+            State::PickPath { picker } => State::PickHeader {
+                source: Source::File(picker.path()),
+                picker: ListPicker::new(Header::iter().collect()).with_title("Has Header"),
+            },
+            State::PickHeader { source, picker } => match picker.selected_item() {
+                Some(header) => {
+                    final_step(
+                        source,
+                        ExcelToDataFarmes::default().with_has_header(header.has_header()),
+                    );
+                    Default::default()
+                }
+                None => State::PickHeader { source, picker },
+            },
         }
     }
 
     fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
         match self {
             State::PickPath { picker } => picker,
+            State::PickHeader { source: _, picker } => picker,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, IntoStaticStr, EnumIter)]
+pub enum Header {
+    Detect,
+    Yes,
+    No,
+}
+
+impl Header {
+    /// Header setting of the spreadsheet readers, None to detect it per sheet.
+    pub fn has_header(&self) -> Option<bool> {
+        match self {
+            Header::Detect => None,
+            Header::Yes => Some(true),
+            Header::No => Some(false),
         }
     }
 }
 
+impl Display for Header {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Into::<&str>::into(self))
+    }
+}
+
 impl Default for State {
     fn default() -> Self {
         State::PickPath {
@@ -1,5 +1,6 @@
 use crate::{
-    reader::{FwfToDataFrame, Source},
+    handler::message::Message,
+    reader::{FwfToDataFrame, Source, read_schema_file},
     tui::{
         pickers::text_picker::TextPicker,
         popups::{
@@ -21,17 +22,23 @@ pub enum State {
     PickPath {
         picker: PathPicker,
     },
+    PickSchemaFile {
+        source: Source,
+        picker: TextPicker,
+    },
     PickWidths {
         source: Source,
         picker: TextPicker,
     },
     PickHeader {
+        names: Vec<String>,
         widths: Vec<usize>,
         source: Source,
         picker: YesNoPicker,
     },
     PickSeparatorLength {
         has_header: bool,
+        names: Vec<String>,
         widths: Vec<usize>,
         source: Source,
         picker: TextPicker,
@@ -39,6 +46,7 @@ pub enum State {
     PickFlexibleWidth {
         separator_length: usize,
         has_header: bool,
+        names: Vec<String>,
         widths: Vec<usize>,
         source: Source,
         picker: YesNoPicker,
@@ -52,22 +60,44 @@ impl WizardState for State {
                 Some(import_source_picker::Source::File) => State::PickPath {
                     picker: PathPicker::default(),
                 },
-                Some(import_source_picker::Source::Stdin) => State::PickWidths {
+                Some(import_source_picker::Source::Stdin) => State::PickSchemaFile {
                     source: Source::Stdin,
-                    picker: TextPicker::default()
-                        .with_input_type(InputType::MultiNumeric)
-                        .with_title("Widths")
-                        .with_hint("4 8 12 or leave empty to auto detect"),
+                    picker: schema_file_picker(),
                 },
                 None => State::PickSource { picker },
             },
-            State::PickPath { picker } => State::PickWidths {
+            State::PickPath { picker } => State::PickSchemaFile {
                 source: Source::File(picker.path()),
-                picker: TextPicker::default()
-                    .with_input_type(InputType::MultiNumeric)
-                    .with_title("Widths")
-                    .with_hint("4 8 12 or leave empty to auto detect"),
+                picker: schema_file_picker(),
             },
+            State::PickSchemaFile { source, picker } => {
+                let path = picker.value().trim();
+                if path.is_empty() {
+                    State::PickWidths {
+                        source,
+                        picker: TextPicker::default()
+                            .with_input_type(InputType::MultiNumeric)
+                            .with_title("Widths")
+                            .with_hint("4 8 12 or leave empty to auto detect"),
+                    }
+                } else {
+                    match read_schema_file(path) {
+                        Ok(schema) => {
+                            let (names, widths) = schema.into_iter().unzip();
+                            State::PickHeader {
+                                names,
+                                widths,
+                                source,
+                                picker: YesNoPicker::default().with_title("Has Header"),
+                            }
+                        }
+                        Err(err) => {
+                            Message::AppShowError(err.to_string()).enqueue();
+                            State::PickSchemaFile { source, picker }
+                        }
+                    }
+                }
+            }
             State::PickWidths { source, picker } => {
                 let widths = picker
                     .value()
@@ -76,18 +106,21 @@ impl WizardState for State {
                     .collect::<Result<_, _>>()
                     .unwrap_or_default();
                 State::PickHeader {
+                    names: Vec::default(),
                     widths,
                     source,
                     picker: YesNoPicker::default().with_title("Has Header"),
                 }
             }
             State::PickHeader {
+                names,
                 widths,
                 source,
                 picker,
             } => match picker.value() {
                 Some(has_header) => State::PickSeparatorLength {
                     has_header,
+                    names,
                     widths,
                     source,
                     picker: TextPicker::default()
@@ -95,6 +128,7 @@ impl WizardState for State {
                         .with_title("Separator Legnth"),
                 },
                 None => State::PickHeader {
+                    names,
                     widths,
                     source,
                     picker,
@@ -102,6 +136,7 @@ impl WizardState for State {
             },
             State::PickSeparatorLength {
                 has_header,
+                names,
                 widths,
                 source,
                 picker,
@@ -110,6 +145,7 @@ impl WizardState for State {
                 State::PickFlexibleWidth {
                     separator_length,
                     has_header,
+                    names,
                     widths,
                     source,
                     picker: YesNoPicker::default().with_title("Flexible Width"),
@@ -118,6 +154,7 @@ impl WizardState for State {
             State::PickFlexibleWidth {
                 separator_length,
                 has_header,
+                names,
                 widths,
                 source,
                 picker,
@@ -127,7 +164,8 @@ impl WizardState for State {
                     .with_flexible_width(flexible_width)
                     .with_has_header(has_header)
                     .with_separator_length(separator_length)
-                    .with_widths(widths);
+                    .with_widths(widths)
+                    .with_names(names);
                 final_step(source, rtdf);
                 Default::default()
             }
@@ -138,14 +176,17 @@ impl WizardState for State {
         match self {
             State::PickSource { picker } => picker,
             State::PickPath { picker } => picker,
+            State::PickSchemaFile { source: _, picker } => picker,
             State::PickWidths { source: _, picker } => picker,
             State::PickHeader {
+                names: _,
                 widths: _,
                 source: _,
                 picker,
             } => picker,
             State::PickSeparatorLength {
                 has_header: _,
+                names: _,
                 widths: _,
                 source: _,
                 picker,
@@ -153,6 +194,7 @@ impl WizardState for State {
             State::PickFlexibleWidth {
                 separator_length: _,
                 has_header: _,
+                names: _,
                 widths: _,
                 source: _,
                 picker,
@@ -161,6 +203,12 @@ impl WizardState for State {
     }
 }
 
+fn schema_file_picker() -> TextPicker {
+    TextPicker::default()
+        .with_title("Schema File")
+        .with_hint("Path to a 'name:width' per line file or leave empty")
+}
+
 impl Default for State {
     fn default() -> Self {
         Self::PickSource {
@@ -2,7 +2,7 @@ use crate::{
     reader::{JsonToDataFrame, Source},
     tui::popups::{
         import_source_picker::{self, ImportSourcePicker},
-        importers::final_step,
+        importers::{final_step, row_window::RowWindow},
         path_picker::PathPicker,
         wizard::WizardState,
     },
@@ -12,25 +12,38 @@ use crate::{
 pub enum State {
     PickSource { picker: ImportSourcePicker },
     PickPath { picker: PathPicker },
+    PickRows { source: Source, rows: RowWindow },
 }
 
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
             State::PickSource { picker } => match picker.value() {
-                Some(import_source_picker::Source::Stdin) => {
-                    final_step(Source::Stdin, JsonToDataFrame::default());
-                    State::PickSource { picker }
-                }
+                Some(import_source_picker::Source::Stdin) => State::PickRows {
+                    source: Source::Stdin,
+                    rows: Default::default(),
+                },
                 Some(import_source_picker::Source::File) => State::PickPath {
                     picker: Default::default(),
                 },
                 None => State::PickSource { picker },
             },
-            State::PickPath { picker } => {
-                final_step(Source::File(picker.path()), JsonToDataFrame::default());
-                Default::default()
-            }
+            State::PickPath { picker } => State::PickRows {
+                source: Source::File(picker.path()),
+                rows: Default::default(),
+            },
+            State::PickRows { source, rows } => match rows.next() {
+                Ok((limit, offset)) => {
+                    final_step(
+                        source,
+                        JsonToDataFrame::default()
+                            .with_limit(limit)
+                            .with_offset(offset),
+                    );
+                    Default::default()
+                }
+                Err(rows) => State::PickRows { source, rows },
+            },
         }
     }
 
@@ -38,6 +51,7 @@ impl WizardState for State {
         match self {
             State::PickSource { picker } => picker,
             State::PickPath { picker } => picker,
+            State::PickRows { source: _, rows } => rows.responder(),
         }
     }
 }
@@ -2,35 +2,82 @@ use crate::{
     reader::{JsonLineToDataFrame, Source},
     tui::popups::{
         import_source_picker::{self, ImportSourcePicker},
-        importers::final_step,
+        importers::{final_step, row_window::RowWindow},
         path_picker::PathPicker,
         wizard::WizardState,
+        yes_no_picker::YesNoPicker,
     },
 };
 
 #[derive(Debug)]
 pub enum State {
-    PickSource { picker: ImportSourcePicker },
-    PickPath { picker: PathPicker },
+    PickSource {
+        picker: ImportSourcePicker,
+    },
+    PickPath {
+        picker: PathPicker,
+    },
+    PickRows {
+        source: Source,
+        rows: RowWindow,
+    },
+    PickSkipErrors {
+        source: Source,
+        limit: Option<usize>,
+        offset: usize,
+        picker: YesNoPicker,
+    },
 }
 
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
             State::PickSource { picker } => match picker.value() {
-                Some(import_source_picker::Source::Stdin) => {
-                    final_step(Source::Stdin, JsonLineToDataFrame::default());
-                    State::PickSource { picker }
-                }
+                Some(import_source_picker::Source::Stdin) => State::PickRows {
+                    source: Source::Stdin,
+                    rows: Default::default(),
+                },
                 Some(import_source_picker::Source::File) => State::PickPath {
                     picker: Default::default(),
                 },
                 None => State::PickSource { picker },
             },
-            State::PickPath { picker } => {
-                final_step(Source::File(picker.path()), JsonLineToDataFrame::default());
-                Default::default()
-            }
+            State::PickPath { picker } => State::PickRows {
+                source: Source::File(picker.path()),
+                rows: Default::default(),
+            },
+            State::PickRows { source, rows } => match rows.next() {
+                Ok((limit, offset)) => State::PickSkipErrors {
+                    source,
+                    limit,
+                    offset,
+                    picker: YesNoPicker::default().with_title("Skip Malformed Lines"),
+                },
+                Err(rows) => State::PickRows { source, rows },
+            },
+            State::PickSkipErrors {
+                source,
+                limit,
+                offset,
+                picker,
+            } => match picker.value() {
+                Some(skip_errors) => {
+                    final_step(
+                        source,
+                        JsonLineToDataFrame::default()
+                            .with_limit(limit)
+                            .with_offset(offset)
+                            .with_skip_errors(skip_errors),
+                    );
+                    Default::default()
+                }
+                None => State::PickSkipErrors {
+                    source,
+                    limit,
+                    offset,
+                    picker,
+                },
+            },
         }
     }
 
@@ -38,6 +85,13 @@ impl WizardState for State {
         match self {
             State::PickSource { picker } => picker,
             State::PickPath { picker } => picker,
+            State::PickRows { source: _, rows } => rows.responder(),
+            State::PickSkipErrors {
+                source: _,
+                limit: _,
+                offset: _,
+                picker,
+            } => picker,
         }
     }
 }
@@ -1,29 +1,49 @@
+use std::sync::Arc;
+
 use crate::{
     handler::message::Message,
     misc::globals::sql,
-    reader::{ReadToDataFrames, Source},
+    reader::{ImportProgress, ReadToDataFrames, Source},
 };
 
 pub mod arrow_importer;
+pub mod auto_importer;
+pub mod avro_importer;
 pub mod csv_importer;
 pub mod excel_importer;
 pub mod fwf_importer;
 pub mod json_importer;
 pub mod jsonl_importer;
 pub mod logfmt_importer;
+pub mod ods_importer;
 pub mod parquet_importer;
+mod row_window;
 pub mod sqlite_importer;
 pub mod tsv_importer;
 
-fn final_step(source: Source, rtdf: impl ReadToDataFrames) {
+/// Reads the frames on a worker thread and opens a tab for each of them, unless the import is
+/// cancelled from the status bar before it finishes. Chunked readers stop at the next chunk of
+/// a cancelled import.
+fn final_step(source: Source, rtdf: impl ReadToDataFrames + Send + Sync + 'static) {
+    let rtdf = Arc::new(rtdf);
     Message::AppDismissOverlay.enqueue();
-    match rtdf.named_frames(source.clone()) {
-        Ok(named_frames) => {
-            for (name, df) in named_frames {
-                let name = sql().register(&name, df.clone(), source.clone());
-                Message::TabsAddNamePane(df, name).enqueue();
+    let progress = ImportProgress::new();
+    Message::TabsShowImportProgress(progress.clone()).enqueue();
+    std::thread::spawn(move || {
+        let result = rtdf.named_frames_with_progress(source.clone(), &progress);
+        Message::TabsDismissImportProgress(progress.clone()).enqueue();
+        if progress.is_cancelled() {
+            return;
+        }
+        match result {
+            Ok(named_frames) => {
+                for (name, df) in named_frames {
+                    let name = sql().register(&name, df.clone(), source.clone());
+                    sql().set_reader(&name, rtdf.clone());
+                    Message::TabsAddNamePane(df, name).enqueue();
+                }
             }
+            Err(err) => Message::AppShowError(err.to_string()).enqueue(),
         }
-        Err(err) => Message::AppShowError(err.to_string()).enqueue(),
-    }
+    });
 }
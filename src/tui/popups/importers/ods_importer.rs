@@ -0,0 +1,60 @@
+use strum::IntoEnumIterator;
+
+use crate::{
+    reader::{OdsToDataFrame, Source},
+    tui::{
+        pickers::list_picker::ListPicker,
+        popups::{
+            importers::{excel_importer::Header, final_step},
+            path_picker::PathPicker,
+            wizard::WizardState,
+        },
+    },
+};
+
+#[derive(Debug)]
+pub enum State {
+    PickPath {
+        picker: PathPicker,
+    },
+    PickHeader {
+        source: Source,
+        picker: ListPicker<Header>,
+    },
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickPath { picker } => State::PickHeader {
+                source: Source::File(picker.path()),
+                picker: ListPicker::new(Header::iter().collect()).with_title("Has Header"),
+            },
+            State::PickHeader { source, picker } => match picker.selected_item() {
+                Some(header) => {
+                    final_step(
+                        source,
+                        OdsToDataFrame::default().with_has_header(header.has_header()),
+                    );
+                    Default::default()
+                }
+                None => State::PickHeader { source, picker },
+            },
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
+        match self {
+            State::PickPath { picker } => picker,
+            State::PickHeader { source: _, picker } => picker,
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::PickPath {
+            picker: Default::default(),
+        }
+    }
+}
@@ -1,26 +1,89 @@
+use itertools::Itertools;
+
 use crate::{
     reader::{ParquetToDataFrame, Source},
-    tui::popups::{importers::final_step, path_picker::PathPicker, wizard::WizardState},
+    tui::{
+        pickers::text_picker::TextPicker,
+        popups::{
+            importers::{final_step, row_window::RowWindow},
+            path_picker::PathPicker,
+            wizard::WizardState,
+        },
+    },
 };
 
 #[derive(Debug)]
 pub enum State {
-    PickPath { picker: PathPicker },
+    PickPath {
+        picker: PathPicker,
+    },
+    PickColumns {
+        source: Source,
+        picker: TextPicker,
+    },
+    PickRows {
+        source: Source,
+        columns: Vec<String>,
+        rows: RowWindow,
+    },
 }
 
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
-            State::PickPath { picker } => {
-                final_step(Source::File(picker.path()), ParquetToDataFrame);
-                Default::default()
+            State::PickPath { picker } => State::PickColumns {
+                source: Source::File(picker.path()),
+                picker: TextPicker::default()
+                    .with_title("Columns")
+                    .with_hint("Comma separated names, empty reads every column"),
+            },
+            State::PickColumns { source, picker } => {
+                let columns = picker
+                    .value()
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|column| !column.is_empty())
+                    .map(str::to_owned)
+                    .collect_vec();
+                State::PickRows {
+                    source,
+                    columns,
+                    rows: Default::default(),
+                }
             }
+            State::PickRows {
+                source,
+                columns,
+                rows,
+            } => match rows.next() {
+                Ok((limit, offset)) => {
+                    final_step(
+                        source,
+                        ParquetToDataFrame::default()
+                            .with_columns(columns)
+                            .with_limit(limit)
+                            .with_offset(offset),
+                    );
+                    Default::default()
+                }
+                Err(rows) => State::PickRows {
+                    source,
+                    columns,
+                    rows,
+                },
+            },
         }
     }
 
     fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
         match self {
             State::PickPath { picker } => picker,
+            State::PickColumns { source: _, picker } => picker,
+            State::PickRows {
+                source: _,
+                columns: _,
+                rows,
+            } => rows.responder(),
         }
     }
 }
@@ -0,0 +1,51 @@
+use crate::tui::{
+    component::Component, pickers::text_picker::TextPicker, widgets::input::InputType,
+};
+
+/// Rows of the input to read, the limit and then the offset, picked after the reader options.
+#[derive(Debug)]
+pub enum RowWindow {
+    PickLimit {
+        picker: TextPicker,
+    },
+    PickOffset {
+        limit: Option<usize>,
+        picker: TextPicker,
+    },
+}
+
+impl RowWindow {
+    /// Moves on to the offset, returns the limit and the offset once both are picked.
+    pub fn next(self) -> Result<(Option<usize>, usize), Self> {
+        match self {
+            RowWindow::PickLimit { picker } => Err(RowWindow::PickOffset {
+                limit: picker.value().trim().parse().ok(),
+                picker: TextPicker::default()
+                    .with_input_type(InputType::Numeric)
+                    .with_title("Offset")
+                    .with_hint("Number of records to skip or leave empty to start at the first"),
+            }),
+            RowWindow::PickOffset { limit, picker } => {
+                Ok((limit, picker.value().trim().parse().unwrap_or_default()))
+            }
+        }
+    }
+
+    pub fn responder(&mut self) -> &mut dyn Component {
+        match self {
+            RowWindow::PickLimit { picker } => picker,
+            RowWindow::PickOffset { limit: _, picker } => picker,
+        }
+    }
+}
+
+impl Default for RowWindow {
+    fn default() -> Self {
+        RowWindow::PickLimit {
+            picker: TextPicker::default()
+                .with_input_type(InputType::Numeric)
+                .with_title("Limit")
+                .with_hint("Number of records to read or leave empty to read all"),
+        }
+    }
+}
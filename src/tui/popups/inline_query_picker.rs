@@ -1,29 +1,81 @@
+use anyhow::anyhow;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use itertools::Itertools;
 use polars::frame::DataFrame;
 
 use crate::{
     AppResult,
     handler::message::Message,
-    misc::globals::sql,
-    tui::{component::Component, pane::TableDescription, pickers::text_picker::TextPicker},
+    misc::{
+        globals::sql,
+        polars_ext::{DataFrameExt, RenameSpec, UnpivotSpec},
+        sql::{group_by_query, join_query},
+        type_ext::UnwrapOrEnqueueError,
+    },
+    tui::{
+        component::Component,
+        pane::{PaneId, TableDescription},
+        pickers::text_picker::TextPicker,
+        popups::column_caster_wizard::TargetType,
+        themes::theme::Theme,
+        widgets::completion::Completer,
+    },
+    writer::TextFormat,
 };
 
 #[derive(Debug)]
 pub struct InlineQueryPicker {
+    pane: PaneId,
     df: DataFrame,
+    lazy_table: Option<String>,
     text_picker: TextPicker,
     query_type: QueryType,
+    completer: Completer,
 }
 
 impl InlineQueryPicker {
-    pub fn new(df: DataFrame, query_type: QueryType) -> Self {
+    pub fn new(pane: PaneId, df: DataFrame, query_type: QueryType) -> Self {
         Self {
-            text_picker: TextPicker::default().with_title(query_type.title()),
+            text_picker: match query_type.hint() {
+                Some(hint) => TextPicker::default()
+                    .with_title(query_type.title())
+                    .with_hint(hint),
+                None => TextPicker::default().with_title(query_type.title()),
+            },
             query_type,
+            completer: Completer::new(
+                "Columns (Tab)",
+                df.get_column_names()
+                    .into_iter()
+                    .map(|name| name.to_string())
+                    .chain(match query_type {
+                        QueryType::Join | QueryType::Diff => sql()
+                            .schema()
+                            .iter()
+                            .map(|(name, _)| name.to_owned())
+                            .collect_vec(),
+                        _ => Vec::new(),
+                    })
+                    .collect(),
+            ),
+            pane,
             df,
+            lazy_table: None,
         }
     }
 
+    /// Runs the queries on the lazily scanned table the frame is a preview of.
+    pub fn with_lazy_table(mut self, lazy_table: Option<String>) -> Self {
+        self.lazy_table = lazy_table;
+        self
+    }
+
+    /// Starts the editor with the given text, the select editor shows the current columns.
+    pub fn with_value(mut self, value: String) -> Self {
+        self.text_picker.set_value(value);
+        self
+    }
+
     pub fn value(&self) -> &str {
         self.text_picker.input().value()
     }
@@ -33,18 +85,157 @@ impl InlineQueryPicker {
     }
 
     fn sql_query(&self, query: &str) -> AppResult<DataFrame> {
-        Ok(sql().execute(query, self.df.clone())?)
+        let mut sql = sql();
+        match &self.lazy_table {
+            Some(name) => {
+                sql.set_default(name)?;
+                Ok(sql.execute_timed(query, None)?)
+            }
+            None => Ok(sql.execute_timed(query, self.df.clone())?),
+        }
     }
 
-    fn select(&self, select: &str) -> AppResult<DataFrame> {
-        self.sql_query(&format!("SELECT {select} FROM _"))
+    /// The whole table the queries run on, collected if it was scanned lazily.
+    fn data_frame(&self) -> AppResult<DataFrame> {
+        match &self.lazy_table {
+            Some(_) => self.sql_query("SELECT * FROM _"),
+            None => Ok(self.df.clone()),
+        }
+    }
+
+    /// Plans the query right away and collects it on a worker thread, `done` gets the result
+    /// unless the query is cancelled from the status bar first.
+    fn spawn_query(
+        &self,
+        query: &str,
+        done: impl FnOnce(DataFrame) + Send + 'static,
+    ) -> AppResult<()> {
+        let mut sql = sql();
+        let prepared = match &self.lazy_table {
+            Some(name) => {
+                sql.set_default(name)?;
+                sql.prepare(query, None)?
+            }
+            None => sql.prepare(query, self.df.clone())?,
+        };
+        prepared.spawn(done);
+        Ok(())
+    }
+
+    fn select(&self, select: &str) -> String {
+        format!("SELECT {select} FROM _")
+    }
+    fn order(&self, order: &str) -> String {
+        format!("SELECT * FROM _ ORDER BY {order}")
     }
-    fn order(&self, order: &str) -> AppResult<DataFrame> {
-        self.sql_query(&format!("SELECT * FROM _ ORDER BY {order}"))
+    fn filter(&self, filter: &str) -> String {
+        format!("SELECT * FROM _ where {filter}")
     }
-    fn filter(&self, filter: &str) -> AppResult<DataFrame> {
-        self.sql_query(&format!("SELECT * FROM _ where {filter}"))
+
+    fn join(&self, spec: &str) -> AppResult<()> {
+        let (other, query) = join_query(spec)?;
+        ensure_registered(&other)?;
+        self.spawn_query(&query, move |df| {
+            Message::TabsAddNamePane(df, format!("join {other}")).enqueue()
+        })
+    }
+    /// Compares with a registered table given as `other [on key]`, rows are aligned by position
+    /// without a key.
+    fn diff(&self, spec: &str) -> AppResult<(DataFrame, String)> {
+        let mut tokens = spec.split_whitespace();
+        let other = tokens
+            .next()
+            .map(|name| name.trim_matches('"').to_owned())
+            .ok_or(anyhow!("Diff needs a table to compare with"))?;
+        let key = match (tokens.next(), tokens.next(), tokens.next()) {
+            (None, _, _) => None,
+            (Some(on), Some(key), None) if on.eq_ignore_ascii_case("on") => {
+                Some(key.trim_matches('"'))
+            }
+            _ => Err(anyhow!(
+                "Invalid diff '{spec}', expected 'other_table [on key]'"
+            ))?,
+        };
+        ensure_registered(&other)?;
+        let other_df =
+            self.sql_query(&format!("SELECT * FROM \"{}\"", other.replace('"', "\"\"")))?;
+        Ok((self.data_frame()?.diff_with(&other_df, key)?, other))
+    }
+}
+
+/// Theme picked by its name ignoring case, an empty name clears the override of the tab.
+fn tab_theme(name: &str) -> AppResult<Option<Theme>> {
+    match name.trim() {
+        "" => Ok(None),
+        name => Theme::all()
+            .find(|theme| theme.id().eq_ignore_ascii_case(name))
+            .map(Some)
+            .ok_or(anyhow!(
+                "Unknown theme '{name}', the theme selector lists the available ones"
+            )),
+    }
+}
+
+/// Format of a table copied to the clipboard, tab separated values paste into spreadsheets.
+fn clipboard_format(format: &str) -> AppResult<TextFormat> {
+    match format.trim() {
+        "" => Ok(TextFormat::Tsv),
+        format => format.parse().map_err(|_| {
+            anyhow!("Unknown format '{format}', expected one of tsv, csv, json or md")
+        }),
+    }
+}
+
+/// Splits `name as query` at the first `as`, the name may be quoted.
+fn view_spec(spec: &str) -> AppResult<(String, String)> {
+    let (name, query) = spec
+        .trim()
+        .split_once(char::is_whitespace)
+        .and_then(|(name, rest)| {
+            let (keyword, query) = rest.trim_start().split_once(char::is_whitespace)?;
+            keyword
+                .eq_ignore_ascii_case("as")
+                .then_some((name, query.trim()))
+        })
+        .filter(|(_, query)| !query.is_empty())
+        .ok_or(anyhow!("Invalid view '{spec}', expected 'name as query'"))?;
+    Ok((name.trim_matches('"').to_owned(), query.to_owned()))
+}
+
+/// Parses `column type [--strict=false]`, the column name may contain spaces.
+fn cast_spec(spec: &str) -> AppResult<(String, TargetType, bool)> {
+    let (rest, strict) = match spec.trim().rsplit_once(char::is_whitespace) {
+        Some((rest, flag)) if flag.starts_with("--strict") => match flag {
+            "--strict" | "--strict=true" => (rest, true),
+            "--strict=false" => (rest, false),
+            _ => Err(anyhow!(
+                "Invalid flag '{flag}', expected --strict=true or --strict=false"
+            ))?,
+        },
+        _ => (spec.trim(), true),
+    };
+    let (column, target_type) = rest
+        .trim_end()
+        .rsplit_once(char::is_whitespace)
+        .ok_or(anyhow!("Invalid cast '{spec}', expected 'column type'"))?;
+    let target_type = target_type.parse::<TargetType>().map_err(|_| {
+        anyhow!("Unknown type '{target_type}', expected int, float, str, bool, date or datetime")
+    })?;
+    Ok((
+        column.trim().trim_matches('"').to_owned(),
+        target_type,
+        strict,
+    ))
+}
+
+fn ensure_registered(name: &str) -> AppResult<()> {
+    if sql().schema().get(name).is_none() {
+        Err(anyhow!(
+            "Table '{name}' is not registered, available tables: {}",
+            sql().schema().iter().map(|(name, _)| name).join(", ")
+        ))?
     }
+    Ok(())
 }
 
 impl Component for InlineQueryPicker {
@@ -55,47 +246,139 @@ impl Component for InlineQueryPicker {
         focus_state: crate::tui::component::FocusState,
     ) {
         self.text_picker.render(area, buf, focus_state);
+        self.completer.render(self.text_picker.input(), buf);
     }
 
     fn handle(&mut self, event: KeyEvent) -> bool {
+        if let (KeyCode::Tab, KeyModifiers::NONE) = (event.code, event.modifiers) {
+            self.completer.complete(self.text_picker.input_mut());
+            return true;
+        }
+        self.completer.reset();
         self.text_picker.handle(event)
             || match (event.code, event.modifiers) {
                 (KeyCode::Enter, KeyModifiers::NONE) => {
+                    let value = self.value();
                     let result = match self.query_type {
-                        QueryType::Select => self.select(self.value()),
-                        QueryType::Filter => self.filter(self.value()),
-                        QueryType::Order => self.order(self.value()),
-                    };
-                    match (result, self.query_type) {
-                        (Ok(df), QueryType::Select) => {
+                        QueryType::Select => Ok((
+                            self.select(value),
+                            TableDescription::Select(value.to_owned()),
+                        )),
+                        QueryType::Filter => Ok((
+                            self.filter(value),
+                            TableDescription::Filter(value.to_owned()),
+                        )),
+                        QueryType::Order => {
+                            Ok((self.order(value), TableDescription::Order(value.to_owned())))
+                        }
+                        QueryType::GroupBy => group_by_query(value)
+                            .map(|query| (query.clone(), TableDescription::Query(query))),
+                        // Joins open a new tab instead of replacing the table of this one
+                        QueryType::Join => {
+                            Message::PaneDismissModal.enqueue();
+                            self.join(value).unwrap_or_enqueue_error();
+                            return true;
+                        }
+                        QueryType::Diff => {
+                            Message::PaneDismissModal.enqueue();
+                            match self.diff(value) {
+                                Ok((df, other)) if df.height() == 0 => {
+                                    Message::AppShowToast(format!("No differences to '{other}'"))
+                                        .enqueue()
+                                }
+                                Ok((df, other)) => {
+                                    Message::TabsAddNamePane(df, format!("diff {other}")).enqueue()
+                                }
+                                Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+                            }
+                            return true;
+                        }
+                        QueryType::Unpivot => {
+                            Message::PaneDismissModal.enqueue();
+                            match value
+                                .parse::<UnpivotSpec>()
+                                .and_then(|spec| self.data_frame()?.melt(&spec))
+                            {
+                                Ok((df, cast)) => {
+                                    if let Some(dtype) = cast {
+                                        Message::AppShowToast(format!(
+                                            "Unpivoted values were cast to {dtype}"
+                                        ))
+                                        .enqueue();
+                                    }
+                                    Message::TabsAddNamePane(df, format!("unpivot {value}"))
+                                        .enqueue()
+                                }
+                                Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+                            }
+                            return true;
+                        }
+                        QueryType::Cast => {
                             Message::PaneDismissModal.enqueue();
-                            Message::PanePushDataFrame(
-                                df,
-                                TableDescription::Select(self.value().to_owned()),
-                            )
-                            .enqueue();
+                            match cast_spec(value) {
+                                Ok((column, target_type, strict)) => {
+                                    Message::PaneCastColumn(column, target_type, strict).enqueue()
+                                }
+                                Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+                            }
+                            return true;
                         }
-                        (Ok(df), QueryType::Order) => {
+                        QueryType::Count => {
                             Message::PaneDismissModal.enqueue();
-                            Message::PanePushDataFrame(
-                                df,
-                                TableDescription::Order(self.value().to_owned()),
-                            )
-                            .enqueue();
+                            Message::PaneCount(value.to_owned()).enqueue();
+                            return true;
                         }
-                        (Ok(df), QueryType::Filter) => {
+                        QueryType::AddColumn => {
                             Message::PaneDismissModal.enqueue();
-                            Message::PanePushDataFrame(
-                                df,
-                                TableDescription::Filter(self.value().to_owned()),
-                            )
-                            .enqueue();
+                            Message::PaneAddColumn(value.to_owned()).enqueue();
+                            return true;
                         }
-                        (Err(err), _) => {
+                        QueryType::TabTheme => {
                             Message::PaneDismissModal.enqueue();
-                            Message::AppShowError(err.to_string()).enqueue();
+                            match tab_theme(value) {
+                                Ok(theme) => Message::PaneSetTheme(theme).enqueue(),
+                                Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+                            }
+                            return true;
                         }
-                    }
+                        QueryType::ExportClipboard => {
+                            Message::PaneDismissModal.enqueue();
+                            match clipboard_format(value) {
+                                Ok(format) => Message::PaneExportClipboard(format).enqueue(),
+                                Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+                            }
+                            return true;
+                        }
+                        QueryType::View => {
+                            Message::PaneDismissModal.enqueue();
+                            match view_spec(value).and_then(|(name, query)| {
+                                sql().create_view(&name, &query)?;
+                                Ok(name)
+                            }) {
+                                Ok(name) => Message::AppShowToast(format!("View '{name}' created"))
+                                    .enqueue(),
+                                Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+                            }
+                            return true;
+                        }
+                        QueryType::Rename => {
+                            Message::PaneDismissModal.enqueue();
+                            match value.parse::<RenameSpec>() {
+                                Ok(spec) => Message::PaneRenameColumns(spec).enqueue(),
+                                Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+                            }
+                            return true;
+                        }
+                    };
+                    Message::PaneDismissModal.enqueue();
+                    let pane = self.pane;
+                    result
+                        .and_then(|(query, description)| {
+                            self.spawn_query(&query, move |df| {
+                                Message::PanePushQueryResult(pane, df, description).enqueue()
+                            })
+                        })
+                        .unwrap_or_enqueue_error();
                     true
                 }
                 (KeyCode::Esc, KeyModifiers::NONE) => {
@@ -112,6 +395,17 @@ pub enum QueryType {
     Select,
     Filter,
     Order,
+    GroupBy,
+    Join,
+    Diff,
+    Unpivot,
+    Rename,
+    Cast,
+    View,
+    ExportClipboard,
+    TabTheme,
+    AddColumn,
+    Count,
 }
 
 impl QueryType {
@@ -120,7 +414,35 @@ impl QueryType {
             QueryType::Select => "Select",
             QueryType::Filter => "Filter",
             QueryType::Order => "Order",
+            QueryType::GroupBy => "Group By",
+            QueryType::Join => "Join",
+            QueryType::Diff => "Diff",
+            QueryType::Unpivot => "Unpivot",
+            QueryType::Rename => "Rename Columns",
+            QueryType::Cast => "Cast Column",
+            QueryType::View => "Create View",
+            QueryType::ExportClipboard => "Export to Clipboard",
+            QueryType::TabTheme => "Tab Theme",
+            QueryType::AddColumn => "Add Column",
+            QueryType::Count => "Count Rows",
         }
         .to_owned()
     }
+
+    fn hint(&self) -> Option<&'static str> {
+        match self {
+            QueryType::GroupBy => Some("region, year :: sum(sales) mean(units)"),
+            QueryType::Join => Some("orders on id = customer_id and region = region left"),
+            QueryType::Diff => Some("orders_v2 on id"),
+            QueryType::Unpivot => Some("id, name :: jan, feb, mar"),
+            QueryType::Rename => Some("old_name new_name, qty quantity"),
+            QueryType::Cast => Some("price float, or price int --strict=false to null failures"),
+            QueryType::ExportClipboard => Some("tsv (or leave empty), csv, json or md"),
+            QueryType::AddColumn => Some("total = price * qty, or running = cumsum(amount)"),
+            QueryType::Count => Some("price > 100, or leave empty to count every row"),
+            QueryType::TabTheme => Some("Dracula, or leave empty to use the global theme"),
+            QueryType::View => Some("big_orders as SELECT * FROM orders WHERE total > 100"),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,73 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    misc::{config::config, type_ext::UnwrapOrEnqueueError},
+    tui::{component::Component, pickers::text_picker::TextPicker, widgets::input::InputType},
+};
+
+#[derive(Debug)]
+pub struct MaxWidthPicker {
+    picker: TextPicker,
+}
+
+impl Default for MaxWidthPicker {
+    fn default() -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title("Max Column Width")
+                .with_hint("Characters per column, empty leaves them unbounded")
+                .with_input_type(InputType::Numeric)
+                .with_value(
+                    config()
+                        .max_column_width()
+                        .map(|width| width.to_string())
+                        .unwrap_or_default(),
+                ),
+        }
+    }
+}
+
+impl MaxWidthPicker {
+    fn apply(&self) {
+        match self.picker.value().trim() {
+            "" => config().set_max_column_width(None),
+            value => match value.parse::<usize>() {
+                Ok(width) if width > 0 => config().set_max_column_width(Some(width)),
+                _ => {
+                    Message::AppShowError(format!("'{value}' is not a valid column width"))
+                        .enqueue();
+                    return;
+                }
+            },
+        }
+        config().store().unwrap_or_enqueue_error();
+    }
+}
+
+impl Component for MaxWidthPicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    self.apply();
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
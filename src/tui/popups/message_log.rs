@@ -0,0 +1,181 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local};
+use crossterm::event::{KeyCode, KeyModifiers};
+use itertools::Itertools;
+use ratatui::{
+    layout::{Alignment, Constraint, Margin},
+    text::Text,
+    widgets::{Clear, Row, StatefulWidget, Table, TableState, Widget},
+};
+use strum_macros::Display;
+
+use crate::{
+    handler::message::Message,
+    misc::config::theme,
+    tui::{component::Component, widgets::block::Block},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum LogKind {
+    Info,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    time: DateTime<Local>,
+    kind: LogKind,
+    text: String,
+}
+
+/// Recent toasts and errors, the oldest are dropped once the log is full.
+#[derive(Debug, Default)]
+pub struct MessageLog {
+    entries: VecDeque<LogEntry>,
+}
+
+impl MessageLog {
+    pub fn push(&mut self, kind: LogKind, text: impl Into<String>, capacity: usize) {
+        self.entries.push_back(LogEntry {
+            time: Local::now(),
+            kind,
+            text: text.into(),
+        });
+        while self.entries.len() > capacity {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Lists the logged messages, the latest first.
+#[derive(Debug)]
+pub struct MessageLogView {
+    entries: Vec<LogEntry>,
+    table_state: TableState,
+}
+
+impl MessageLogView {
+    pub fn new(log: &MessageLog) -> Self {
+        Self {
+            entries: log.entries.iter().rev().cloned().collect(),
+            table_state: TableState::default().with_selected(0),
+        }
+    }
+}
+
+impl Component for MessageLogView {
+    fn render(
+        &mut self,
+        _area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        _focus_state: crate::tui::component::FocusState,
+    ) {
+        let area = buf.area.inner(Margin::new(7, 3));
+        Widget::render(Clear, area, buf);
+        let rows = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(line, entry)| {
+                Row::new([
+                    entry.time.format("%H:%M:%S").to_string(),
+                    entry.kind.to_string(),
+                    entry.text.lines().join(" "),
+                ])
+                .style(match entry.kind {
+                    LogKind::Info => theme().row(line),
+                    LogKind::Error => theme().row(line).patch(theme().error()),
+                })
+            })
+            .collect_vec();
+        let title = if rows.is_empty() {
+            "Messages (nothing logged yet)"
+        } else {
+            "Messages"
+        };
+        StatefulWidget::render(
+            Table::default()
+                .header(
+                    Row::new(
+                        ["Time", "Kind", "Message"]
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, s)| Text::styled(s, theme().header(i))),
+                    )
+                    .style(theme().table_header()),
+                )
+                .rows(rows)
+                .widths([
+                    Constraint::Length(8),
+                    Constraint::Length(5),
+                    Constraint::Fill(1),
+                ])
+                .style(theme().text())
+                .row_highlight_style(theme().row_highlighted())
+                .block(
+                    Block::default()
+                        .title(title)
+                        .title_alignment(Alignment::Center)
+                        .into_widget(),
+                ),
+            area,
+            buf,
+            &mut self.table_state,
+        );
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        match (event.code, event.modifiers) {
+            (KeyCode::Up, KeyModifiers::NONE) | (KeyCode::Char('k'), KeyModifiers::NONE) => {
+                self.table_state.select_previous();
+                true
+            }
+            (KeyCode::Down, KeyModifiers::NONE) | (KeyCode::Char('j'), KeyModifiers::NONE) => {
+                if self.table_state.selected() < Some(self.entries.len().saturating_sub(1)) {
+                    self.table_state.select_next();
+                }
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE)
+            | (KeyCode::Char('q'), KeyModifiers::NONE)
+            | (KeyCode::Enter, KeyModifiers::NONE) => {
+                Message::AppDismissOverlay.enqueue();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_log_capacity() {
+        let mut log = MessageLog::default();
+        for idx in 0..5 {
+            log.push(LogKind::Info, format!("message {idx}"), 3);
+        }
+        log.push(LogKind::Error, "failed", 3);
+        assert_eq!(log.len(), 3);
+        let view = MessageLogView::new(&log);
+        assert_eq!(
+            view.entries
+                .iter()
+                .map(|entry| entry.text.as_str())
+                .collect_vec(),
+            ["failed", "message 4", "message 3"]
+        );
+        assert_eq!(view.entries[0].kind, LogKind::Error);
+    }
+}
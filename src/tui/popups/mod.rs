@@ -1,20 +1,41 @@
+pub mod box_plot_wizard;
+pub mod cell_editor;
 pub mod column_caster_wizard;
+pub mod column_index_wizard;
+pub mod column_list;
+pub mod column_stats;
 pub mod command_palette;
+pub mod confirm_picker;
 pub mod data_frame_info;
+pub mod density_wizard;
 pub mod export_target_picker;
 pub mod export_wizard;
 pub mod exporters;
+pub mod go_to_column;
 pub mod go_to_line;
+pub mod head_tail_picker;
 pub mod help_modal;
 pub mod histogram_wizard;
+pub mod import_source_picker;
 pub mod import_wizard;
 pub mod importers;
 pub mod inline_query_picker;
-pub mod import_source_picker;
+pub mod max_width_picker;
+pub mod message_log;
+pub mod notation_wizard;
 pub mod path_picker;
+pub mod pin_picker;
+pub mod pivot_wizard;
+pub mod precision_picker;
+pub mod rename_picker;
+pub mod sample_picker;
 pub mod scatter_plot_wizard;
+pub mod session_picker;
+pub mod sql_file_runner;
 pub mod sql_query_picker;
 pub mod table_registerer;
 pub mod theme_selector;
+pub mod time_format_picker;
+pub mod where_picker;
 pub mod wizard;
 pub mod yes_no_picker;
@@ -0,0 +1,69 @@
+use polars::frame::DataFrame;
+use strum::IntoEnumIterator;
+
+use crate::{
+    handler::message::Message,
+    misc::cell_format::Notation,
+    tui::{
+        pickers::search_picker::SearchPicker,
+        popups::wizard::{Wizard, WizardState},
+    },
+};
+
+pub type NotationWizard = Wizard<State>;
+
+#[derive(Debug)]
+pub enum State {
+    PickColumn {
+        picker: SearchPicker<String>,
+    },
+    PickNotation {
+        column: String,
+        picker: SearchPicker<Notation>,
+    },
+}
+
+impl State {
+    pub fn new(df: &DataFrame) -> Self {
+        let items = df
+            .column_iter()
+            .filter(|col| col.dtype().is_primitive_numeric())
+            .map(|col| col.name().to_string())
+            .collect();
+        State::PickColumn {
+            picker: SearchPicker::new(items).with_title("Column"),
+        }
+    }
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickColumn { picker } => {
+                if let Some(column) = picker.selected_item() {
+                    State::PickNotation {
+                        column: column.to_owned(),
+                        picker: SearchPicker::new(Notation::iter().collect())
+                            .with_title("Notation"),
+                    }
+                } else {
+                    State::PickColumn { picker }
+                }
+            }
+            State::PickNotation { column, picker } => {
+                if let Some(notation) = picker.selected_item() {
+                    Message::PaneSetNotation(column.clone(), *notation).enqueue();
+                    Message::PaneDismissModal.enqueue();
+                }
+                State::PickNotation { column, picker }
+            }
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
+        match self {
+            State::PickColumn { picker } => picker,
+            State::PickNotation { column: _, picker } => picker,
+        }
+    }
+}
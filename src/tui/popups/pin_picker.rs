@@ -0,0 +1,60 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    tui::{component::Component, pickers::text_picker::TextPicker},
+};
+
+#[derive(Debug)]
+pub struct PinPicker {
+    picker: TextPicker,
+}
+
+impl PinPicker {
+    pub fn new(pinned: usize) -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title("Pin")
+                .with_hint("Number of leading columns")
+                .with_value(pinned.to_string()),
+        }
+    }
+
+    fn apply(&self) {
+        match self.picker.value().trim() {
+            "" => Message::PaneSetPinned(0).enqueue(),
+            value => match value.parse::<usize>() {
+                Ok(pinned) => Message::PaneSetPinned(pinned).enqueue(),
+                Err(_) => Message::AppShowError(format!("'{value}' is not a valid column count"))
+                    .enqueue(),
+            },
+        }
+    }
+}
+
+impl Component for PinPicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    self.apply();
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
@@ -0,0 +1,150 @@
+use itertools::Itertools;
+use polars::frame::DataFrame;
+use strum::IntoEnumIterator;
+
+use crate::{
+    handler::message::Message,
+    misc::polars_ext::Aggregation,
+    tui::{
+        pickers::search_picker::SearchPicker,
+        popups::wizard::{Wizard, WizardState},
+    },
+};
+
+pub type PivotWizard = Wizard<State>;
+
+#[derive(Debug)]
+pub enum State {
+    PickIndex {
+        df: DataFrame,
+        picker: SearchPicker<String>,
+    },
+    PickColumns {
+        df: DataFrame,
+        index: String,
+        picker: SearchPicker<String>,
+    },
+    PickValues {
+        df: DataFrame,
+        index: String,
+        columns: String,
+        picker: SearchPicker<String>,
+    },
+    PickAggregation {
+        index: String,
+        columns: String,
+        values: String,
+        picker: SearchPicker<Aggregation>,
+    },
+}
+
+impl State {
+    pub fn new(df: DataFrame) -> Self {
+        let items = column_names(&df, &[]);
+        Self::PickIndex {
+            df,
+            picker: SearchPicker::new(items).with_title("Index"),
+        }
+    }
+}
+
+impl WizardState for State {
+    fn next(self) -> Self {
+        match self {
+            State::PickIndex { df, picker } => {
+                if let Some(index) = picker.selected_item().cloned() {
+                    State::PickColumns {
+                        picker: SearchPicker::new(column_names(&df, &[&index]))
+                            .with_title("Columns"),
+                        df,
+                        index,
+                    }
+                } else {
+                    State::PickIndex { df, picker }
+                }
+            }
+            State::PickColumns { df, index, picker } => {
+                if let Some(columns) = picker.selected_item().cloned() {
+                    State::PickValues {
+                        picker: SearchPicker::new(column_names(&df, &[&index, &columns]))
+                            .with_title("Values"),
+                        df,
+                        index,
+                        columns,
+                    }
+                } else {
+                    State::PickColumns { df, index, picker }
+                }
+            }
+            State::PickValues {
+                df,
+                index,
+                columns,
+                picker,
+            } => {
+                if let Some(values) = picker.selected_item().cloned() {
+                    // Numeric values are summed by default, anything else keeps the first value
+                    let default = if df
+                        .column(&values)
+                        .is_ok_and(|col| col.dtype().is_primitive_numeric())
+                    {
+                        Aggregation::Sum
+                    } else {
+                        Aggregation::First
+                    };
+                    let items = std::iter::once(default)
+                        .chain(Aggregation::iter().filter(|agg| *agg != default))
+                        .collect();
+                    State::PickAggregation {
+                        index,
+                        columns,
+                        values,
+                        picker: SearchPicker::new(items).with_title("Aggregation"),
+                    }
+                } else {
+                    State::PickValues {
+                        df,
+                        index,
+                        columns,
+                        picker,
+                    }
+                }
+            }
+            State::PickAggregation {
+                index,
+                columns,
+                values,
+                picker,
+            } => {
+                if let Some(agg) = picker.selected_item() {
+                    Message::PanePivot(index.clone(), columns.clone(), values.clone(), *agg)
+                        .enqueue();
+                    Message::PaneDismissModal.enqueue();
+                }
+                State::PickAggregation {
+                    index,
+                    columns,
+                    values,
+                    picker,
+                }
+            }
+        }
+    }
+
+    fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
+        match self {
+            State::PickIndex { picker, .. } => picker,
+            State::PickColumns { picker, .. } => picker,
+            State::PickValues { picker, .. } => picker,
+            State::PickAggregation { picker, .. } => picker,
+        }
+    }
+}
+
+fn column_names(df: &DataFrame, exclude: &[&str]) -> Vec<String> {
+    df.get_column_names()
+        .into_iter()
+        .filter(|name| !exclude.contains(&name.as_str()))
+        .map(|name| name.to_string())
+        .collect_vec()
+}
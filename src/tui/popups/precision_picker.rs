@@ -0,0 +1,65 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    tui::{component::Component, pickers::text_picker::TextPicker},
+};
+
+#[derive(Debug)]
+pub struct PrecisionPicker {
+    picker: TextPicker,
+}
+
+impl PrecisionPicker {
+    pub fn new(precision: Option<usize>) -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title("Precision")
+                .with_hint("Decimal places or 'auto'")
+                .with_value(
+                    precision
+                        .map(|p| p.to_string())
+                        .unwrap_or_else(|| "auto".to_owned()),
+                ),
+        }
+    }
+
+    fn apply(&self) {
+        match self.picker.value().trim() {
+            "auto" | "" => Message::PaneSetPrecision(None).enqueue(),
+            value => match value.parse::<usize>() {
+                Ok(precision) => Message::PaneSetPrecision(Some(precision)).enqueue(),
+                Err(_) => {
+                    Message::AppShowError(format!("'{value}' is not a valid precision")).enqueue()
+                }
+            },
+        }
+    }
+}
+
+impl Component for PrecisionPicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    self.apply();
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
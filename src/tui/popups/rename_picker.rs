@@ -0,0 +1,52 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    tui::{component::Component, pickers::text_picker::TextPicker},
+};
+
+#[derive(Debug)]
+pub struct RenamePicker {
+    picker: TextPicker,
+}
+
+impl RenamePicker {
+    pub fn new(name: &str) -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title("Rename")
+                .with_hint("Display name of the tab, empty to restore the default")
+                .with_value(name.to_owned()),
+        }
+    }
+}
+
+impl Component for RenamePicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    match self.picker.value().trim() {
+                        "" => Message::PaneRename(None).enqueue(),
+                        name => Message::PaneRename(Some(name.to_owned())).enqueue(),
+                    }
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
@@ -0,0 +1,58 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    misc::polars_ext::SampleSpec,
+    tui::{component::Component, pickers::text_picker::TextPicker},
+};
+
+#[derive(Debug)]
+pub struct SamplePicker {
+    picker: TextPicker,
+}
+
+impl Default for SamplePicker {
+    fn default() -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title("Sample")
+                .with_hint("Number of rows like 1000 or a share like 5%"),
+        }
+    }
+}
+
+impl SamplePicker {
+    fn apply(&self) {
+        match self.picker.value().parse::<SampleSpec>() {
+            Ok(spec) => Message::PaneSample(spec).enqueue(),
+            Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+        }
+    }
+}
+
+impl Component for SamplePicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    self.apply();
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
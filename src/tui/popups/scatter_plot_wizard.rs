@@ -10,18 +10,24 @@ use crate::{
 
 pub type ScatterPlotWizard = Wizard<State>;
 
+/// Builds the message showing the plot from the picked x, y and color by columns.
+type ShowPlot = fn(String, String, Option<String>) -> Message;
+
 #[derive(Debug)]
 pub enum State {
     PickX {
         df: DataFrame,
+        show: ShowPlot,
         picker: SearchPicker<String>,
     },
     PickY {
         df: DataFrame,
+        show: ShowPlot,
         x: String,
         picker: SearchPicker<String>,
     },
     PickColorBy {
+        show: ShowPlot,
         x: String,
         y: String,
         picker: SearchPicker<String>,
@@ -30,6 +36,10 @@ pub enum State {
 
 impl State {
     pub fn new(df: DataFrame) -> Self {
+        Self::with_show(df, Message::PaneShowScatterPlot)
+    }
+
+    pub fn with_show(df: DataFrame, show: ShowPlot) -> Self {
         let items = df
             .column_iter()
             .filter(|col| col.dtype().is_numeric())
@@ -37,6 +47,7 @@ impl State {
             .collect();
         Self::PickX {
             df,
+            show,
             picker: SearchPicker::new(items).with_title("Axis X"),
         }
     }
@@ -45,7 +56,7 @@ impl State {
 impl WizardState for State {
     fn next(self) -> Self {
         match self {
-            State::PickX { df, picker } => {
+            State::PickX { df, show, picker } => {
                 if let Some(x) = picker.selected_item().cloned() {
                     let items = picker
                         .into_items()
@@ -54,16 +65,23 @@ impl WizardState for State {
                         .collect();
                     State::PickY {
                         df,
+                        show,
                         x,
                         picker: SearchPicker::new(items).with_title("Axis Y"),
                     }
                 } else {
-                    State::PickX { df, picker }
+                    State::PickX { df, show, picker }
                 }
             }
-            State::PickY { df, x, picker } => {
+            State::PickY {
+                df,
+                show,
+                x,
+                picker,
+            } => {
                 if let Some(y) = picker.selected_item().cloned() {
                     State::PickColorBy {
+                        show,
                         x,
                         y,
                         picker: SearchPicker::new(
@@ -83,33 +101,48 @@ impl WizardState for State {
                         .with_title("Color By"),
                     }
                 } else {
-                    State::PickY { df, x, picker }
+                    State::PickY {
+                        df,
+                        show,
+                        x,
+                        picker,
+                    }
                 }
             }
-            State::PickColorBy { x, y, picker } => {
+            State::PickColorBy { show, x, y, picker } => {
                 if let Some(grp) = picker.selected_item().cloned() {
                     let x = x.clone();
                     let y = y.clone();
                     if picker.selected() == Some(0) {
-                        Message::PaneShowScatterPlot(x, y, None).enqueue();
+                        show(x, y, None).enqueue();
                     } else {
-                        Message::PaneShowScatterPlot(x, y, Some(grp)).enqueue();
+                        show(x, y, Some(grp)).enqueue();
                     }
                 }
-                State::PickColorBy { x, y, picker }
+                State::PickColorBy { show, x, y, picker }
             }
         }
     }
 
     fn responder(&mut self) -> &mut dyn crate::tui::component::Component {
         match self {
-            State::PickX { df: _, picker } => picker,
+            State::PickX {
+                df: _,
+                show: _,
+                picker,
+            } => picker,
             State::PickY {
                 df: _,
+                show: _,
+                x: _,
+                picker,
+            } => picker,
+            State::PickColorBy {
+                show: _,
                 x: _,
+                y: _,
                 picker,
             } => picker,
-            State::PickColorBy { x: _, y: _, picker } => picker,
         }
     }
 }
@@ -0,0 +1,97 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    misc::session::Session,
+    tui::{
+        component::Component,
+        pickers::{search_picker::SearchPicker, text_picker::TextPicker},
+    },
+};
+
+#[derive(Debug)]
+pub struct SaveSessionPicker {
+    picker: TextPicker,
+}
+
+impl Default for SaveSessionPicker {
+    fn default() -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title("Save Session")
+                .with_hint("Name of the session, an existing one is overwritten"),
+        }
+    }
+}
+
+impl Component for SaveSessionPicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    let name = self.picker.value().trim();
+                    if !name.is_empty() {
+                        Message::AppDismissOverlay.enqueue();
+                        Message::AppSaveSession(name.to_owned()).enqueue();
+                    }
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::AppDismissOverlay.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadSessionPicker {
+    picker: SearchPicker<String>,
+}
+
+impl Default for LoadSessionPicker {
+    fn default() -> Self {
+        Self {
+            picker: SearchPicker::new(Session::names()).with_title("Load Session"),
+        }
+    }
+}
+
+impl Component for LoadSessionPicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    if let Some(name) = self.picker.selected_item() {
+                        Message::AppDismissOverlay.enqueue();
+                        Message::AppLoadSession(name.to_owned()).enqueue();
+                    }
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::AppDismissOverlay.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
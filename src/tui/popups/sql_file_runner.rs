@@ -0,0 +1,74 @@
+use std::fs::read_to_string;
+
+use anyhow::anyhow;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use polars::frame::DataFrame;
+
+use crate::{
+    AppResult,
+    handler::message::Message,
+    misc::{globals::sql, sql::split_statements, type_ext::UnwrapOrEnqueueError},
+    tui::{component::Component, popups::path_picker::PathPicker},
+};
+
+const NO_RESULT_STATEMENTS: [&str; 6] = ["CREATE", "DROP", "TRUNCATE", "INSERT", "DELETE", "SET"];
+
+#[derive(Debug)]
+pub struct SqlFileRunner {
+    df: Option<DataFrame>,
+    picker: PathPicker,
+}
+
+impl SqlFileRunner {
+    pub fn new(df: Option<DataFrame>) -> Self {
+        Self {
+            df,
+            picker: PathPicker::default().with_title("SQL File"),
+        }
+    }
+
+    fn run(&self) -> AppResult<()> {
+        let script = read_to_string(self.picker.path())?;
+        for (idx, statement) in split_statements(&script).into_iter().enumerate() {
+            let df = sql()
+                .execute(statement, self.df.clone())
+                .map_err(|err| anyhow!("Statement {} failed: {statement}\n{err}", idx + 1))?;
+            let keyword = statement
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_uppercase();
+            if df.height() > 0 && !NO_RESULT_STATEMENTS.contains(&keyword.as_str()) {
+                Message::TabsAddQueryPane(df, statement.to_owned()).enqueue();
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Component for SqlFileRunner {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    Message::AppDismissOverlay.enqueue();
+                    self.run().unwrap_or_enqueue_error();
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::AppDismissOverlay.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
@@ -1,25 +1,99 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use polars::frame::DataFrame;
+use itertools::Itertools;
+use polars::{frame::DataFrame, prelude::Column};
 
 use crate::{
     handler::message::Message,
-    misc::globals::sql,
-    tui::{component::Component, pickers::text_picker::TextPicker},
+    misc::{globals::sql, history::history},
+    tui::{component::Component, pickers::text_picker::TextPicker, widgets::completion::Completer},
 };
 
 #[derive(Debug)]
 pub struct SqlQueryPicker {
     df: Option<DataFrame>,
     text_picker: TextPicker,
+    recall: Option<usize>,
+    draft: String,
+    completer: Completer,
+    explain: bool,
 }
 
 impl SqlQueryPicker {
     pub fn new(df: Option<DataFrame>) -> Self {
+        // Registered tables first, then the columns of the current tab
+        let names = sql()
+            .schema()
+            .iter()
+            .map(|(name, _)| name.to_owned())
+            .chain(df.iter().flat_map(|df| {
+                df.get_column_names()
+                    .into_iter()
+                    .map(|name| name.to_string())
+            }))
+            .unique()
+            .collect();
         Self {
+            completer: Completer::new("Tables and Columns (Tab)", names),
             df,
             text_picker: TextPicker::default().with_title("Sql"),
+            recall: None,
+            draft: String::new(),
+            explain: false,
         }
     }
+
+    /// Opens the query plan in a new tab on enter instead of running the query.
+    pub fn with_explain(self) -> Self {
+        Self {
+            text_picker: self.text_picker.with_title("Explain"),
+            explain: true,
+            ..self
+        }
+    }
+
+    fn explain(&self) {
+        match sql().explain(self.text_picker.value(), self.df.clone()) {
+            Ok(plan) => {
+                let df =
+                    DataFrame::new(vec![Column::new("plan".into(), plan.lines().collect_vec())]);
+                match df {
+                    Ok(df) => Message::TabsAddNamePane(df, "explain".to_owned()).enqueue(),
+                    Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+                }
+            }
+            Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+        }
+    }
+
+    /// Plans the query right away and collects it on a worker thread, the result opens in a new
+    /// tab unless the query is cancelled from the status bar first.
+    fn execute(&self) {
+        let query = self.text_picker.value().to_owned();
+        match sql().prepare(&query, self.df.clone()) {
+            Ok(prepared) => {
+                prepared.spawn(move |df| Message::TabsAddQueryPane(df, query).enqueue())
+            }
+            Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+        }
+    }
+
+    /// Replaces the input with the history entry `back` steps before the most recent one, or the
+    /// text typed before browsing the history if `None`.
+    fn recall(&mut self, back: Option<usize>) {
+        match back {
+            Some(back) => {
+                let Some(entry) = history().get(back).map(str::to_owned) else {
+                    return;
+                };
+                if self.recall.is_none() {
+                    self.draft = self.text_picker.value().to_owned();
+                }
+                self.text_picker.set_value(entry);
+            }
+            None => self.text_picker.set_value(std::mem::take(&mut self.draft)),
+        }
+        self.recall = back;
+    }
 }
 
 impl Component for SqlQueryPicker {
@@ -30,20 +104,35 @@ impl Component for SqlQueryPicker {
         focus_state: crate::tui::component::FocusState,
     ) {
         self.text_picker.render(area, buf, focus_state);
+        self.completer.render(self.text_picker.input(), buf);
     }
 
     fn handle(&mut self, event: KeyEvent) -> bool {
+        if let (KeyCode::Tab, KeyModifiers::NONE) = (event.code, event.modifiers) {
+            self.completer.complete(self.text_picker.input_mut());
+            return true;
+        }
+        self.completer.reset();
         self.text_picker.handle(event)
             || match (event.code, event.modifiers) {
+                (KeyCode::Up, KeyModifiers::NONE) => {
+                    self.recall(Some(self.recall.map_or(0, |back| back + 1)));
+                    true
+                }
+                (KeyCode::Down, KeyModifiers::NONE) if self.recall.is_some() => {
+                    self.recall(self.recall.and_then(|back| back.checked_sub(1)));
+                    true
+                }
+                (KeyCode::Enter, KeyModifiers::NONE) if self.explain => {
+                    Message::AppDismissOverlay.enqueue();
+                    history().push(self.text_picker.value());
+                    self.explain();
+                    true
+                }
                 (KeyCode::Enter, KeyModifiers::NONE) => {
                     Message::AppDismissOverlay.enqueue();
-                    match sql().execute(self.text_picker.value(), self.df.clone()) {
-                        Ok(df) => {
-                            Message::TabsAddQueryPane(df, self.text_picker.value().to_owned())
-                                .enqueue();
-                        }
-                        Err(err) => Message::AppShowError(err.to_string()).enqueue(),
-                    }
+                    history().push(self.text_picker.value());
+                    self.execute();
                     true
                 }
                 (KeyCode::Esc, KeyModifiers::NONE) => {
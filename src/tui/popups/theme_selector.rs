@@ -1,7 +1,6 @@
 use std::ops::Deref;
 
 use crossterm::event::KeyCode;
-use strum::IntoEnumIterator;
 
 use crate::{
     handler::message::Message,
@@ -38,6 +37,15 @@ impl ThemeSelector {
     pub fn search_picker_mut(&mut self) -> &mut SearchPicker<Theme> {
         &mut self.search_picker
     }
+
+    /// Applies the selected theme behind the selector, the original one is restored on Esc.
+    fn preview(&self) {
+        if let Some(selected) = self.selected()
+            && selected != *theme().app_theme()
+        {
+            config().set_theme(selected);
+        }
+    }
 }
 
 impl Component for ThemeSelector {
@@ -48,36 +56,34 @@ impl Component for ThemeSelector {
         focus_state: crate::tui::component::FocusState,
     ) {
         self.search_picker.render(area, buf, focus_state);
-        if let Some(t) = self.search_picker.selected_item()
-            && t != theme().app_theme()
-        {
-            config().set_theme(*t);
-        }
     }
 
     fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
-        self.search_picker.handle(event)
-            || match event.code {
-                KeyCode::Esc => {
-                    Message::AppDismissOverlay.enqueue();
-                    config().set_theme(self.rollback.clone());
-                    true
-                }
-                KeyCode::Enter => {
-                    Message::AppDismissOverlay.enqueue();
-                    config().store().unwrap_or_enqueue_error();
-                    true
-                }
-                _ => false,
+        if self.search_picker.handle(event) {
+            self.preview();
+            return true;
+        }
+        match event.code {
+            KeyCode::Esc => {
+                Message::AppDismissOverlay.enqueue();
+                config().set_theme(self.rollback.clone());
+                true
             }
+            KeyCode::Enter => {
+                Message::AppDismissOverlay.enqueue();
+                config().store().unwrap_or_enqueue_error();
+                true
+            }
+            _ => false,
+        }
     }
 }
 
 impl Default for ThemeSelector {
     fn default() -> Self {
-        let mut search_picker = SearchPicker::new(Theme::iter().collect());
+        let mut search_picker = SearchPicker::new(Theme::all().collect());
         let rollback = config().theme().deref().clone();
-        let idx = Theme::iter()
+        let idx = Theme::all()
             .enumerate()
             .find_map(|(i, t)| rollback.app_theme().eq(&t).then_some(i))
             .unwrap_or_default();
@@ -0,0 +1,51 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use strum::IntoEnumIterator;
+
+use crate::{
+    handler::message::Message,
+    misc::{cell_format::TimeFormat, config::config, type_ext::UnwrapOrEnqueueError},
+    tui::{component::Component, pickers::search_picker::SearchPicker},
+};
+
+#[derive(Debug)]
+pub struct TimeFormatPicker {
+    picker: SearchPicker<TimeFormat>,
+}
+
+impl Default for TimeFormatPicker {
+    fn default() -> Self {
+        let mut picker = SearchPicker::new(TimeFormat::iter().collect()).with_title("Time Format");
+        picker.select(TimeFormat::iter().position(|fmt| fmt == config().time_format()));
+        Self { picker }
+    }
+}
+
+impl Component for TimeFormatPicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    if let Some(time_format) = self.picker.selected_item() {
+                        config().set_time_format(*time_format);
+                        config().store().unwrap_or_enqueue_error();
+                    }
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
@@ -0,0 +1,52 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::{
+    handler::message::Message,
+    tui::{component::Component, pickers::text_picker::TextPicker},
+};
+
+#[derive(Debug)]
+pub struct WherePicker {
+    picker: TextPicker,
+}
+
+impl WherePicker {
+    pub fn new(predicate: Option<&str>) -> Self {
+        Self {
+            picker: TextPicker::default()
+                .with_title("Where")
+                .with_hint("Base predicate for the tab or 'off'")
+                .with_value(predicate.unwrap_or_default().to_owned()),
+        }
+    }
+}
+
+impl Component for WherePicker {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        focus_state: crate::tui::component::FocusState,
+    ) {
+        self.picker.render(area, buf, focus_state);
+    }
+
+    fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        self.picker.handle(event)
+            || match (event.code, event.modifiers) {
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    match self.picker.value().trim() {
+                        "off" | "" => Message::PaneSetWhere(None).enqueue(),
+                        predicate => Message::PaneSetWhere(Some(predicate.to_owned())).enqueue(),
+                    }
+                    true
+                }
+                (KeyCode::Esc, KeyModifiers::NONE) => {
+                    Message::PaneDismissModal.enqueue();
+                    true
+                }
+                _ => false,
+            }
+    }
+}
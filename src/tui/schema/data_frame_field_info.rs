@@ -1,4 +1,8 @@
+use std::collections::HashMap;
+
 use crossterm::event::{KeyCode, KeyModifiers};
+use itertools::Itertools;
+use polars::frame::DataFrame;
 use ratatui::{
     layout::{Alignment, Constraint},
     text::Text,
@@ -6,7 +10,9 @@ use ratatui::{
 };
 
 use crate::{
-    misc::{config::theme, sql::TableSchema, type_ext::human_readable_size},
+    misc::{
+        config::theme, polars_ext::DataFrameExt, sql::TableSchema, type_ext::human_readable_size,
+    },
     tui::{
         component::Component,
         status_bar::{StatusBar, Tag},
@@ -14,10 +20,15 @@ use crate::{
     },
 };
 
+/// Number of buckets of a sparkline, one character each.
+const SPARKLINE_BUCKETS: usize = 12;
+
 #[derive(Debug)]
 pub struct DataFrameFieldInfo {
     table_state: TableState,
     table_schema: TableSchema,
+    data_frame: Option<DataFrame>,
+    sparklines: HashMap<String, String>,
 }
 
 impl DataFrameFieldInfo {
@@ -25,9 +36,17 @@ impl DataFrameFieldInfo {
         Self {
             table_state: Default::default(),
             table_schema,
+            data_frame: None,
+            sparklines: HashMap::new(),
         }
     }
 
+    /// Shows the distribution of the numeric columns of the data frame as sparklines.
+    pub fn with_data_frame(mut self, data_frame: DataFrame) -> Self {
+        self.data_frame = Some(data_frame);
+        self
+    }
+
     pub fn table_state(&self) -> &TableState {
         &self.table_state
     }
@@ -43,6 +62,46 @@ impl DataFrameFieldInfo {
     pub fn scroll_down(&mut self) {
         *self.table_state.offset_mut() = self.table_state.offset().saturating_add(1)
     }
+
+    /// Computes the sparklines of the rows on screen that do not have one yet, the histograms
+    /// are only built once per column.
+    fn update_sparklines(&mut self, rows: usize) {
+        let Some(df) = self.data_frame.as_ref() else {
+            return;
+        };
+        for (name, info) in self
+            .table_schema
+            .iter()
+            .skip(self.table_state.offset())
+            .take(rows)
+        {
+            if !self.sparklines.contains_key(name) {
+                let line = if info.dtype().is_primitive_numeric() {
+                    df.histogram_plot_data(name, SPARKLINE_BUCKETS)
+                        .map(|buckets| {
+                            sparkline(&buckets.into_iter().map(|(_, count)| count).collect_vec())
+                        })
+                        .unwrap_or_default()
+                } else {
+                    String::new()
+                };
+                self.sparklines.insert(name.to_owned(), line);
+            }
+        }
+    }
+}
+
+/// Renders the counts as block characters scaled to the largest one, empty buckets are blank.
+fn sparkline(counts: &[u64]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = counts.iter().copied().max().unwrap_or_default();
+    counts
+        .iter()
+        .map(|count| match count {
+            0 => ' ',
+            count => BLOCKS[(count * 7 / max) as usize],
+        })
+        .collect()
 }
 
 impl Component for DataFrameFieldInfo {
@@ -57,15 +116,24 @@ impl Component for DataFrameFieldInfo {
                 .len()
                 .saturating_sub(area.height.saturating_sub(2).into()),
         );
+        self.update_sparklines(area.height.saturating_sub(2).into());
         Widget::render(Clear, area, buf);
         StatefulWidget::render(
             Table::default()
                 .header(
                     Row::new(
-                        ["Name", "Type", "Estimated Size", "Null Count", "Min", "Max"]
-                            .into_iter()
-                            .enumerate()
-                            .map(|(i, s)| Text::styled(s, theme().header(i))),
+                        [
+                            "Name",
+                            "Type",
+                            "Estimated Size",
+                            "Null Count",
+                            "Min",
+                            "Max",
+                            "Distribution",
+                        ]
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, s)| Text::styled(s, theme().header(i))),
                     )
                     .style(theme().table_header()),
                 )
@@ -81,6 +149,7 @@ impl Component for DataFrameFieldInfo {
                                 format!("{}", info.null_count()),
                                 info.min().to_string(),
                                 info.max().to_string(),
+                                self.sparklines.get(name).cloned().unwrap_or_default(),
                             ])
                             .style(theme().row(idx))
                         }),
@@ -92,6 +161,7 @@ impl Component for DataFrameFieldInfo {
                     Constraint::Fill(1),
                     Constraint::Fill(1),
                     Constraint::Fill(1),
+                    Constraint::Length(SPARKLINE_BUCKETS as u16),
                 ])
                 .style(theme().text())
                 .block(
@@ -135,3 +205,15 @@ impl Component for DataFrameFieldInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[1, 0, 4, 8, 2]), "▁ ▄█▂");
+        assert_eq!(sparkline(&[]), "");
+        assert_eq!(sparkline(&[0, 0]), "  ");
+    }
+}
@@ -1,3 +1,4 @@
+use polars::frame::DataFrame;
 use ratatui::layout::{Constraint, Layout};
 
 use crate::{
@@ -24,6 +25,12 @@ impl DataFrameInfo {
         }
     }
 
+    /// Adds sparklines of the numeric columns computed from the data frame.
+    pub fn with_data_frame(mut self, data_frame: DataFrame) -> Self {
+        self.field_info = self.field_info.with_data_frame(data_frame);
+        self
+    }
+
     pub fn table_info(&self) -> &TableInfo {
         self.meta_info.table_info()
     }
@@ -47,16 +47,36 @@ impl Component for Schema {
         buf.set_style(area, theme().text());
         Widget::render(Clear, area, buf);
 
-        if let Some(selected) = self.names.selected()
-            && let Some((_, new_info)) = sql().schema().get_by_index(selected)
+        let selected = self.names.selected().and_then(|idx| {
+            sql()
+                .schema()
+                .get_by_index(idx)
+                .map(|(name, info)| (name.to_owned(), info.clone()))
+        });
+        if let Some((name, new_info)) = selected
             && self
                 .info
                 .as_ref()
                 .map(|df_info| df_info.table_info())
-                .map(|cur_info| cur_info != new_info)
+                .map(|cur_info| cur_info != &new_info)
                 .unwrap_or(true)
         {
-            self.info = Some(DataFrameInfo::new(new_info.clone()))
+            // Scanned tables would be read whole for the sparklines, they go without
+            let df = (!new_info.is_lazy())
+                .then(|| {
+                    sql()
+                        .execute(
+                            &format!("SELECT * FROM \"{}\"", name.replace('"', "\"\"")),
+                            None,
+                        )
+                        .ok()
+                })
+                .flatten();
+            let info = DataFrameInfo::new(new_info);
+            self.info = Some(match df {
+                Some(df) => info.with_data_frame(df),
+                None => info,
+            });
         }
 
         if sql().schema().is_empty() {
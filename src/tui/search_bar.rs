@@ -1,10 +1,11 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use polars::frame::DataFrame;
-use ratatui::widgets::Widget;
+use ratatui::{text::Line, widgets::Widget};
 
 use crate::{
     handler::message::Message,
-    misc::search::{self, Contain, Skim},
+    misc::config::theme,
+    misc::search::{self, Contain, Regex, Skim},
     tui::{component::Component, widgets::block::Block},
 };
 
@@ -14,6 +15,7 @@ use super::widgets::input::Input;
 pub enum Searcher {
     Fuzzy(search::Search<Skim>),
     Exact(search::Search<Contain>),
+    Regex(search::Search<Regex>),
 }
 
 impl Searcher {
@@ -21,6 +23,7 @@ impl Searcher {
         match self {
             Searcher::Fuzzy(search) => search.pattern(),
             Searcher::Exact(search) => search.pattern(),
+            Searcher::Regex(search) => search.pattern(),
         }
     }
 
@@ -28,6 +31,7 @@ impl Searcher {
         match self {
             Searcher::Fuzzy(search) => search.latest(),
             Searcher::Exact(search) => search.latest(),
+            Searcher::Regex(search) => search.latest(),
         }
     }
 }
@@ -37,6 +41,7 @@ pub struct SearchBar {
     input: Input,
     searcher: Searcher,
     rollback_df: DataFrame,
+    error: Option<String>,
 }
 
 impl SearchBar {
@@ -45,6 +50,7 @@ impl SearchBar {
             input: Default::default(),
             searcher: Searcher::Exact(search::Search::new(dataframe.clone(), Default::default())),
             rollback_df: dataframe,
+            error: None,
         }
     }
 
@@ -53,6 +59,16 @@ impl SearchBar {
             input: Default::default(),
             searcher: Searcher::Fuzzy(search::Search::new(dataframe.clone(), Default::default())),
             rollback_df: dataframe,
+            error: None,
+        }
+    }
+
+    pub fn regex(dataframe: DataFrame) -> Self {
+        SearchBar {
+            input: Default::default(),
+            searcher: Searcher::Regex(search::Search::new(dataframe.clone(), Default::default())),
+            rollback_df: dataframe,
+            error: None,
         }
     }
 
@@ -79,6 +95,25 @@ impl SearchBar {
                         self.input.value().to_owned(),
                     ))
                 }
+                // keep the last valid search running while the pattern does not compile
+                Searcher::Regex(_) => match regex::Regex::new(self.input.value()) {
+                    Ok(_) => {
+                        self.error = None;
+                        self.searcher = Searcher::Regex(search::Search::new(
+                            self.rollback_df.clone(),
+                            self.input.value().to_owned(),
+                        ))
+                    }
+                    Err(err) => {
+                        self.error = Some(
+                            err.to_string()
+                                .lines()
+                                .last()
+                                .unwrap_or_default()
+                                .to_owned(),
+                        )
+                    }
+                },
             }
         }
     }
@@ -94,9 +129,13 @@ impl Component for SearchBar {
         let title = match &self.searcher {
             Searcher::Fuzzy(_) => "Fuzzy Search",
             Searcher::Exact(_) => "Search",
+            Searcher::Regex(_) => "Regex Search",
         };
         let area = {
-            let block = Block::default().title(title);
+            let mut block = Block::default().title(title);
+            if let Some(error) = self.error.as_deref() {
+                block = block.bottom(Line::styled(format!(" {error} "), theme().error()));
+            }
             let inner = block.inner(area);
             block.render(area, buf);
             inner
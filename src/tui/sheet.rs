@@ -32,7 +32,10 @@ impl SheetSection {
 pub struct Sheet {
     scroll: Scroll,
     row: usize,
+    total: usize,
     sections: Vec<SheetSection>,
+    /// Digits typed so far of a record number to jump to.
+    jump: String,
 }
 
 impl Sheet {
@@ -40,7 +43,9 @@ impl Sheet {
         Self {
             scroll: Default::default(),
             row,
+            total: 0,
             sections,
+            jump: String::new(),
         }
     }
 
@@ -56,9 +61,15 @@ impl Sheet {
         self.row
     }
 
+    /// Number of records of the table, shown next to the current one.
+    pub fn set_total(&mut self, total: usize) {
+        self.total = total;
+    }
+
     pub fn set(&mut self, row: usize, sections: Vec<SheetSection>) {
         self.row = row;
         self.sections = sections;
+        self.scroll = Default::default();
     }
 }
 
@@ -91,10 +102,18 @@ impl Component for Sheet {
         .wrap(Wrap { trim: true })
         .block(
             Block::default()
+                .title(if self.jump.is_empty() {
+                    format!(" Record {} / {} ", self.row + 1, self.total)
+                } else {
+                    format!(" Go to Record {}_ / {} ", self.jump, self.total)
+                })
                 .bottom(
                     StatusBar::new()
                         .mono_color()
                         .centered()
+                        .tag(Tag::new(" Prev Row ", " k | \u{2191} "))
+                        .tag(Tag::new(" Next Row ", " j | \u{2193} "))
+                        .tag(Tag::new(" Go to Record ", " Number + Enter "))
                         .tag(Tag::new(" Scroll Up ", " Shift+K | Shift+\u{2191} "))
                         .tag(Tag::new(" Scroll Down ", " Shift+J | Shift+\u{2193} ")),
                 )
@@ -110,6 +129,24 @@ impl Component for Sheet {
 
     fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
         match (event.code, event.modifiers) {
+            (KeyCode::Char(digit), KeyModifiers::NONE) if digit.is_ascii_digit() => {
+                self.jump.push(digit);
+                true
+            }
+            (KeyCode::Backspace, KeyModifiers::NONE) if !self.jump.is_empty() => {
+                self.jump.pop();
+                true
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) if !self.jump.is_empty() => {
+                if let Ok(record) = std::mem::take(&mut self.jump).parse::<usize>() {
+                    Message::PaneSheetGoTo(record.saturating_sub(1)).enqueue();
+                }
+                true
+            }
+            (KeyCode::Esc, KeyModifiers::NONE) if !self.jump.is_empty() => {
+                self.jump.clear();
+                true
+            }
             (KeyCode::Char('K'), KeyModifiers::SHIFT) | (KeyCode::Up, KeyModifiers::SHIFT) => {
                 self.scroll.up();
                 true
@@ -118,6 +155,14 @@ impl Component for Sheet {
                 self.scroll.down();
                 true
             }
+            (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, KeyModifiers::NONE) => {
+                Message::PaneSheetPrevRow.enqueue();
+                true
+            }
+            (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, KeyModifiers::NONE) => {
+                Message::PaneSheetNextRow.enqueue();
+                true
+            }
             (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
                 Message::PaneDismissModal.enqueue();
                 true
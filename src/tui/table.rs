@@ -1,44 +1,78 @@
-use std::ops::{Add, Div};
+use std::{
+    collections::BTreeSet,
+    ops::{Add, Div},
+};
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use anyhow::anyhow;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use itertools::Itertools;
-use polars::{frame::DataFrame, series::Series};
+use polars::{
+    frame::DataFrame,
+    prelude::{AnyValue, ChunkAgg, DataType, IdxCa, IdxSize},
+    series::Series,
+};
 use ratatui::{
     layout::{Constraint, Layout, Position, Rect},
+    style::Style,
     text::Text,
     widgets::{Cell, List, ListItem, ListState, Row, StatefulWidget, TableState},
 };
 use tui_scrollview::{ScrollView, ScrollViewState, ScrollbarVisibility};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::{
+    AppResult,
     misc::{
+        cell_format::CellFormat,
         config::theme,
         iter_ext::ZipItersExt,
-        polars_ext::{AnyValueExt, DataFrameExt},
-        type_ext::ConstraintExt,
+        polars_ext::{DIFF_CHANGED, DataFrameExt},
+        type_ext::{ConstraintExt, HasSubsequence},
     },
-    tui::component::Component,
+    tui::{component::Component, plots::corr_matrix::blend},
 };
 
 #[derive(Debug, Clone)]
 pub struct Table {
     df: DataFrame,
+    /// The data frame without the hidden columns, everything column related works on it.
+    view: DataFrame,
+    hidden: BTreeSet<String>,
     col_widths: Vec<Constraint>,
     col_offsets: Vec<u16>,
     col_space: u16,
     striped: bool,
     show_header: bool,
     selected: Option<usize>,
+    marked: BTreeSet<usize>,
+    bookmarks: BTreeSet<usize>,
+    pinned: usize,
+    highlighted: Option<usize>,
     offset: usize,
     rendered_rows: usize,
+    /// Lines taken up by each rendered row, empty unless cells wrap.
+    row_heights: Vec<usize>,
     rendered_width: u16,
+    rendered_area: Rect,
+    rendered_table_area: Rect,
     column_mode: ColumnMode,
     gutter_mode: GutterMode,
+    format: CellFormat,
+    /// Sizes the columns to the rows on screen instead of the whole column.
+    fit: bool,
+    wrap: bool,
+    /// Column the rows are ordered by and whether the order is descending, marked in its header.
+    sort: Option<(String, bool)>,
+    shade: Option<Shade>,
+    /// Offset and number of rows the fitted widths were computed for.
+    fitted: Option<(usize, usize)>,
+    max_width: Option<usize>,
 }
 
 impl Table {
     pub fn new(df: DataFrame) -> Self {
         let col_space = 1;
+        let view = df.clone();
         let col_widths = df
             .widths()
             .into_iter()
@@ -49,15 +83,31 @@ impl Table {
         Self {
             col_widths,
             selected: None,
+            marked: BTreeSet::new(),
+            bookmarks: BTreeSet::new(),
+            pinned: 0,
+            highlighted: None,
             col_offsets,
             offset: 0,
             rendered_rows: 0,
+            row_heights: Vec::new(),
             rendered_width: 0,
+            rendered_area: Rect::default(),
+            rendered_table_area: Rect::default(),
             column_mode: ColumnMode::Compact,
             striped: false,
             show_header: false,
             gutter_mode: GutterMode::Visible(gutter_width),
+            format: CellFormat::default(),
+            fit: false,
+            wrap: false,
+            sort: None,
+            shade: None,
+            fitted: None,
+            max_width: None,
             df,
+            view,
+            hidden: BTreeSet::new(),
             col_space,
         }
     }
@@ -123,8 +173,110 @@ impl Table {
         &mut self.df
     }
 
+    /// The columns shown on screen, without the hidden ones.
+    pub fn visible_data_frame(&self) -> &DataFrame {
+        &self.view
+    }
+
     pub fn set_data_frame(&mut self, df: DataFrame) {
         self.df = df;
+        self.update_view();
+        self.marked.clear();
+        self.bookmarks.clear();
+        // Keep the selection on a row of the new frame, the offset follows it on the next render
+        let height = self.df.height();
+        self.selected = self
+            .selected
+            .filter(|_| height > 0)
+            .map(|selected| selected.min(height - 1));
+    }
+
+    pub fn marked(&self) -> &BTreeSet<usize> {
+        &self.marked
+    }
+
+    /// Returns the marked rows in their original order, or `None` if no row is marked.
+    pub fn marked_data_frame(&self) -> Option<DataFrame> {
+        (!self.marked.is_empty()).then(|| {
+            self.df
+                .take(&IdxCa::from_vec(
+                    "marked".into(),
+                    self.marked.iter().map(|idx| *idx as IdxSize).collect(),
+                ))
+                .unwrap_or_default()
+        })
+    }
+
+    fn toggle_mark(&mut self) {
+        if let Some(selected) = self.selected
+            && !self.marked.remove(&selected)
+        {
+            self.marked.insert(selected);
+        }
+    }
+
+    pub fn bookmarks(&self) -> &BTreeSet<usize> {
+        &self.bookmarks
+    }
+
+    /// Returns the bookmarked rows in their original order, or `None` if no row is bookmarked.
+    pub fn bookmarked_data_frame(&self) -> Option<DataFrame> {
+        (!self.bookmarks.is_empty()).then(|| {
+            self.df
+                .take(&IdxCa::from_vec(
+                    "bookmarks".into(),
+                    self.bookmarks.iter().map(|idx| *idx as IdxSize).collect(),
+                ))
+                .unwrap_or_default()
+        })
+    }
+
+    /// Bookmarks are row indices, so they are dropped whenever the rows may have moved.
+    pub fn clear_bookmarks(&mut self) {
+        self.bookmarks.clear();
+    }
+
+    fn toggle_bookmark(&mut self) {
+        if let Some(selected) = self.selected
+            && !self.bookmarks.remove(&selected)
+        {
+            self.bookmarks.insert(selected);
+        }
+    }
+
+    /// Selects the nearest bookmarked row after the selection, wrapping around to the first.
+    fn select_next_bookmark(&mut self) {
+        let next = match self.selected {
+            Some(selected) => self.bookmarks.range(selected + 1..).next(),
+            None => None,
+        }
+        .or(self.bookmarks.first())
+        .copied();
+        if next.is_some() {
+            self.select(next);
+        }
+    }
+
+    /// Selects the nearest bookmarked row before the selection, wrapping around to the last.
+    fn select_prev_bookmark(&mut self) {
+        let prev = match self.selected {
+            Some(selected) => self.bookmarks.range(..selected).next_back(),
+            None => None,
+        }
+        .or(self.bookmarks.last())
+        .copied();
+        if prev.is_some() {
+            self.select(prev);
+        }
+    }
+
+    /// Keeps the given number of leading columns in view while scrolling horizontally.
+    pub fn set_pinned(&mut self, pinned: usize) {
+        self.pinned = pinned;
+    }
+
+    pub fn set_striped(&mut self, striped: bool) {
+        self.striped = striped;
     }
 
     pub fn set_gutter_visibility(&mut self, value: bool) {
@@ -135,6 +287,100 @@ impl Table {
         }
     }
 
+    /// Leaves the named columns out of the rendered table, the data frame keeps them.
+    pub fn set_hidden(&mut self, hidden: &BTreeSet<String>) {
+        if &self.hidden != hidden {
+            self.hidden = hidden.clone();
+            self.update_view();
+            self.update_widths();
+            self.highlighted = None;
+        }
+    }
+
+    /// Every column is shown if all of them are hidden, so a table is never left empty.
+    fn update_view(&mut self) {
+        let visible = self
+            .df
+            .get_column_names_owned()
+            .into_iter()
+            .filter(|name| !self.hidden.contains(name.as_str()))
+            .collect_vec();
+        self.view = if visible.is_empty() || visible.len() == self.df.width() {
+            self.df.clone()
+        } else {
+            self.df.select(visible).unwrap_or_else(|_| self.df.clone())
+        };
+        self.shade = self
+            .shade
+            .take()
+            .and_then(|shade| Shade::new(&self.view, &shade.column).ok());
+    }
+
+    pub fn set_format(&mut self, format: &CellFormat) {
+        if &self.format != format {
+            self.format = format.clone();
+            self.update_widths();
+        }
+    }
+
+    /// Toggles sizing the columns to the header and the rendered cells, the widths follow the
+    /// rows on screen while scrolling.
+    pub fn set_fit_columns(&mut self, fit: bool) {
+        if self.fit != fit {
+            self.fit = fit;
+            self.update_widths();
+        }
+    }
+
+    /// Wraps long cells over up to `WRAP_MAX_LINES` lines, rows grow to their tallest cell.
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    /// Upper bound of every column width, longer cells are truncated.
+    pub fn set_max_width(&mut self, max_width: Option<usize>) {
+        if self.max_width != max_width {
+            self.max_width = max_width;
+            self.update_widths();
+        }
+    }
+
+    /// Replaces the value of a single cell, parsed as the type of its column.
+    pub fn set_cell(&mut self, row: usize, column: &str, value: &str) -> AppResult<()> {
+        self.df.set_cell(row, column, value)?;
+        self.update_view();
+        self.update_widths();
+        Ok(())
+    }
+
+    fn update_widths(&mut self) {
+        let widths = if self.fit {
+            self.fitted = Some((self.offset, self.rendered_rows));
+            self.view
+                .slice(self.offset as i64, self.rendered_rows)
+                .widths_with_format(&self.format)
+        } else {
+            self.view.widths_with_format(&self.format)
+        };
+        // The header of the sorted column has room for its arrow
+        let sorted = self.sort.as_ref().and_then(|(column, _)| {
+            self.view
+                .get_column_index(column)
+                .map(|idx| (idx, column.width() + 2))
+        });
+        self.col_widths = widths
+            .into_iter()
+            .map(|u| self.max_width.map_or(u, |max| u.min(max)))
+            .enumerate()
+            .map(|(idx, u)| match sorted {
+                Some((sorted, min)) if sorted == idx => u.max(min),
+                _ => u,
+            })
+            .map(|u| Constraint::Length(u as u16))
+            .collect_vec();
+        self.col_offsets = col_offsets(&self.col_widths, self.col_space);
+    }
+
     pub fn selected(&self) -> Option<usize> {
         self.selected
     }
@@ -202,42 +448,146 @@ impl Table {
         }
     }
 
+    /// Scrolls the named column, or the column at the one based position, to the left edge of
+    /// the scrolled columns and highlights its header.
+    pub fn go_to_column(&mut self, column: &str) -> AppResult<()> {
+        let names = self.view.get_column_names();
+        let idx = names
+            .iter()
+            .position(|name| name.as_str() == column)
+            .or_else(|| {
+                column
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|idx| (1..=names.len()).contains(idx))
+                    .map(|idx| idx - 1)
+            });
+        let Some(idx) = idx else {
+            let lower = column.to_lowercase();
+            let matches = names
+                .iter()
+                .filter(|name| name.to_lowercase().has_subsequence(&lower))
+                .join(", ");
+            if matches.is_empty() {
+                Err(anyhow!("Unknown column '{column}'"))?
+            } else {
+                Err(anyhow!(
+                    "Unknown column '{column}', close matches: {matches}"
+                ))?
+            }
+        };
+        let pinned = self.pinned_count();
+        let offset = self
+            .scroll_offsets()
+            .get(idx.saturating_sub(pinned))
+            .copied()
+            .unwrap_or_default();
+        if let ColumnMode::Expanded(x) = &mut self.column_mode
+            && idx >= pinned
+        {
+            *x = offset;
+        }
+        self.highlighted = Some(idx);
+        Ok(())
+    }
+
+    /// Index of the highlighted column, or else of the left-most scrolled column.
+    pub fn current_column(&self) -> usize {
+        self.highlighted.unwrap_or_else(|| match self.column_mode {
+            ColumnMode::Compact => 0,
+            ColumnMode::Expanded(offset) => {
+                self.pinned_count() + column_index(&self.scroll_offsets(), &offset)
+            }
+        })
+    }
+
+    pub fn sort(&self) -> Option<&(String, bool)> {
+        self.sort.as_ref()
+    }
+
+    pub fn set_sort(&mut self, sort: Option<(String, bool)>) {
+        self.sort = sort;
+        self.update_widths();
+    }
+
+    /// Shades the cells of the numeric column from its min to its max value.
+    pub fn shade(&mut self, column: &str) -> AppResult<()> {
+        self.shade = Some(Shade::new(&self.view, column)?);
+        Ok(())
+    }
+
+    /// Shades the column if the table has it as a numeric column, clears the shading otherwise.
+    pub fn set_shade(&mut self, column: Option<&str>) {
+        if self.shade.as_ref().map(|shade| shade.column.as_str()) != column {
+            self.shade = column.and_then(|column| Shade::new(&self.view, column).ok());
+        }
+    }
+
     fn scroll_left(&mut self) {
+        self.highlighted = None;
         if let ColumnMode::Expanded(st) = &mut self.column_mode {
             *st = st.saturating_sub(1)
         }
     }
 
     fn scroll_right(&mut self) {
+        self.highlighted = None;
         if let ColumnMode::Expanded(st) = &mut self.column_mode {
             *st = st.saturating_add(1)
         }
     }
 
     fn scroll_to_left_column(&mut self) {
+        self.highlighted = None;
+        let col_offsets = self.scroll_offsets();
         if let ColumnMode::Expanded(offset) = &mut self.column_mode {
-            *offset = prev_column_offset(&self.col_offsets, offset);
+            *offset = prev_column_offset(&col_offsets, offset);
         }
     }
 
     fn scroll_to_right_column(&mut self) {
+        self.highlighted = None;
+        let col_offsets = self.scroll_offsets();
         if let ColumnMode::Expanded(offset) = &mut self.column_mode {
-            *offset = next_column_offset(&self.col_offsets, offset);
+            *offset = next_column_offset(&col_offsets, offset);
         }
     }
 
     fn scroll_to_first_column(&mut self) {
+        self.highlighted = None;
         if let ColumnMode::Expanded(offset) = &mut self.column_mode {
             *offset = 0;
         }
     }
 
     fn scroll_to_last_column(&mut self) {
+        self.highlighted = None;
+        let last = self.scroll_offsets().last().copied().unwrap_or(0);
         if let ColumnMode::Expanded(offset) = &mut self.column_mode {
-            *offset = self.col_offsets.last().copied().unwrap_or(0);
+            *offset = last;
+        }
+    }
+
+    /// Number of pinned columns in effect, at least one column is always left to scroll and
+    /// nothing is pinned while every column fits on the screen.
+    fn pinned_count(&self) -> usize {
+        match self.column_mode {
+            ColumnMode::Compact => 0,
+            ColumnMode::Expanded(_) => self.pinned.min(self.view.width().saturating_sub(1)),
         }
     }
 
+    /// Offsets of the columns after the pinned ones, relative to the first of them.
+    fn scroll_offsets(&self) -> Vec<u16> {
+        let pinned = self.pinned_count();
+        let base = self.col_offsets.get(pinned).copied().unwrap_or_default();
+        self.col_offsets
+            .iter()
+            .skip(pinned)
+            .map(|offset| offset - base)
+            .collect()
+    }
+
     fn half_page_up(&mut self) {
         if let Some(selected) = self.selected {
             self.select(selected.saturating_sub(self.rendered_rows.div(2)));
@@ -250,10 +600,92 @@ impl Table {
         }
     }
 
+    /// Indices of the view columns on screen in a table area of the given width.
+    fn visible_columns(&self, width: u16) -> Vec<usize> {
+        let ColumnMode::Expanded(x) = self.column_mode else {
+            return (0..self.view.width()).collect();
+        };
+        let pinned = self.pinned_count();
+        let width = width.saturating_sub(self.col_offsets[pinned].min(width));
+        let col_offsets = self.scroll_offsets();
+        let total_width = col_offsets.last().copied().unwrap_or(0).max(width);
+        let x = x.min(total_width.saturating_sub(width));
+        let col_start = column_index(&col_offsets, &x);
+        let col_end = column_index(&col_offsets, &x.add(width));
+        (0..pinned)
+            .chain(pinned + col_start..=pinned + col_end)
+            .collect()
+    }
+
+    /// Lines the row takes up if the cells of the given view columns wrap.
+    fn row_height(&self, row: usize, columns: &[usize]) -> usize {
+        columns
+            .iter()
+            .filter_map(|idx| {
+                let column = self.view.get_columns().get(*idx)?;
+                let width = self.col_widths.get(*idx)?.value() as usize;
+                let text = self.format.format(column.name(), column.get(row).ok()?);
+                Some(wrap_text(&text, width, WRAP_MAX_LINES).len())
+            })
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// First row to render and the heights of the rows that fit into the lines when cells wrap,
+    /// the offset moves as little as possible to keep the selection on screen.
+    fn wrapped_rows(&self, lines: usize, columns: &[usize]) -> (usize, Vec<usize>) {
+        let rows = self.df.height();
+        let mut offset = self.offset.min(rows.saturating_sub(1));
+        if let Some(selected) = self.selected {
+            let mut used = 0;
+            if selected < offset {
+                offset = selected;
+            } else if !(offset..=selected).all(|row| {
+                used += self.row_height(row, columns);
+                used <= lines
+            }) {
+                // Fill the screen upwards from the selection
+                offset = selected;
+                used = self.row_height(selected, columns);
+                while let Some(prev) = offset.checked_sub(1) {
+                    used += self.row_height(prev, columns);
+                    if used > lines {
+                        break;
+                    }
+                    offset = prev;
+                }
+            }
+        }
+        let mut heights = Vec::new();
+        let mut used = 0;
+        for row in offset..rows {
+            let height = self.row_height(row, columns);
+            used += height;
+            // A row taller than the screen is still rendered, cut at the bottom
+            if used > lines && (!heights.is_empty() || lines == 0) {
+                break;
+            }
+            heights.push(height);
+        }
+        (offset, heights)
+    }
+
     fn gutter_item(&self, idx: usize) -> ListItem<'_> {
+        let height = self
+            .row_heights
+            .get(idx.saturating_sub(self.offset))
+            .copied()
+            .unwrap_or(1);
         ListItem::new(Text::raw(format!(
-            "  {:>w$}  ",
+            "{} {:>w$} {}{}",
+            if self.marked.contains(&idx) { '*' } else { ' ' },
             idx + 1,
+            if self.bookmarks.contains(&idx) {
+                '<'
+            } else {
+                ' '
+            },
+            "\n".repeat(height.saturating_sub(1)),
             w = self.gutter_mode.width().into()
         )))
         .style(theme().gutter(idx))
@@ -277,10 +709,78 @@ impl Table {
         }
     }
 
+    /// Returns the data row rendered at the screen position, if any.
+    fn row_at(&self, column: u16, row: u16) -> Option<usize> {
+        let first = self.rendered_area.y + u16::from(self.show_header);
+        if !self.rendered_area.contains(Position::new(column, row)) || row < first {
+            return None;
+        }
+        let line = (row - first) as usize;
+        if self.row_heights.is_empty() {
+            Some(self.offset + line)
+        } else {
+            self.row_heights
+                .iter()
+                .scan(0, |end, height| {
+                    *end += height;
+                    Some(*end)
+                })
+                .position(|end| line < end)
+                .map(|idx| self.offset + idx)
+        }
+        .filter(|idx| *idx < self.df.height())
+    }
+
+    /// Returns the index of the column whose header is rendered at the screen position, if any.
+    pub fn header_column_at(&self, column: u16, row: u16) -> Option<usize> {
+        let area = self.rendered_table_area;
+        if !self.show_header || row != area.y || !area.contains(Position::new(column, row)) {
+            return None;
+        }
+        let pinned = self.pinned_count();
+        let pinned_width = self.col_offsets[pinned].min(area.width);
+        let column = column - area.x;
+        if column < pinned_width {
+            return Some(column_index(&self.col_offsets[..=pinned], &column));
+        }
+        let scroll = match self.column_mode {
+            ColumnMode::Compact => 0,
+            ColumnMode::Expanded(x) => x,
+        };
+        let col_offsets = self.scroll_offsets();
+        let x = scroll + column - pinned_width;
+        (x < col_offsets.last().copied().unwrap_or_default())
+            .then(|| pinned + column_index(&col_offsets, &x))
+    }
+
     fn minimum_compact_width(&self) -> u16 {
-        let col_count = self.df.width() as u16;
+        let col_count = self.view.width() as u16;
         col_count + (col_count.saturating_sub(1) * self.col_space)
     }
+
+    /// Modified columns of every rendered row if the table is a diff, empty otherwise.
+    fn changed_columns(&self, height: usize) -> Vec<Vec<String>> {
+        let Some(changed) = self
+            .df
+            .column(DIFF_CHANGED)
+            .ok()
+            .and_then(|column| column.str().ok())
+        else {
+            return Vec::new();
+        };
+        changed
+            .slice(self.offset as i64, height)
+            .into_iter()
+            .map(|columns| {
+                columns
+                    .unwrap_or_default()
+                    .split(", ")
+                    .filter(|name| !name.is_empty())
+                    .map(ToOwned::to_owned)
+                    .collect()
+            })
+            .collect()
+    }
 }
 
 impl Component for Table {
@@ -295,18 +795,34 @@ impl Component for Table {
         } else {
             area.height
         } as usize;
-        self.rendered_rows = height;
         self.rendered_width = area.width;
 
-        if let Some(selected) = self.selected {
-            self.offset = self
-                .offset
-                .clamp(selected.saturating_sub(height.saturating_sub(1)), selected);
-        } else {
-            self.offset = self.offset.min(self.df.height().saturating_sub(height))
+        let (gutter_area, table_area) = self.gutter_table_area(area);
+        self.rendered_area = area;
+        self.rendered_table_area = table_area;
+
+        if table_area.width < self.minimum_compact_width()
+            && matches!(self.column_mode, ColumnMode::Compact)
+        {
+            self.column_mode = ColumnMode::Expanded(0);
         }
 
-        let (gutter_area, table_area) = self.gutter_table_area(area);
+        // Wrapped rows take up a varying number of lines, only the rows that fit are rendered
+        if self.wrap {
+            let columns = self.visible_columns(table_area.width);
+            let (offset, heights) = self.wrapped_rows(height, &columns);
+            self.offset = offset;
+            self.rendered_rows = heights.len();
+            self.row_heights = heights;
+        } else {
+            self.offset = visible_offset(self.offset, self.selected, height, self.df.height());
+            self.rendered_rows = height;
+            self.row_heights.clear();
+        }
+        let height = self.rendered_rows;
+        if self.fit && self.fitted != Some((self.offset, height)) {
+            self.update_widths();
+        }
 
         if let Some(gutter_area) = gutter_area {
             List::default()
@@ -326,60 +842,103 @@ impl Component for Table {
                 );
         }
 
-        if table_area.width < self.minimum_compact_width()
-            && matches!(self.column_mode, ColumnMode::Compact)
-        {
-            self.column_mode = ColumnMode::Expanded(0);
-        }
+        let selected = if focus_state.is_focused() {
+            self.selected.map(|s| s.saturating_sub(self.offset))
+        } else {
+            None
+        };
+        let pinned = self.pinned_count();
+        let col_offsets = self.scroll_offsets();
+        let changed = self.changed_columns(height);
 
         match &mut self.column_mode {
             ColumnMode::Compact => {
-                let df = self.df.slice(self.offset as i64, height);
+                let df = self.view.slice(self.offset as i64, height);
                 let table = build_table(
                     &df,
                     &self.col_widths,
                     self.col_space,
                     self.show_header,
                     self.striped,
+                    &self.format,
                     self.offset,
                     0,
+                    self.highlighted,
+                    &changed,
+                    self.wrap,
+                    self.sort.as_ref(),
+                    self.shade.as_ref(),
                 );
                 table.render(
                     table_area,
                     buf,
-                    &mut TableState::default().with_selected(if focus_state.is_focused() {
-                        self.selected.map(|s| s.saturating_sub(self.offset))
-                    } else {
-                        None
-                    }),
+                    &mut TableState::default().with_selected(selected),
                 );
             }
             ColumnMode::Expanded(x) => {
-                let total_width = self
-                    .col_offsets
+                let [pinned_area, table_area] = Layout::horizontal([
+                    Constraint::Length(self.col_offsets[pinned].min(table_area.width)),
+                    Constraint::Fill(1),
+                ])
+                .areas(table_area);
+                if pinned > 0 {
+                    let df = self
+                        .view
+                        .select_by_range(0..pinned)
+                        .unwrap()
+                        .slice(self.offset as i64, height);
+                    build_table(
+                        &df,
+                        &self.col_widths[..pinned],
+                        self.col_space,
+                        self.show_header,
+                        self.striped,
+                        &self.format,
+                        self.offset,
+                        0,
+                        self.highlighted,
+                        &changed,
+                        self.wrap,
+                        self.sort.as_ref(),
+                        self.shade.as_ref(),
+                    )
+                    .render(
+                        pinned_area,
+                        buf,
+                        &mut TableState::default().with_selected(selected),
+                    );
+                }
+
+                let total_width = col_offsets
                     .last()
                     .copied()
                     .unwrap_or(0)
                     .max(table_area.width);
                 *x = (*x).min(total_width.saturating_sub(table_area.width));
-                let col_start = column_index(&self.col_offsets, x);
-                let col_end = column_index(&self.col_offsets, &x.add(table_area.width));
+                let col_start = column_index(&col_offsets, x);
+                let col_end = column_index(&col_offsets, &x.add(table_area.width));
                 let df = self
-                    .df
-                    .select_by_range(col_start..=col_end)
+                    .view
+                    .select_by_range(pinned + col_start..=pinned + col_end)
                     .unwrap()
                     .slice(self.offset as i64, height);
                 let table = build_table(
                     &df,
-                    &self.col_widths[col_start..=col_end],
+                    &self.col_widths[pinned + col_start..=pinned + col_end],
                     self.col_space,
                     self.show_header,
                     self.striped,
+                    &self.format,
                     self.offset,
-                    col_start,
+                    pinned + col_start,
+                    self.highlighted,
+                    &changed,
+                    self.wrap,
+                    self.sort.as_ref(),
+                    self.shade.as_ref(),
                 );
-                let width = (self.col_offsets[col_end + 1] - self.col_offsets[col_start])
-                    .max(table_area.width);
+                let width =
+                    (col_offsets[col_end + 1] - col_offsets[col_start]).max(table_area.width);
                 let size = ratatui::layout::Size {
                     width,
                     height: table_area.height,
@@ -389,18 +948,14 @@ impl Component for Table {
                 scroll_area.render_stateful_widget(
                     table,
                     scroll_area.area(),
-                    &mut TableState::default().with_selected(if focus_state.is_focused() {
-                        self.selected.map(|s| s.saturating_sub(self.offset))
-                    } else {
-                        None
-                    }),
+                    &mut TableState::default().with_selected(selected),
                 );
                 scroll_area.render(
                     table_area,
                     buf,
                     &mut ScrollViewState::with_offset(Position {
                         x: x.saturating_sub(
-                            self.col_offsets.get(col_start).copied().unwrap_or_default(),
+                            col_offsets.get(col_start).copied().unwrap_or_default(),
                         ),
                         y: 0,
                     }),
@@ -464,6 +1019,26 @@ impl Component for Table {
                 self.scroll_to_left_column();
                 true
             }
+            (KeyCode::Char('m'), KeyModifiers::NONE) => {
+                self.toggle_mark();
+                true
+            }
+            (KeyCode::Char('M'), KeyModifiers::SHIFT) => {
+                self.marked.clear();
+                true
+            }
+            (KeyCode::Char('\''), KeyModifiers::NONE) => {
+                self.toggle_bookmark();
+                true
+            }
+            (KeyCode::Char(']'), KeyModifiers::NONE) => {
+                self.select_next_bookmark();
+                true
+            }
+            (KeyCode::Char('['), KeyModifiers::NONE) => {
+                self.select_prev_bookmark();
+                true
+            }
             (KeyCode::Char('_'), _) => {
                 self.scroll_to_first_column();
                 true
@@ -475,6 +1050,36 @@ impl Component for Table {
             _ => false,
         }
     }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> bool {
+        match event.kind {
+            MouseEventKind::ScrollUp => {
+                self.select_up();
+                true
+            }
+            MouseEventKind::ScrollDown => {
+                self.select_down();
+                true
+            }
+            MouseEventKind::ScrollLeft => {
+                self.scroll_left();
+                true
+            }
+            MouseEventKind::ScrollRight => {
+                self.scroll_right();
+                true
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(idx) = self.row_at(event.column, event.row) {
+                    self.select(idx);
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -498,6 +1103,104 @@ impl GutterMode {
     }
 }
 
+/// Numeric column whose cells get a background between the theme colors of its min and max.
+#[derive(Debug, Clone, PartialEq)]
+struct Shade {
+    column: String,
+    min: f64,
+    max: f64,
+}
+
+impl Shade {
+    fn new(df: &DataFrame, column: &str) -> AppResult<Self> {
+        let column = df.column(column)?;
+        if !column.dtype().is_primitive_numeric() {
+            Err(anyhow!(
+                "Column '{}' is {}, only numeric columns can be shaded",
+                column.name(),
+                column.dtype()
+            ))?
+        }
+        let values = column.cast(&DataType::Float64)?;
+        let values = values.f64()?;
+        let (Some(min), Some(max)) = (values.min(), values.max()) else {
+            Err(anyhow!("Column '{}' has no values to shade", column.name()))?
+        };
+        Ok(Self {
+            column: column.name().to_string(),
+            min,
+            max,
+        })
+    }
+
+    /// Background of a cell along the theme gradient, nulls get a neutral one.
+    fn style(&self, value: &AnyValue) -> Style {
+        let [low, high] = [0, 3].map(|idx| theme().graph(idx).fg.unwrap_or_default());
+        let (background, foreground) = {
+            let text = theme().text();
+            (text.bg.unwrap_or_default(), text.fg.unwrap_or_default())
+        };
+        match value.extract::<f64>().filter(|value| !value.is_nan()) {
+            Some(value) => {
+                let ratio = if self.max > self.min {
+                    (value - self.min) / (self.max - self.min)
+                } else {
+                    0.5
+                };
+                Style::default()
+                    .bg(blend(low, high, ratio.clamp(0.0, 1.0)))
+                    .fg(background)
+            }
+            None => Style::default().bg(blend(background, foreground, 0.2)),
+        }
+    }
+}
+
+/// First row to render so that the selection is one of the `rows` visible rows, without a
+/// selection the rows below the data are kept off the screen instead.
+fn visible_offset(offset: usize, selected: Option<usize>, rows: usize, height: usize) -> usize {
+    match selected {
+        Some(selected) => offset.clamp(selected.saturating_sub(rows.saturating_sub(1)), selected),
+        None => offset.min(height.saturating_sub(rows)),
+    }
+}
+
+/// Upper bound of the lines a wrapped cell takes up.
+const WRAP_MAX_LINES: usize = 4;
+
+/// Splits the text into lines of at most `width` columns, breaking between words where possible.
+/// Text beyond `max_lines` is cut and the last line ends with an ellipsis instead.
+fn wrap_text(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    for word in text.split_inclusive(' ') {
+        if line_width > 0 && line_width + word.trim_end().width() > width {
+            lines.push(std::mem::take(&mut line).trim_end().to_owned());
+            line_width = 0;
+        }
+        for c in word.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if line_width > 0 && line_width + char_width > width && c != ' ' {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push(c);
+            line_width += char_width;
+        }
+    }
+    lines.push(line.trim_end().to_owned());
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            while last.width() + 1 > width && last.pop().is_some() {}
+            last.push('…');
+        }
+    }
+    lines
+}
+
 fn col_offsets(col_widths: &[Constraint], col_space: u16) -> Vec<u16> {
     std::iter::once(0)
         .chain(
@@ -543,14 +1246,21 @@ fn next_column_offset(col_offsets: &[u16], offset: &u16) -> u16 {
         .unwrap_or_default()
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_table<'a>(
     df: &'a DataFrame,
     col_widths: &[Constraint],
     col_space: u16,
     show_header: bool,
     striped: bool,
+    format: &CellFormat,
     offset_row: usize,
     offset_col: usize,
+    highlighted: Option<usize>,
+    changed: &[Vec<String>],
+    wrap: bool,
+    sort: Option<&(String, bool)>,
+    shade: Option<&Shade>,
 ) -> ratatui::widgets::Table<'a> {
     let mut table = ratatui::widgets::Table::default()
         .widths(col_widths)
@@ -563,10 +1273,38 @@ fn build_table<'a>(
                 .zip_iters()
                 .enumerate()
                 .map(|(idx, vals)| {
-                    let cells = vals
+                    let mut lines = 1;
+                    let cells = df
+                        .get_column_names()
                         .into_iter()
-                        .map(|val| Cell::new(val.into_single_line()));
-                    Row::new(cells).style(if striped {
+                        .zip(col_widths)
+                        .zip(vals)
+                        .map(|((col, width), val)| {
+                            let style = shade
+                                .filter(|shade| shade.column == col.as_str())
+                                .map(|shade| shade.style(&val))
+                                .unwrap_or_default();
+                            let text = format.format(col, val);
+                            let cell = if wrap {
+                                let wrapped =
+                                    wrap_text(&text, width.value() as usize, WRAP_MAX_LINES);
+                                lines = lines.max(wrapped.len());
+                                Cell::new(Text::from_iter(wrapped))
+                            } else {
+                                Cell::new(text)
+                            }
+                            .style(style);
+                            if changed
+                                .get(idx)
+                                .is_some_and(|changed| changed.iter().any(|name| name == col))
+                            {
+                                cell.style(theme().error())
+                            } else {
+                                cell
+                            }
+                        })
+                        .collect_vec();
+                    Row::new(cells).height(lines as u16).style(if striped {
                         theme().row(offset_row + idx)
                     } else {
                         theme().row(0)
@@ -575,13 +1313,318 @@ fn build_table<'a>(
         );
 
     if show_header {
-        table =
-            table.header(
-                Row::new(df.iter().enumerate().map(|(i, d)| {
-                    Cell::new(d.name().as_str()).style(theme().header(offset_col + i))
-                }))
-                .style(theme().table_header()),
-            )
+        table = table.header(
+            Row::new(df.iter().enumerate().map(|(i, d)| {
+                let name = match sort {
+                    Some((column, false)) if column == d.name().as_str() => format!("{column} ▲"),
+                    Some((column, true)) if column == d.name().as_str() => format!("{column} ▼"),
+                    _ => d.name().to_string(),
+                };
+                Cell::new(name).style(if highlighted == Some(offset_col + i) {
+                    theme().row_highlighted()
+                } else {
+                    theme().header(offset_col + i)
+                })
+            }))
+            .style(theme().table_header()),
+        )
     }
     table
 }
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyEvent;
+    use polars::prelude::NamedFrom;
+    use ratatui::buffer::Buffer;
+
+    use super::*;
+    use crate::tui::component::FocusState;
+
+    /// Renders a table with a header into a pane of the given height after every key and checks
+    /// that the selection is drawn.
+    fn assert_selection_visible(rows: usize, area_height: u16, keys: &[(char, usize)]) {
+        let df = DataFrame::new(vec![
+            Series::new("a".into(), (0..rows as i64).collect_vec()).into(),
+        ])
+        .unwrap();
+        let mut table = Table::new(df).with_show_header(true).with_selected(0);
+        let area = Rect::new(0, 0, 20, area_height);
+        let mut buf = Buffer::empty(area);
+        table.render(area, &mut buf, FocusState::Focused);
+        for (key, times) in keys {
+            for _ in 0..*times {
+                table.handle(KeyEvent::new(KeyCode::Char(*key), KeyModifiers::CONTROL));
+                table.render(area, &mut buf, FocusState::Focused);
+                let selected = table.selected().unwrap();
+                assert!(selected < rows);
+                assert!(
+                    (table.offset..table.offset + table.rendered_rows).contains(&selected),
+                    "row {selected} outside of {} + {}",
+                    table.offset,
+                    table.rendered_rows
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_page_jumps_keep_selection_visible() {
+        let keys = [('d', 12), ('u', 12), ('f', 5), ('b', 5), ('d', 3), ('b', 1)];
+        // Shorter than, equal to and taller than the nine rows below the header
+        for rows in [4, 9, 100] {
+            for area_height in [2, 9, 10, 11] {
+                assert_selection_visible(rows, area_height, &keys);
+            }
+        }
+    }
+
+    #[test]
+    fn test_go_to_column() {
+        let df = DataFrame::new(
+            ["id", "first_name", "last_name", "age"]
+                .into_iter()
+                .map(|name| Series::new(name.into(), [1]).into())
+                .collect(),
+        )
+        .unwrap();
+        let mut table = Table::new(df).with_extended_column();
+        table.go_to_column("last_name").unwrap();
+        assert_eq!(table.highlighted, Some(2));
+        assert!(matches!(table.column_mode, ColumnMode::Expanded(x) if x == table.col_offsets[2]));
+        table.go_to_column("2").unwrap();
+        assert_eq!(table.highlighted, Some(1));
+        assert_eq!(
+            table.go_to_column("nme").unwrap_err().to_string(),
+            "Unknown column 'nme', close matches: first_name, last_name"
+        );
+        assert_eq!(
+            table.go_to_column("5").unwrap_err().to_string(),
+            "Unknown column '5'"
+        );
+    }
+
+    #[test]
+    fn test_current_column_and_sort_header() {
+        let df = DataFrame::new(
+            ["id", "name", "age"]
+                .into_iter()
+                .map(|name| Series::new(name.into(), [1]).into())
+                .collect(),
+        )
+        .unwrap();
+        let mut table = Table::new(df).with_extended_column().with_show_header(true);
+        assert_eq!(table.current_column(), 0);
+        table.scroll_to_right_column();
+        assert_eq!(table.current_column(), 1);
+        table.go_to_column("age").unwrap();
+        assert_eq!(table.current_column(), 2);
+
+        table.set_sort(Some(("name".to_owned(), true)));
+        let area = Rect::new(0, 0, 30, 3);
+        let mut buf = Buffer::empty(area);
+        table.render(area, &mut buf, FocusState::Focused);
+        let header = (0..area.width)
+            .map(|x| buf[(x, 0)].symbol())
+            .collect::<String>();
+        assert!(header.contains("name ▼"), "{header}");
+    }
+
+    #[test]
+    fn test_shade() {
+        let df = DataFrame::new(vec![
+            Series::new("value".into(), [Some(1), Some(3), None]).into(),
+            Series::new("name".into(), ["a", "b", "c"]).into(),
+        ])
+        .unwrap();
+        let mut table = Table::new(df);
+        assert_eq!(
+            table.shade("name").unwrap_err().to_string(),
+            "Column 'name' is str, only numeric columns can be shaded"
+        );
+        assert!(table.shade("missing").is_err());
+        table.shade("value").unwrap();
+        let shade = table.shade.clone().unwrap();
+        assert_eq!((shade.min, shade.max), (1.0, 3.0));
+        let [low, high] = [0, 3].map(|idx| theme().graph(idx).fg.unwrap_or_default());
+        assert_eq!(shade.style(&AnyValue::Int32(1)).bg, Some(low));
+        assert_eq!(shade.style(&AnyValue::Int32(3)).bg, Some(high));
+        assert_ne!(shade.style(&AnyValue::Null).bg, Some(low));
+
+        table.set_hidden(&BTreeSet::from(["value".to_owned()]));
+        assert_eq!(table.shade, None);
+        table.set_hidden(&BTreeSet::new());
+        table.set_shade(Some("value"));
+        assert!(table.shade.is_some());
+        table.set_shade(None);
+        assert_eq!(table.shade, None);
+    }
+
+    #[test]
+    fn test_changed_columns() {
+        let df = DataFrame::new(vec![
+            Series::new("a".into(), [1, 2, 3]).into(),
+            Series::new(DIFF_CHANGED.into(), ["a", "", "a, b"]).into(),
+        ])
+        .unwrap();
+        let mut table = Table::new(df);
+        assert_eq!(table.changed_columns(2), [vec!["a".to_owned()], Vec::new()]);
+        table.offset = 2;
+        assert_eq!(table.changed_columns(2), [["a", "b"]]);
+        table.set_data_frame(DataFrame::empty());
+        assert!(table.changed_columns(2).is_empty());
+    }
+
+    #[test]
+    fn test_visible_offset() {
+        assert_eq!(visible_offset(0, Some(4), 5, 100), 0);
+        assert_eq!(visible_offset(0, Some(5), 5, 100), 1);
+        assert_eq!(visible_offset(50, Some(10), 5, 100), 10);
+        assert_eq!(visible_offset(7, Some(2), 5, 3), 2);
+        assert_eq!(visible_offset(3, Some(3), 0, 100), 3);
+        assert_eq!(visible_offset(90, None, 20, 100), 80);
+        assert_eq!(visible_offset(90, None, 20, 10), 0);
+    }
+
+    #[test]
+    fn test_set_data_frame_clamps_selection() {
+        let rows = |n: i64| {
+            DataFrame::new(vec![Series::new("a".into(), (0..n).collect_vec()).into()]).unwrap()
+        };
+        let mut table = Table::new(rows(100)).with_selected(99);
+        table.set_data_frame(rows(5));
+        assert_eq!(table.selected(), Some(4));
+        table.set_data_frame(rows(0));
+        assert_eq!(table.selected(), None);
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(wrap_text("short", 10, 4), ["short"]);
+        assert_eq!(
+            wrap_text("the quick brown fox jumps", 10, 4),
+            ["the quick", "brown fox", "jumps"]
+        );
+        assert_eq!(wrap_text("abcdefghij", 4, 4), ["abcd", "efgh", "ij"]);
+        assert_eq!(wrap_text("aaaa bbbb cccc dddd", 4, 2), ["aaaa", "bbb…"]);
+        assert_eq!(wrap_text("", 4, 2), [""]);
+    }
+
+    #[test]
+    fn test_wrapped_rows() {
+        let df = DataFrame::new(vec![
+            Series::new("a".into(), ["x", "a long text that wraps", "y", "z", "w"]).into(),
+        ])
+        .unwrap();
+        let mut table = Table::new(df).with_selected(0).with_compaect_column();
+        table.set_max_width(Some(6));
+        table.set_wrap(true);
+        let area = Rect::new(0, 0, 20, 5);
+        let mut buf = Buffer::empty(area);
+        table.render(area, &mut buf, FocusState::Focused);
+        assert_eq!(table.row_heights, [1, 4]);
+        assert_eq!(table.row_at(10, 3), Some(1));
+
+        table.select(3);
+        table.render(area, &mut buf, FocusState::Focused);
+        assert_eq!((table.offset, table.rendered_rows), (2, 3));
+        table.select(1);
+        table.render(area, &mut buf, FocusState::Focused);
+        assert_eq!(table.offset, 1);
+        assert_eq!(table.row_heights, [4, 1]);
+    }
+
+    #[test]
+    fn test_bookmarks() {
+        let df = DataFrame::new(vec![
+            Series::new("a".into(), (0..10i64).collect_vec()).into(),
+        ])
+        .unwrap();
+        let mut table = Table::new(df.clone()).with_selected(2);
+        let key = |table: &mut Table, c| {
+            table.handle(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+        };
+        key(&mut table, '\'');
+        table.select(7);
+        key(&mut table, '\'');
+        table.select(4);
+        key(&mut table, ']');
+        assert_eq!(table.selected(), Some(7));
+        key(&mut table, ']');
+        assert_eq!(table.selected(), Some(2));
+        key(&mut table, '[');
+        assert_eq!(table.selected(), Some(7));
+        assert_eq!(
+            table
+                .bookmarked_data_frame()
+                .unwrap()
+                .column("a")
+                .unwrap()
+                .i64()
+                .unwrap()
+                .get(1),
+            Some(7)
+        );
+
+        table.set_data_frame(df);
+        assert!(table.bookmarks().is_empty());
+        key(&mut table, ']');
+        assert_eq!(table.selected(), Some(7));
+    }
+
+    #[test]
+    fn test_fit_columns() {
+        let df = DataFrame::new(vec![
+            Series::new(
+                "name".into(),
+                (0..20)
+                    .map(|idx| if idx < 15 { "ab" } else { "a long name" })
+                    .collect_vec(),
+            )
+            .into(),
+        ])
+        .unwrap();
+        let mut table = Table::new(df).with_show_header(true).with_selected(0);
+        let area = Rect::new(0, 0, 30, 6);
+        let mut buf = Buffer::empty(area);
+        table.set_fit_columns(true);
+        table.render(area, &mut buf, FocusState::Focused);
+        assert_eq!(table.col_widths, [Constraint::Length(4)]);
+
+        table.select(19);
+        table.render(area, &mut buf, FocusState::Focused);
+        assert_eq!(table.col_widths, [Constraint::Length(11)]);
+
+        table.set_max_width(Some(6));
+        assert_eq!(table.col_widths, [Constraint::Length(6)]);
+        table.set_fit_columns(false);
+        table.set_max_width(None);
+        assert_eq!(table.col_widths, [Constraint::Length(11)]);
+    }
+
+    #[test]
+    fn test_hidden_columns() {
+        let df = DataFrame::new(
+            ["id", "name", "age"]
+                .into_iter()
+                .map(|name| Series::new(name.into(), [1]).into())
+                .collect(),
+        )
+        .unwrap();
+        let mut table = Table::new(df);
+        table.set_hidden(&BTreeSet::from(["name".to_owned()]));
+        assert_eq!(table.visible_data_frame().get_column_names(), ["id", "age"]);
+        assert_eq!(table.data_frame().width(), 3);
+        assert_eq!(table.col_widths.len(), 2);
+        table.go_to_column("age").unwrap();
+        assert_eq!(table.highlighted, Some(1));
+        assert!(table.go_to_column("name").is_err());
+
+        table.set_hidden(&BTreeSet::from(
+            ["id", "name", "age"].map(ToOwned::to_owned),
+        ));
+        assert_eq!(table.visible_data_frame().width(), 3);
+        table.set_hidden(&BTreeSet::new());
+        assert_eq!(table.col_widths.len(), 3);
+    }
+}
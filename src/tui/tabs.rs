@@ -1,14 +1,23 @@
+use std::time::Duration;
+
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::widgets::{Borders, Widget};
 
 use crate::{
     handler::message::Message,
-    misc::config::config,
+    misc::{
+        config::{ThemeOverride, config},
+        globals::sql,
+        sql::{QueryProgress, TableInfo},
+        type_ext::{human_readable_duration, human_readable_size},
+    },
+    reader::ImportProgress,
     tui::{
         component::{Component, FocusState},
         pane::TableDescription,
         widgets::block::Block,
     },
+    writer::WriteProgress,
 };
 
 use super::{
@@ -17,10 +26,19 @@ use super::{
     tab_switcher::TabSwitcher,
 };
 
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
 #[derive(Debug)]
 pub struct Tabs {
     panes: Vec<Pane>,
     switcher: Option<TabSwitcher>,
+    /// Exports running in the background, oldest first.
+    write_progress: Vec<WriteProgress>,
+    /// Imports running in the background, oldest first.
+    import_progress: Vec<ImportProgress>,
+    /// Queries running in the background, oldest first.
+    query_progress: Vec<QueryProgress>,
+    query_time: Option<Duration>,
     idx: usize,
 }
 
@@ -29,6 +47,10 @@ impl Tabs {
         self.panes.get(self.idx)
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &Pane> {
+        self.panes.iter()
+    }
+
     fn add(&mut self, tabular: Pane) {
         self.panes.push(tabular);
         self.idx = self.panes.len().saturating_sub(1);
@@ -98,13 +120,18 @@ impl Component for Tabs {
     ) {
         // fix state (if invalid)
         self.idx = self.idx().min(self.len().saturating_sub(1));
+        let _theme = ThemeOverride::new(self.selected().and_then(Pane::theme).cloned());
 
         // build the status bar
-        let status_bar = self
+        let mut status_bar = self
             .panes
             .get(self.idx)
             .map(|tabular| {
-                StatusBar::default()
+                let status_bar = match tabular.predicate() {
+                    Some(predicate) => StatusBar::default().tag(Tag::new("Where", predicate)),
+                    None => StatusBar::default(),
+                };
+                status_bar
                     .tag(match tabular.description() {
                         TableDescription::Table(desc) => Tag::new("Table", desc),
                         TableDescription::Query(desc) => Tag::new("Query", desc),
@@ -117,14 +144,7 @@ impl Component for Tabs {
                         "Tab",
                         format!("{} / {}", self.idx + 1, self.len()),
                     ))
-                    .tag(Tag::new(
-                        "Row",
-                        format!(
-                            "{:>width$}",
-                            tabular.table().selected().unwrap_or_default() + 1,
-                            width = tabular.table().data_frame().height().to_string().len()
-                        ),
-                    ))
+                    .tag(Tag::new("Row", row_position(tabular)))
                     .tag(Tag::new(
                         "Shape",
                         format!(
@@ -135,6 +155,59 @@ impl Component for Tabs {
                     ))
             })
             .unwrap_or_default();
+        if let Some(column) = self.selected().and_then(column_position) {
+            status_bar = status_bar.tag(Tag::new("Column", column));
+        }
+        if let Some(TableDescription::Table(name)) = self.selected().map(Pane::base_description)
+            && let Some(preview) = sql().schema().get(name).and_then(TableInfo::preview)
+        {
+            status_bar = status_bar.tag(Tag::new("Showing", preview.to_string()));
+        }
+        if let Some(marked) = self
+            .selected()
+            .map(|pane| pane.table().marked().len())
+            .filter(|marked| *marked > 0)
+        {
+            status_bar = status_bar.tag(Tag::new("Marked", marked.to_string()));
+        }
+        if let Some(bookmarks) = self
+            .selected()
+            .map(|pane| pane.table().bookmarks().len())
+            .filter(|bookmarks| *bookmarks > 0)
+        {
+            status_bar = status_bar.tag(Tag::new("Bookmarks", bookmarks.to_string()));
+        }
+        if let Some(duration) = self.query_time {
+            status_bar = status_bar.tag(Tag::new("Query Time", human_readable_duration(duration)));
+        }
+        if !self.write_progress.is_empty() {
+            let bytes = self.write_progress.iter().map(WriteProgress::bytes).sum();
+            let title = match self.write_progress.len() {
+                1 => "Exporting".to_owned(),
+                count => format!("Exporting {count} tables"),
+            };
+            status_bar = status_bar.tag(Tag::new(title, human_readable_size(bytes)));
+        }
+        if let Some(progress) = self.import_progress.first() {
+            let elapsed = progress.elapsed();
+            let spinner = SPINNER[(elapsed.as_millis() / 100) as usize % SPINNER.len()];
+            let title = match self.import_progress.len() {
+                1 => "Importing (Esc to cancel)".to_owned(),
+                count => format!("Importing {count} sources (Esc to cancel)"),
+            };
+            status_bar =
+                status_bar.tag(Tag::new(title, format!("{spinner} {}s", elapsed.as_secs())));
+        }
+        if let Some(progress) = self.query_progress.first() {
+            let elapsed = progress.elapsed();
+            let spinner = SPINNER[(elapsed.as_millis() / 100) as usize % SPINNER.len()];
+            let title = match self.query_progress.len() {
+                1 => "Running query… (Esc to cancel)".to_owned(),
+                count => format!("Running {count} queries… (Esc to cancel)"),
+            };
+            status_bar =
+                status_bar.tag(Tag::new(title, format!("{spinner} {}s", elapsed.as_secs())));
+        }
 
         // render block with status bar
         let area = {
@@ -166,6 +239,14 @@ impl Component for Tabs {
         }
     }
 
+    fn handle_mouse(&mut self, event: crossterm::event::MouseEvent) -> bool {
+        self.switcher.is_none()
+            && self
+                .panes
+                .get_mut(self.idx)
+                .is_some_and(|pane| pane.handle_mouse(event))
+    }
+
     fn handle(&mut self, event: crossterm::event::KeyEvent) -> bool {
         if let Some(switcher) = self.switcher.as_mut() {
             switcher.handle(event)
@@ -182,6 +263,20 @@ impl Component for Tabs {
                         }
                         true
                     }
+                    (KeyCode::Esc, KeyModifiers::NONE) if !self.import_progress.is_empty() => {
+                        let cancelled = std::mem::take(&mut self.import_progress);
+                        cancelled.iter().for_each(ImportProgress::cancel);
+                        match cancelled.len() {
+                            1 => Message::AppShowToast("Import cancelled".to_owned()).enqueue(),
+                            count => Message::AppShowToast(format!("{count} imports cancelled"))
+                                .enqueue(),
+                        }
+                        true
+                    }
+                    (KeyCode::Esc, KeyModifiers::NONE) if !self.query_progress.is_empty() => {
+                        Message::TabsCancelQuery.enqueue();
+                        true
+                    }
                     (KeyCode::Char('t'), KeyModifiers::NONE) => {
                         self.show_tab_switcher();
                         true
@@ -215,6 +310,39 @@ impl Component for Tabs {
                     TableDescription::Query(query.to_owned()),
                 ));
             }
+            Message::TabsShowWriteProgress(progress) => {
+                self.write_progress.push(progress.clone());
+            }
+            Message::TabsDismissWriteProgress(progress) => {
+                self.write_progress
+                    .retain(|running| !running.same(progress));
+            }
+            Message::TabsShowImportProgress(progress) => {
+                self.import_progress.push(progress.clone());
+            }
+            Message::TabsDismissImportProgress(progress) => {
+                self.import_progress
+                    .retain(|running| !running.same(progress));
+            }
+            Message::TabsShowQueryProgress(progress) => {
+                self.query_progress.push(progress.clone());
+            }
+            Message::TabsDismissQueryProgress(progress) => {
+                self.query_progress
+                    .retain(|running| !running.same(progress));
+            }
+            Message::TabsShowQueryTime(duration) => {
+                self.query_time = Some(*duration);
+            }
+            Message::TabsCancelQuery => {
+                let cancelled = std::mem::take(&mut self.query_progress);
+                cancelled.iter().for_each(QueryProgress::cancel);
+                match cancelled.len() {
+                    0 => (),
+                    1 => Message::AppShowToast("Query cancelled".to_owned()).enqueue(),
+                    count => Message::AppShowToast(format!("{count} queries cancelled")).enqueue(),
+                }
+            }
             Message::TabsSelect(idx) if focus_state.is_focused() => self.select(*idx),
             Message::TabsDismissSwitcher if focus_state.is_focused() => self.dismiss_tab_switcher(),
             _ => (),
@@ -254,6 +382,39 @@ impl FromIterator<Pane> for Tabs {
             panes: iter.into_iter().collect(),
             idx: 0,
             switcher: None,
+            write_progress: Vec::new(),
+            import_progress: Vec::new(),
+            query_progress: Vec::new(),
+            query_time: None,
         }
     }
 }
+
+/// Selected row out of the rows in the table, followed by the rows of the unfiltered table when
+/// a query or filter dropped some.
+fn row_position(pane: &Pane) -> String {
+    let height = pane.table().data_frame().height();
+    let row = pane
+        .table()
+        .selected()
+        .map(|row| row + 1)
+        .unwrap_or_default();
+    let mut position = format!(
+        "{row:>width$} of {height}",
+        width = height.to_string().len()
+    );
+    let total = pane.base_table().data_frame().height();
+    if total != height {
+        position.push_str(&format!(" (unfiltered {total})"));
+    }
+    position
+}
+
+/// Name and one based index of the current column, None for a table without columns.
+fn column_position(pane: &Pane) -> Option<String> {
+    let names = pane.table().visible_data_frame().get_column_names();
+    let idx = pane.table().current_column();
+    names
+        .get(idx)
+        .map(|name| format!("{name} ({} of {})", idx + 1, names.len()))
+}
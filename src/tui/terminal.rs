@@ -1,12 +1,39 @@
 use crate::AppResult;
 use crate::app::App;
 use crate::handler::event::EventHandler;
+use crate::misc::config::config;
 use crate::tui::component::Component;
 use crate::tui::component::FocusState;
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
 use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
 use ratatui::backend::Backend;
-use std::io;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
 use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(not(target_os = "windows"))]
+const TTY_PATH: &str = "/dev/tty";
+#[cfg(target_os = "windows")]
+const TTY_PATH: &str = "CONOUT$";
+
+static DRAW_TO_TTY: AtomicBool = AtomicBool::new(false);
+
+/// Draws the interface on the controlling terminal instead of stdout, so a redirected stdout
+/// only receives the table printed on exit.
+pub fn draw_to_tty() {
+    DRAW_TO_TTY.store(true, Ordering::Relaxed);
+}
+
+/// Writer the interface and its escape sequences go to, stdout unless [`draw_to_tty`] was
+/// called.
+pub fn output() -> io::Result<Box<dyn Write + Send>> {
+    if DRAW_TO_TTY.load(Ordering::Relaxed) {
+        Ok(Box::new(OpenOptions::new().write(true).open(TTY_PATH)?))
+    } else {
+        Ok(Box::new(io::stdout()))
+    }
+}
 
 /// Representation of a terminal user interface.
 ///
@@ -31,7 +58,11 @@ impl<B: Backend> Terminal<B> {
     /// It enables the raw mode and sets terminal properties.
     pub fn init(&mut self) -> AppResult<()> {
         terminal::enable_raw_mode()?;
-        crossterm::execute!(io::stdout(), EnterAlternateScreen)?;
+        crossterm::execute!(output()?, EnterAlternateScreen)?;
+        // Capturing the mouse disables the native text selection of the terminal.
+        if config().mouse() {
+            crossterm::execute!(output()?, EnableMouseCapture)?;
+        }
 
         // Define a custom panic hook to reset the terminal properties.
         // This way, you won't have your terminal messed up if an unexpected error happens.
@@ -65,7 +96,7 @@ impl<B: Backend> Terminal<B> {
     /// the terminal properties if unexpected errors occur.
     fn reset() -> AppResult<()> {
         terminal::disable_raw_mode()?;
-        crossterm::execute!(io::stdout(), LeaveAlternateScreen)?;
+        crossterm::execute!(output()?, DisableMouseCapture, LeaveAlternateScreen)?;
         Ok(())
     }
 
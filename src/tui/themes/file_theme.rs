@@ -0,0 +1,143 @@
+use std::{fs, path::Path, sync::OnceLock};
+
+use ratatui::style::{Color, Style, Stylize};
+use serde::Deserialize;
+
+use crate::{AppResult, misc::paths::themes_dir, tui::themes::styler::Styler};
+
+static FILE_THEMES: OnceLock<Vec<FileTheme>> = OnceLock::new();
+
+/// Themes discovered in the themes directory, loaded once on first access.
+pub fn file_themes() -> &'static [FileTheme] {
+    FILE_THEMES.get_or_init(|| themes_dir().map(|dir| discover(&dir)).unwrap_or_default())
+}
+
+/// A six colors, two rows theme read from a TOML file at runtime instead of being compiled in.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct FileTheme {
+    #[serde(skip)]
+    name: String,
+    background: Color,
+    light_background: Color,
+    foreground: Color,
+    dark_foreground: Color,
+    colors: [Color; 6],
+    dark_colors: [Color; 6],
+    row_backgrounds: [Color; 2],
+    highlight_background: Color,
+    highlight_foreground: Color,
+    status_bar_error: Color,
+}
+
+impl FileTheme {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn load(path: &Path) -> AppResult<Self> {
+        let contents = fs::read_to_string(path)?;
+        let mut theme: FileTheme = toml::from_str(&contents)?;
+        theme.name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(theme)
+    }
+}
+
+fn discover(dir: &Path) -> Vec<FileTheme> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut themes = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .filter_map(|path| match FileTheme::load(&path) {
+            Ok(theme) => Some(theme),
+            Err(err) => {
+                eprintln!("Warning: skipping theme {}: {err}", path.display());
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    themes.sort_by(|a, b| a.name.cmp(&b.name));
+    themes
+}
+
+impl Styler for FileTheme {
+    fn table_header(&self) -> Style {
+        Style::default().bg(self.background)
+    }
+
+    fn header(&self, idx: usize) -> Style {
+        Style::default()
+            .fg(self.colors[idx % self.colors.len()])
+            .bold()
+    }
+
+    fn row(&self, idx: usize) -> Style {
+        Style::new()
+            .bg(self.row_backgrounds[idx % self.row_backgrounds.len()])
+            .fg(self.foreground)
+    }
+
+    fn row_highlighted(&self) -> Style {
+        Style::new()
+            .bg(self.highlight_background)
+            .fg(self.highlight_foreground)
+    }
+
+    fn tag(&self, idx: usize) -> Style {
+        Style::default()
+            .bg(self.dark_colors[idx % self.dark_colors.len()])
+            .fg(self.light_background)
+    }
+
+    fn block(&self) -> Style {
+        Style::default()
+            .bg(self.background)
+            .fg(self.highlight_background)
+    }
+
+    fn block_tag(&self) -> Style {
+        Style::default()
+            .bg(self.highlight_background)
+            .fg(self.light_background)
+    }
+
+    fn text(&self) -> Style {
+        Style::default().bg(self.background).fg(self.foreground)
+    }
+
+    fn text_highlighted(&self) -> Style {
+        Style::default()
+            .bg(self.background)
+            .fg(self.highlight_background)
+    }
+
+    fn subtext(&self) -> Style {
+        Style::default()
+            .bg(self.background)
+            .fg(self.dark_foreground)
+    }
+
+    fn error(&self) -> Style {
+        Style::default()
+            .bg(self.status_bar_error)
+            .fg(self.foreground)
+    }
+
+    fn graph(&self, idx: usize) -> Style {
+        Style::default()
+            .fg(self.dark_colors[idx % self.dark_colors.len()])
+            .bold()
+    }
+
+    fn gutter(&self, idx: usize) -> Style {
+        Style::new()
+            .bg(self.row_backgrounds[idx % self.row_backgrounds.len()])
+            .fg(self.dark_foreground)
+    }
+}
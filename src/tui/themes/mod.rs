@@ -124,6 +124,7 @@ pub mod fairyfloss;
 pub mod farmhouse_dark;
 pub mod farmhouse_light;
 pub mod fideloper;
+pub mod file_theme;
 pub mod firefly_traditional;
 pub mod firefox_dev;
 pub mod firewatch;
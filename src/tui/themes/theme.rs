@@ -6,6 +6,7 @@ use std::{
 };
 
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use strum_macros::{AsRefStr, EnumIter, EnumString};
 
 use crate::tui::themes::{
@@ -50,10 +51,11 @@ use crate::tui::themes::{
     everblush::Everblush, everforest_dark_hard::EverforestDarkHard,
     everforest_light_med::EverforestLightMed, fahrenheit::Fahrenheit, fairyfloss::Fairyfloss,
     farmhouse_dark::FarmhouseDark, farmhouse_light::FarmhouseLight, fideloper::Fideloper,
-    firefly_traditional::FireflyTraditional, firefox_dev::FirefoxDev, firewatch::Firewatch,
-    fish_tank::FishTank, flat::Flat, flatland::Flatland, flexoki_dark::FlexokiDark,
-    flexoki_light::FlexokiLight, floraverse::Floraverse, forest_blue::ForestBlue, framer::Framer,
-    front_end_delight::FrontEndDelight, fun_forrest::FunForrest, galaxy::Galaxy, galizur::Galizur,
+    file_theme::file_themes, firefly_traditional::FireflyTraditional, firefox_dev::FirefoxDev,
+    firewatch::Firewatch, fish_tank::FishTank, flat::Flat, flatland::Flatland,
+    flexoki_dark::FlexokiDark, flexoki_light::FlexokiLight, floraverse::Floraverse,
+    forest_blue::ForestBlue, framer::Framer, front_end_delight::FrontEndDelight,
+    fun_forrest::FunForrest, galaxy::Galaxy, galizur::Galizur,
     ghostty_default_style_dark::GhosttyDefaultStyleDark, git_hub::Github, git_hub_dark::GithubDark,
     git_hub_dark_colorblind::GithubDarkColorblind, git_hub_dark_default::GithubDarkDefault,
     git_hub_dark_dimmed::GithubDarkDimmed, git_hub_dark_high_contrast::GithubDarkHighContrast,
@@ -580,6 +582,10 @@ impl From<Theme> for LoadedTheme {
             Theme::Terminal => LoadedTheme::new(value, Terminal),
             Theme::Chakra => LoadedTheme::new(value, Chakra),
             Theme::Custom => LoadedTheme::new(value, Custom::create_or_load()),
+            Theme::File(idx) => match file_themes().get(idx) {
+                Some(theme) => LoadedTheme::new(value, theme.clone()),
+                None => LoadedTheme::default(),
+            },
         }
     }
 }
@@ -607,8 +613,15 @@ impl<'de> Deserialize<'de> for LoadedTheme {
         D: serde::Deserializer<'de>,
     {
         let theme_str = String::deserialize(deserializer)?;
-        let app_theme =
-            Theme::from_str(&theme_str).map_err(|e| serde::de::Error::custom(e.to_string()))?;
+        let app_theme = Theme::from_str(&theme_str)
+            .ok()
+            .or_else(|| {
+                file_themes()
+                    .iter()
+                    .position(|theme| theme.name() == theme_str)
+                    .map(Theme::File)
+            })
+            .ok_or_else(|| serde::de::Error::custom(format!("Unknown theme: {theme_str}")))?;
         Ok(LoadedTheme::from(app_theme))
     }
 }
@@ -1022,15 +1035,29 @@ pub enum Theme {
     ZenwrittenDark,
     ZenwrittenLight,
     Custom,
+    /// Theme loaded from the themes directory, indexing into [`file_themes`].
+    #[strum(disabled)]
+    File(usize),
 }
 
 impl Theme {
+    /// Compiled in themes followed by the ones found in the themes directory.
+    pub fn all() -> impl Iterator<Item = Theme> {
+        Theme::iter().chain((0..file_themes().len()).map(Theme::File))
+    }
+
     pub fn id(&self) -> &str {
-        self.as_ref()
+        match self {
+            Theme::File(idx) => file_themes()
+                .get(*idx)
+                .map(|theme| theme.name())
+                .unwrap_or_default(),
+            _ => self.as_ref(),
+        }
     }
 
     pub fn title(&self) -> &str {
-        self.as_ref()
+        self.id()
     }
 }
 
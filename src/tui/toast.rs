@@ -0,0 +1,55 @@
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    widgets::{Clear, Paragraph, Widget},
+};
+
+use crate::{
+    misc::config::theme,
+    tui::{component::Component, widgets::block::Block},
+};
+
+const TOAST_TICKS: usize = 30;
+
+#[derive(Debug)]
+pub struct Toast {
+    message: String,
+    ticks: usize,
+}
+
+impl Toast {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ticks: TOAST_TICKS,
+        }
+    }
+
+    pub fn expired(&self) -> bool {
+        self.ticks == 0
+    }
+}
+
+impl Component for Toast {
+    fn render(
+        &mut self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        _focus_state: super::component::FocusState,
+    ) {
+        let pg = Paragraph::new(self.message.as_str())
+            .style(theme().text())
+            .block(Block::default().into_widget());
+        let width = (pg.line_width() as u16).min(area.width);
+        let [_, area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Length(3)]).areas(area);
+        let [area] = Layout::horizontal([Constraint::Length(width)])
+            .flex(Flex::End)
+            .areas(area);
+        Clear.render(area, buf);
+        pg.render(area, buf);
+    }
+
+    fn tick(&mut self) {
+        self.ticks = self.ticks.saturating_sub(1);
+    }
+}
@@ -0,0 +1,180 @@
+use itertools::Itertools;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph, Widget},
+};
+
+use crate::{
+    misc::{config::theme, type_ext::HasSubsequence},
+    tui::widgets::{block::Block, input::Input},
+};
+
+/// Tab completion of the word before the cursor from a list of names, repeated tabs cycle
+/// through the candidates.
+#[derive(Debug, Default)]
+pub struct Completer {
+    title: String,
+    names: Vec<String>,
+    completion: Option<Completion>,
+}
+
+#[derive(Debug)]
+struct Completion {
+    prefix: String,
+    word: String,
+    suffix: String,
+    idx: usize,
+}
+
+impl Completer {
+    pub fn new(title: impl Into<String>, names: Vec<String>) -> Self {
+        Self {
+            title: title.into(),
+            names,
+            completion: None,
+        }
+    }
+
+    /// Splits the value at the cursor, given in chars, into the text before the word being
+    /// typed, the word itself and the text after the cursor.
+    fn split_word(value: &str, cursor: usize) -> (&str, &str, &str) {
+        let end = value
+            .char_indices()
+            .nth(cursor)
+            .map(|(idx, _)| idx)
+            .unwrap_or(value.len());
+        let (before, suffix) = value.split_at(end);
+        let start = before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| !(c.is_alphanumeric() || *c == '_' || *c == '"'))
+            .map(|(idx, c)| idx + c.len_utf8())
+            .unwrap_or(0);
+        let (prefix, word) = before.split_at(start);
+        (prefix, word, suffix)
+    }
+
+    fn candidates(&self, word: &str) -> Vec<&str> {
+        let word = word.trim_start_matches('"');
+        if word.is_empty() {
+            return Vec::new();
+        }
+        let (prefixed, others): (Vec<_>, Vec<_>) = self
+            .names
+            .iter()
+            .map(String::as_str)
+            .filter(|name| name.has_subsequence(word))
+            .partition(|name| name.starts_with(word));
+        prefixed.into_iter().chain(others).collect()
+    }
+
+    /// Replaces the word before the cursor with the next candidate, leaving the cursor right
+    /// after it. Returns false if nothing matches.
+    pub fn complete(&mut self, input: &mut Input) -> bool {
+        let completion = match self.completion.take() {
+            Some(completion) => Completion {
+                idx: completion.idx + 1,
+                ..completion
+            },
+            None => {
+                let (prefix, word, suffix) = Self::split_word(input.value(), input.cursor());
+                Completion {
+                    prefix: prefix.to_owned(),
+                    word: word.to_owned(),
+                    suffix: suffix.to_owned(),
+                    idx: 0,
+                }
+            }
+        };
+        let candidates = self.candidates(&completion.word);
+        if candidates.is_empty() {
+            return false;
+        }
+        let name = candidates[completion.idx % candidates.len()];
+        let name = if name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            name.to_owned()
+        } else {
+            format!("\"{name}\"")
+        };
+        let head = format!("{}{name}", completion.prefix);
+        let cursor = head.chars().count();
+        input.set_value_with_cursor(format!("{head}{}", completion.suffix), cursor);
+        self.completion = Some(completion);
+        true
+    }
+
+    /// Ends cycling, the next completion starts from the word at the cursor again.
+    pub fn reset(&mut self) {
+        self.completion = None;
+    }
+
+    /// Lists the candidates for the word before the cursor below the input.
+    pub fn render(&self, input: &Input, buf: &mut Buffer) {
+        let word = match self.completion.as_ref() {
+            Some(completion) => completion.word.as_str(),
+            None => Self::split_word(input.value(), input.cursor()).1,
+        };
+        let candidates = self.candidates(word);
+        if candidates.is_empty() {
+            return;
+        }
+        let selected = self
+            .completion
+            .as_ref()
+            .map(|completion| completion.idx % candidates.len());
+        let [area] = Layout::horizontal([Constraint::Length(80)])
+            .flex(Flex::Center)
+            .areas(buf.area);
+        let [_, area] =
+            Layout::vertical([Constraint::Length(6), Constraint::Length(3)]).areas(area);
+        Clear.render(area, buf);
+        Paragraph::new(Line::from_iter(
+            Itertools::intersperse(
+                candidates.into_iter().enumerate().map(|(idx, name)| {
+                    if Some(idx) == selected {
+                        Span::styled(name, theme().text_highlighted())
+                    } else {
+                        Span::styled(name, theme().subtext())
+                    }
+                }),
+                Span::raw("  "),
+            )
+            .collect_vec(),
+        ))
+        .block(Block::default().title(self.title.as_str()).into_widget())
+        .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_at_cursor() {
+        let mut completer = Completer::new(
+            "Names",
+            vec![
+                "sales".to_owned(),
+                "sale date".to_owned(),
+                "users".to_owned(),
+            ],
+        );
+        let mut input = Input::default().with_value("SELECT * FROM sa WHERE 1".to_owned());
+        input.set_value_with_cursor(input.value().to_owned(), 16);
+        assert!(completer.complete(&mut input));
+        assert_eq!(input.value(), "SELECT * FROM sales WHERE 1");
+        assert_eq!(input.cursor(), 19);
+        assert!(completer.complete(&mut input));
+        assert_eq!(input.value(), "SELECT * FROM \"sale date\" WHERE 1");
+        assert!(completer.complete(&mut input));
+        assert_eq!(input.value(), "SELECT * FROM sales WHERE 1");
+
+        completer.reset();
+        input.set_value_with_cursor("SELECT x".to_owned(), 8);
+        assert!(!completer.complete(&mut input));
+        assert_eq!(input.value(), "SELECT x");
+    }
+}
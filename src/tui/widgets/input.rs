@@ -102,6 +102,21 @@ impl Input {
     pub fn value(&self) -> &str {
         self.input.value()
     }
+
+    pub fn set_value(&mut self, value: String) {
+        self.input = std::mem::take(&mut self.input).with_value(value);
+    }
+
+    /// Position of the cursor in chars.
+    pub fn cursor(&self) -> usize {
+        self.input.cursor()
+    }
+
+    pub fn set_value_with_cursor(&mut self, value: String, cursor: usize) {
+        self.input = std::mem::take(&mut self.input)
+            .with_value(value)
+            .with_cursor(cursor);
+    }
 }
 
 impl Component for Input {
@@ -1,3 +1,4 @@
 pub mod block;
+pub mod completion;
 pub mod highlighted_line;
 pub mod input;
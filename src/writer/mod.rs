@@ -1,13 +1,30 @@
-use std::{fs::File, path::PathBuf};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
 
-use anyhow::Ok;
+use anyhow::{Ok, anyhow};
+use itertools::Itertools;
 use polars::{
     frame::DataFrame,
     io::SerWriter,
-    prelude::{CsvWriter, IpcWriter, JsonWriter, ParquetWriter},
+    prelude::{CsvWriter, DataType, IpcWriter, JsonWriter, ParquetCompression, ParquetWriter},
 };
 
-use crate::{AppResult, misc::osc52::CopyToClipboardOsc52};
+use ratatui::style::{Color, Style};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString};
+
+use crate::{
+    AppResult,
+    handler::message::Message,
+    misc::{config::theme, osc52::CopyToClipboardOsc52, polars_ext::AnyValueExt},
+};
 
 #[derive(Debug, Clone)]
 pub enum Destination {
@@ -15,6 +32,15 @@ pub enum Destination {
     Clipboard,
 }
 
+impl Destination {
+    pub fn display_path(&self) -> String {
+        match self {
+            Destination::File(path) => path.to_string_lossy().into_owned(),
+            Destination::Clipboard => "Clipboard".to_owned(),
+        }
+    }
+}
+
 impl<T> From<T> for Destination
 where
     T: AsRef<str>,
@@ -28,8 +54,129 @@ where
 }
 
 pub trait WriteToFile {
-    fn write_to_file(&self, dest: Destination, data_frame: &mut DataFrame) -> AppResult<()>;
+    fn write(&self, writer: &mut dyn Write, data_frame: &mut DataFrame) -> AppResult<()>;
+
+    fn write_to_file(&self, dest: Destination, data_frame: &mut DataFrame) -> AppResult<()> {
+        match dest {
+            Destination::File(path) => {
+                let mut writer = BufWriter::new(File::create(path)?);
+                self.write(&mut writer, data_frame)?;
+                writer.flush()?;
+                Ok(())
+            }
+            Destination::Clipboard => {
+                self.write_to_bytes(data_frame)?
+                    .copy_to_clipboard_via_osc52();
+                Ok(())
+            }
+        }
+    }
+
+    /// Serializes the data frame in memory instead of writing it to a file.
+    fn write_to_bytes(&self, data_frame: &mut DataFrame) -> AppResult<Vec<u8>> {
+        let mut buf = Vec::new();
+        self.write(&mut buf, data_frame)?;
+        Ok(buf)
+    }
+
+    /// Writes the data frame on a worker thread, reporting progress in the status bar and
+    /// a toast (or an error) once done.
+    fn write_in_background(self, dest: Destination, mut data_frame: DataFrame)
+    where
+        Self: Sized + Send + 'static,
+    {
+        let progress = WriteProgress::default();
+        Message::TabsShowWriteProgress(progress.clone()).enqueue();
+        let shown = progress.clone();
+        std::thread::spawn(move || {
+            let result = match &dest {
+                Destination::File(path) => File::create(path)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|file| {
+                        let mut out = ProgressWriter {
+                            inner: BufWriter::new(file),
+                            progress,
+                        };
+                        self.write(&mut out, &mut data_frame)?;
+                        out.flush()?;
+                        Ok(())
+                    }),
+                Destination::Clipboard => {
+                    let mut out = ProgressWriter {
+                        inner: Vec::new(),
+                        progress,
+                    };
+                    self.write(&mut out, &mut data_frame)
+                        .map(|_| out.inner.copy_to_clipboard_via_osc52())
+                }
+            };
+            Message::TabsDismissWriteProgress(shown).enqueue();
+            match result {
+                std::result::Result::Ok(_) => {
+                    Message::AppShowToast(format!("Exported to {}", dest.display_path())).enqueue()
+                }
+                Err(err) => Message::AppShowError(err.to_string()).enqueue(),
+            }
+        });
+    }
+}
+
+/// Plain text format tables are printed or copied as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum TextFormat {
+    Csv,
+    Tsv,
+    Json,
+    Md,
+}
+
+/// Writer of a plain text format, tables get a header row.
+pub fn text_writer(format: TextFormat) -> Box<dyn WriteToFile> {
+    match format {
+        TextFormat::Csv => Box::new(WriteToCsv::default().with_header(true)),
+        TextFormat::Tsv => Box::new(
+            WriteToCsv::default()
+                .with_separator_char('\t')
+                .with_header(true),
+        ),
+        TextFormat::Json => Box::new(WriteToJson::default()),
+        TextFormat::Md => Box::new(WriteToMarkdown),
+    }
+}
+
+/// Number of bytes written so far by a background write.
+#[derive(Debug, Clone, Default)]
+pub struct WriteProgress(Arc<AtomicU64>);
+
+impl WriteProgress {
+    pub fn bytes(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if both refer to the same write.
+    pub fn same(&self, other: &WriteProgress) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+struct ProgressWriter<W> {
+    inner: W,
+    progress: WriteProgress,
 }
+
+impl<W: Write> Write for ProgressWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = self.inner.write(buf)?;
+        self.progress.0.fetch_add(len as u64, Ordering::Relaxed);
+        std::io::Result::Ok(len)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 pub struct WriteToCsv {
     separator: char,
     quote: char,
@@ -62,43 +209,78 @@ impl WriteToCsv {
 }
 
 impl WriteToFile for WriteToCsv {
-    fn write_to_file(&self, dest: Destination, data_frame: &mut DataFrame) -> AppResult<()> {
-        match dest {
-            Destination::File(path) => Ok(CsvWriter::new(File::create(path)?)
-                .with_separator(self.separator.try_into()?)
-                .with_quote_char(self.quote.try_into()?)
-                .include_header(self.header)
-                .finish(data_frame)?),
-            Destination::Clipboard => {
-                let mut buf = Vec::new();
-                CsvWriter::new(&mut buf)
-                    .with_separator(self.separator.try_into()?)
-                    .with_quote_char(self.quote.try_into()?)
-                    .include_header(self.header)
-                    .finish(data_frame)?;
-                buf.copy_to_clipboard_via_osc52();
-                Ok(())
-            }
-        }
+    fn write(&self, writer: &mut dyn Write, data_frame: &mut DataFrame) -> AppResult<()> {
+        Ok(CsvWriter::new(writer)
+            .with_separator(self.separator.try_into()?)
+            .with_quote_char(self.quote.try_into()?)
+            .include_header(self.header)
+            .finish(data_frame)?)
     }
 }
 
-#[derive(Debug, Default)]
-pub struct WriteToParquet;
+#[derive(Debug, Default, Clone)]
+pub struct WriteToParquet {
+    compression: Option<ParquetCodec>,
+    row_group_size: Option<usize>,
+}
+
+impl WriteToParquet {
+    pub fn with_compression(mut self, compression: ParquetCodec) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Rows per row group, polars picks the size if unset.
+    pub fn with_row_group_size(mut self, row_group_size: Option<usize>) -> Self {
+        self.row_group_size = row_group_size;
+        self
+    }
+}
 
 impl WriteToFile for WriteToParquet {
-    fn write_to_file(&self, dest: Destination, data_frame: &mut DataFrame) -> AppResult<()> {
-        match dest {
-            Destination::File(path) => {
-                ParquetWriter::new(File::create(path)?).finish(data_frame)?;
-                Ok(())
-            }
-            Destination::Clipboard => {
-                let mut buf = Vec::new();
-                ParquetWriter::new(&mut buf).finish(data_frame)?;
-                buf.copy_to_clipboard_via_osc52();
-                Ok(())
-            }
+    fn write(&self, writer: &mut dyn Write, data_frame: &mut DataFrame) -> AppResult<()> {
+        let mut parquet = ParquetWriter::new(writer).with_row_group_size(self.row_group_size);
+        if let Some(compression) = self.compression {
+            parquet = parquet.with_compression(compression.into());
+        }
+        parquet.finish(data_frame)?;
+        Ok(())
+    }
+}
+
+/// Compression codec of the Parquet data pages, zstd is the polars default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, EnumIter, EnumString, Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum ParquetCodec {
+    #[default]
+    Zstd,
+    Snappy,
+    Gzip,
+    Lz4,
+    None,
+}
+
+impl ParquetCodec {
+    /// Parses a codec name, the error lists the supported ones.
+    pub fn parse(name: &str) -> AppResult<Self> {
+        name.trim().to_lowercase().parse().map_err(|_| {
+            anyhow!(
+                "Unknown compression '{}', supported: {}",
+                name.trim(),
+                ParquetCodec::iter().join(", ")
+            )
+        })
+    }
+}
+
+impl From<ParquetCodec> for ParquetCompression {
+    fn from(value: ParquetCodec) -> Self {
+        match value {
+            ParquetCodec::Zstd => ParquetCompression::Zstd(None),
+            ParquetCodec::Snappy => ParquetCompression::Snappy,
+            ParquetCodec::Gzip => ParquetCompression::Gzip(None),
+            ParquetCodec::Lz4 => ParquetCompression::Lz4Raw,
+            ParquetCodec::None => ParquetCompression::Uncompressed,
         }
     }
 }
@@ -122,6 +304,7 @@ impl From<JsonFormat> for polars::prelude::JsonFormat {
 #[derive(Debug, Default)]
 pub struct WriteToJson {
     fmt: JsonFormat,
+    indent: Option<usize>,
 }
 
 impl WriteToJson {
@@ -129,39 +312,471 @@ impl WriteToJson {
         self.fmt = fmt;
         self
     }
+
+    /// Pretty prints with the given number of spaces per level, None writes compact JSON.
+    pub fn with_indent(mut self, indent: Option<usize>) -> Self {
+        self.indent = indent;
+        self
+    }
 }
 
 impl WriteToFile for WriteToJson {
-    fn write_to_file(&self, dest: Destination, data_frame: &mut DataFrame) -> AppResult<()> {
-        match dest {
-            Destination::File(path) => Ok(JsonWriter::new(File::create(path)?)
+    fn write(&self, writer: &mut dyn Write, data_frame: &mut DataFrame) -> AppResult<()> {
+        match (self.indent, self.fmt) {
+            (None, _) => Ok(JsonWriter::new(writer)
                 .with_json_format(self.fmt.into())
                 .finish(data_frame)?),
-            Destination::Clipboard => {
-                let mut buf = Vec::new();
-                JsonWriter::new(&mut buf)
+            (Some(_), JsonFormat::JsonLine) => Err(anyhow!(
+                "JSON lines can not be pretty printed, every record has to stay on one line"
+            )),
+            (Some(indent), JsonFormat::Json) => {
+                let mut compact = Vec::new();
+                JsonWriter::new(&mut compact)
                     .with_json_format(self.fmt.into())
                     .finish(data_frame)?;
-                buf.copy_to_clipboard_via_osc52();
-                Ok(())
+                Ok(writer.write_all(&indent_json(&compact, indent))?)
+            }
+        }
+    }
+}
+
+/// Puts every value of compact JSON on its own line, nested `indent` spaces per level. Empty
+/// objects and arrays are kept on one line.
+fn indent_json(compact: &[u8], indent: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(compact.len() * 2);
+    let mut depth = 0_usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let newline = |out: &mut Vec<u8>, depth: usize| {
+        out.push(b'\n');
+        out.extend(std::iter::repeat_n(b' ', depth * indent));
+    };
+    let mut bytes = compact.iter().copied().peekable();
+    while let Some(byte) = bytes.next() {
+        if in_string {
+            out.push(byte);
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => (),
             }
+            continue;
+        }
+        match byte {
+            b'"' => {
+                in_string = true;
+                out.push(byte);
+            }
+            b'{' | b'[' => {
+                out.push(byte);
+                if matches!(bytes.peek(), Some(b'}' | b']')) {
+                    out.extend(bytes.next());
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            b'}' | b']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut out, depth);
+                out.push(byte);
+            }
+            b',' => {
+                out.push(byte);
+                newline(&mut out, depth);
+            }
+            b':' => out.extend(b": "),
+            b' ' | b'\t' | b'\n' | b'\r' => (),
+            _ => out.push(byte),
         }
     }
+    out.push(b'\n');
+    out
 }
 
 #[derive(Debug, Default)]
 pub struct WriteToArrow;
 
 impl WriteToFile for WriteToArrow {
-    fn write_to_file(&self, dest: Destination, data_frame: &mut DataFrame) -> AppResult<()> {
-        match dest {
-            Destination::File(path) => Ok(IpcWriter::new(File::create(path)?).finish(data_frame)?),
-            Destination::Clipboard => {
-                let mut buf = Vec::new();
-                IpcWriter::new(&mut buf).finish(data_frame)?;
-                buf.copy_to_clipboard_via_osc52();
-                Ok(())
+    fn write(&self, writer: &mut dyn Write, data_frame: &mut DataFrame) -> AppResult<()> {
+        Ok(IpcWriter::new(writer).finish(data_frame)?)
+    }
+}
+
+/// GitHub flavored pipe table, numeric columns are right aligned.
+#[derive(Debug, Default)]
+pub struct WriteToMarkdown;
+
+impl WriteToFile for WriteToMarkdown {
+    fn write(&self, writer: &mut dyn Write, data_frame: &mut DataFrame) -> AppResult<()> {
+        let row = |cells: &mut dyn Iterator<Item = String>| {
+            let mut line = String::from("|");
+            for cell in cells {
+                line.push(' ');
+                line.push_str(&cell);
+                line.push_str(" |");
+            }
+            line
+        };
+        let header = row(&mut data_frame
+            .get_column_names()
+            .into_iter()
+            .map(|name| escape_markdown(name)));
+        let separator = row(&mut data_frame.dtypes().into_iter().map(|dtype| {
+            if dtype.is_primitive_numeric() {
+                "---:".to_owned()
+            } else {
+                "---".to_owned()
+            }
+        }));
+        writeln!(writer, "{header}")?;
+        writeln!(writer, "{separator}")?;
+        for idx in 0..data_frame.height() {
+            let line = row(&mut data_frame.get_columns().iter().map(|column| {
+                column
+                    .get(idx)
+                    .map(|value| escape_markdown(&value.into_single_line()))
+                    .unwrap_or_default()
+            }));
+            writeln!(writer, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('\n', "<br>")
+}
+
+/// Standalone HTML table, styled with inline CSS from the active theme unless disabled.
+#[derive(Debug, Default)]
+pub struct WriteToHtml {
+    caption: Option<String>,
+    css: Option<String>,
+}
+
+impl WriteToHtml {
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Captures the colors of the current theme, it has to be called before moving the writer to
+    /// a background thread.
+    pub fn with_theme_style(mut self) -> Self {
+        let theme = theme();
+        let rule = |selector: &str, style: Style| {
+            let mut rule = format!("{selector} {{");
+            if let Some(fg) = style.fg.and_then(css_color) {
+                rule.push_str(&format!(" color: {fg};"));
+            }
+            if let Some(bg) = style.bg.and_then(css_color) {
+                rule.push_str(&format!(" background-color: {bg};"));
+            }
+            rule.push_str(" }");
+            rule
+        };
+        self.css = Some(
+            [
+                "table { border-collapse: collapse; }".to_owned(),
+                "th, td { padding: 2px 8px; text-align: left; }".to_owned(),
+                rule("table", theme.block()),
+                rule("caption", theme.text()),
+                rule("th", theme.table_header()),
+                rule("tbody tr:nth-child(odd)", theme.row(0)),
+                rule("tbody tr:nth-child(even)", theme.row(1)),
+            ]
+            .join("\n"),
+        );
+        self
+    }
+}
+
+impl WriteToFile for WriteToHtml {
+    fn write(&self, writer: &mut dyn Write, data_frame: &mut DataFrame) -> AppResult<()> {
+        if let Some(css) = &self.css {
+            writeln!(writer, "<style>\n{css}\n</style>")?;
+        }
+        writeln!(writer, "<table>")?;
+        if let Some(caption) = &self.caption {
+            writeln!(writer, "<caption>{}</caption>", escape_html(caption))?;
+        }
+        writeln!(writer, "<thead>\n<tr>")?;
+        for name in data_frame.get_column_names() {
+            writeln!(writer, "<th>{}</th>", escape_html(name))?;
+        }
+        writeln!(writer, "</tr>\n</thead>\n<tbody>")?;
+        for idx in 0..data_frame.height() {
+            writeln!(writer, "<tr>")?;
+            for column in data_frame.get_columns() {
+                let value = column
+                    .get(idx)
+                    .map(|value| value.into_single_line())
+                    .unwrap_or_default();
+                writeln!(writer, "<td>{}</td>", escape_html(&value))?;
+            }
+            writeln!(writer, "</tr>")?;
+        }
+        writeln!(writer, "</tbody>\n</table>")?;
+        Ok(())
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn css_color(color: Color) -> Option<String> {
+    Some(
+        match color {
+            Color::Rgb(r, g, b) => return Some(format!("#{r:02x}{g:02x}{b:02x}")),
+            Color::Black => "black",
+            Color::Red => "maroon",
+            Color::Green => "green",
+            Color::Yellow => "olive",
+            Color::Blue => "navy",
+            Color::Magenta => "purple",
+            Color::Cyan => "teal",
+            Color::Gray => "silver",
+            Color::DarkGray => "gray",
+            Color::LightRed => "red",
+            Color::LightGreen => "lime",
+            Color::LightYellow => "yellow",
+            Color::LightBlue => "blue",
+            Color::LightMagenta => "fuchsia",
+            Color::LightCyan => "aqua",
+            Color::White => "white",
+            Color::Reset | Color::Indexed(_) => return None,
+        }
+        .to_owned(),
+    )
+}
+
+/// SQL dialect of the DDL export, they only differ in a few type names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumIter)]
+pub enum DdlDialect {
+    #[default]
+    Generic,
+    Postgres,
+    Sqlite,
+}
+
+impl std::fmt::Display for DdlDialect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DdlDialect::Generic => write!(f, "Generic SQL"),
+            DdlDialect::Postgres => write!(f, "PostgreSQL"),
+            DdlDialect::Sqlite => write!(f, "SQLite"),
+        }
+    }
+}
+
+impl DdlDialect {
+    fn type_name(&self, dtype: &DataType) -> String {
+        match (self, dtype) {
+            (DdlDialect::Sqlite, dtype) if dtype.is_integer() || dtype.is_bool() => "INTEGER",
+            (DdlDialect::Sqlite, DataType::Float32 | DataType::Float64) => "REAL",
+            (DdlDialect::Sqlite, DataType::Binary) => "BLOB",
+            (DdlDialect::Sqlite, DataType::Duration(_)) => "INTEGER",
+            (DdlDialect::Sqlite, _) => "TEXT",
+            (_, DataType::Boolean) => "BOOLEAN",
+            (_, DataType::Int8 | DataType::Int16 | DataType::UInt8) => "SMALLINT",
+            (_, DataType::Int32 | DataType::UInt16) => "INTEGER",
+            (_, DataType::Int64 | DataType::UInt32) => "BIGINT",
+            (DdlDialect::Postgres, DataType::UInt64) => "NUMERIC(20)",
+            (_, DataType::UInt64) => "BIGINT UNSIGNED",
+            (_, DataType::Float32) => "REAL",
+            (DdlDialect::Postgres, DataType::Float64) => "DOUBLE PRECISION",
+            (_, DataType::Float64) => "DOUBLE",
+            (_, DataType::Decimal(precision, scale)) => {
+                return format!("DECIMAL({precision}, {scale})");
             }
+            (_, DataType::Date) => "DATE",
+            (_, DataType::Time) => "TIME",
+            (DdlDialect::Postgres, DataType::Datetime(_, Some(_))) => "TIMESTAMPTZ",
+            (_, DataType::Datetime(_, _)) => "TIMESTAMP",
+            (_, DataType::Duration(_)) => "INTERVAL",
+            (DdlDialect::Postgres, DataType::Binary) => "BYTEA",
+            (_, DataType::Binary) => "BLOB",
+            _ => "TEXT",
+        }
+        .to_owned()
+    }
+}
+
+/// `CREATE TABLE` statement with one column per data frame column, no rows are written.
+#[derive(Debug, Clone)]
+pub struct WriteToDdl {
+    name: String,
+    dialect: DdlDialect,
+}
+
+impl WriteToDdl {
+    pub fn new(name: impl Into<String>, dialect: DdlDialect) -> Self {
+        Self {
+            name: name.into(),
+            dialect,
+        }
+    }
+}
+
+impl WriteToFile for WriteToDdl {
+    fn write(&self, writer: &mut dyn Write, data_frame: &mut DataFrame) -> AppResult<()> {
+        let quote = |ident: &str| format!("\"{}\"", ident.replace('"', "\"\""));
+        writeln!(writer, "CREATE TABLE {} (", quote(&self.name))?;
+        let columns = data_frame.get_columns();
+        for (idx, column) in columns.iter().enumerate() {
+            let comma = if idx + 1 < columns.len() { "," } else { "" };
+            writeln!(
+                writer,
+                "    {} {}{comma}",
+                quote(column.name()),
+                self.dialect.type_name(column.dtype())
+            )?;
         }
+        writeln!(writer, ");")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::df;
+
+    use super::*;
+
+    #[test]
+    fn test_indent_json() {
+        let compact = br#"[{"a":1,"b":"x, {y}: \"z\"","c":[],"d":{"e":[true,null]}}]"#;
+        let expected = r#"[
+  {
+    "a": 1,
+    "b": "x, {y}: \"z\"",
+    "c": [],
+    "d": {
+      "e": [
+        true,
+        null
+      ]
+    }
+  }
+]
+"#;
+        assert_eq!(
+            String::from_utf8(indent_json(compact, 2)).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_pretty_json_lines_rejected() {
+        let mut df = DataFrame::empty();
+        let mut out = Vec::new();
+        assert!(
+            WriteToJson::default()
+                .with_format(JsonFormat::JsonLine)
+                .with_indent(Some(2))
+                .write(&mut out, &mut df)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parquet_compression() {
+        let mut df = df!("a" => (0..1000).map(|idx| idx % 7).collect::<Vec<i64>>()).unwrap();
+        let write = |writer: WriteToParquet, df: &mut DataFrame| {
+            let mut out = Vec::new();
+            writer.write(&mut out, df).unwrap();
+            out
+        };
+        let mut default = Vec::new();
+        ParquetWriter::new(&mut default).finish(&mut df).unwrap();
+        assert_eq!(write(WriteToParquet::default(), &mut df), default);
+
+        let uncompressed = write(
+            WriteToParquet::default()
+                .with_compression(ParquetCodec::None)
+                .with_row_group_size(Some(100)),
+            &mut df,
+        );
+        assert!(uncompressed.len() > default.len());
+
+        assert_eq!(
+            ParquetCodec::parse(" Snappy").unwrap(),
+            ParquetCodec::Snappy
+        );
+        assert_eq!(
+            ParquetCodec::parse("brotli").unwrap_err().to_string(),
+            "Unknown compression 'brotli', supported: zstd, snappy, gzip, lz4, none"
+        );
+    }
+
+    #[test]
+    fn test_ddl() {
+        let mut df = df!(
+            "id" => [1i64, 2],
+            "price" => [1.5f64, 2.5],
+            "na\"me" => ["a", "b"],
+            "active" => [true, false],
+        )
+        .unwrap();
+        let mut ddl = |dialect| {
+            let mut out = Vec::new();
+            WriteToDdl::new("sales", dialect)
+                .write(&mut out, &mut df)
+                .unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(
+            ddl(DdlDialect::Generic),
+            "CREATE TABLE \"sales\" (\n    \"id\" BIGINT,\n    \"price\" DOUBLE,\n    \"na\"\"me\" TEXT,\n    \"active\" BOOLEAN\n);\n"
+        );
+        assert!(ddl(DdlDialect::Postgres).contains("\"price\" DOUBLE PRECISION,"));
+        assert!(ddl(DdlDialect::Sqlite).contains("\"active\" INTEGER\n"));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let mut df = df!(
+            "name|alias" => ["a|b", "two\nlines"],
+            "qty" => [1i64, 20],
+        )
+        .unwrap();
+        let mut out = Vec::new();
+        WriteToMarkdown.write(&mut out, &mut df).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "| name\\|alias | qty |\n| --- | ---: |\n| a\\|b | 1 |\n| two<br>lines | 20 |\n"
+        );
+    }
+    #[test]
+    fn test_html() {
+        let mut df = df!("<a>" => ["x & \"y\""]).unwrap();
+        let mut html = |writer: WriteToHtml| {
+            let mut out = Vec::new();
+            writer.write(&mut out, &mut df).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(
+            html(WriteToHtml::default().with_caption("Q&A")),
+            "<table>\n<caption>Q&amp;A</caption>\n<thead>\n<tr>\n<th>&lt;a&gt;</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>x &amp; &quot;y&quot;</td>\n</tr>\n</tbody>\n</table>\n"
+        );
+        let styled = html(WriteToHtml::default().with_theme_style());
+        assert!(styled.starts_with("<style>\n"), "{styled}");
+        assert!(styled.contains("</style>\n<table>\n<thead>"), "{styled}");
     }
 }